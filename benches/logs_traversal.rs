@@ -2,6 +2,7 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use rust_logs_sample::*;
 use rust_logs_sample::otlp_bytes;
 use rust_logs_sample::otlp_bytes_lazy;
+use rust_logs_sample::intern;
 use rust_logs_sample::proto::opentelemetry::proto::logs::v1::*;
 use prost::Message;
 
@@ -86,96 +87,487 @@ fn traverse_otlp_bytes_lazy_logs(logs: &otlp_bytes_lazy::LogsDataParser) -> (usi
     (resource_count, scope_count, record_count, attribute_count)
 }
 
+/// Datasets the comparison groups run over: the small hand-written sample
+/// (bench names unsuffixed) and the generated varied payload (`_varied`)
+fn comparison_datasets() -> [(&'static str, LogsData); 2] {
+    [("", create_test_logs()), ("_varied", create_varied_large_logs(200))]
+}
+
 fn bench_parsing_only_comparison(c: &mut Criterion) {
-    let logs = create_test_logs();
-    let encoded = encode_logs_data(&logs);
-    
     let mut group = c.benchmark_group("parsing_only_comparison");
+
+    for (suffix, logs) in comparison_datasets() {
+        let encoded = encode_logs_data(&logs);
+
+        group.bench_function(format!("prost_decode{}", suffix), |b| {
+            b.iter(|| {
+                LogsData::decode(black_box(&encoded[..])).expect("Failed to decode");
+            })
+        });
+
+        group.bench_function(format!("otlp_bytes_parse{}", suffix), |b| {
+            b.iter(|| {
+                let mut bytes_logs = otlp_bytes::LogsData::new();
+                bytes_logs.parse(black_box(&encoded));
+            })
+        });
+
+        group.bench_function(format!("otlp_bytes_lazy_parse{}", suffix), |b| {
+            b.iter(|| {
+                otlp_bytes_lazy::LogsDataParser::new(black_box(&encoded));
+            })
+        });
+    }
     
-    group.bench_function("prost_decode", |b| {
-        b.iter(|| {
-            LogsData::decode(black_box(&encoded[..])).expect("Failed to decode");
-        })
-    });
+    group.finish();
+}
+
+fn bench_traversal_only_comparison(c: &mut Criterion) {
+    let mut group = c.benchmark_group("traversal_only_comparison");
+
+    for (suffix, logs) in comparison_datasets() {
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+
+        let bytes_logs_lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        group.bench_function(format!("prost_structs{}", suffix), |b| {
+            b.iter(|| {
+                traverse_logs(black_box(&logs))
+            })
+        });
+
+        group.bench_function(format!("otlp_bytes{}", suffix), |b| {
+            b.iter(|| {
+                traverse_logs(black_box(&bytes_logs))
+            })
+        });
+
+        group.bench_function(format!("otlp_bytes_lazy{}", suffix), |b| {
+            b.iter(|| {
+                traverse_otlp_bytes_lazy_logs(black_box(&bytes_logs_lazy))
+            })
+        });
+    }
     
-    group.bench_function("otlp_bytes_parse", |b| {
+    group.finish();
+}
+
+fn bench_parse_and_traversal_comparison(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_and_traversal_comparison");
+
+    for (suffix, logs) in comparison_datasets() {
+        let encoded = encode_logs_data(&logs);
+
+        group.bench_function(format!("prost_parse_and_traversal{}", suffix), |b| {
+            b.iter(|| {
+                // Decode bytes back to prost structs
+                let decoded_logs = LogsData::decode(black_box(&encoded[..])).expect("Failed to decode");
+                traverse_logs(black_box(&decoded_logs))
+            })
+        });
+
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        group.bench_function(format!("otlp_bytes_parse_and_traversal{}", suffix), |b| {
+            b.iter(|| {
+                bytes_logs.parse(black_box(&encoded));
+                traverse_logs(black_box(&bytes_logs))
+            })
+        });
+
+        group.bench_function(format!("otlp_bytes_lazy_parse_and_traversal{}", suffix), |b| {
+            b.iter(|| {
+                let bytes_logs = otlp_bytes_lazy::LogsDataParser::new(black_box(&encoded));
+                traverse_otlp_bytes_lazy_logs(black_box(&bytes_logs))
+            })
+        });
+    }
+    
+    group.finish();
+}
+
+fn bench_flat_vs_nested_records(c: &mut Criterion) {
+    let logs = create_large_test_logs();
+    let encoded = encode_logs_data(&logs);
+    let bytes_logs_lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+    let mut group = c.benchmark_group("flat_vs_nested_records");
+
+    group.bench_function("otlp_bytes_lazy_nested", |b| {
         b.iter(|| {
-            let mut bytes_logs = otlp_bytes::LogsData::new();
-            bytes_logs.parse(black_box(&encoded));
+            let mut severity_sum = 0i64;
+            for resource in black_box(&bytes_logs_lazy).resource_logs() {
+                for scope in resource.scope_logs() {
+                    for record in scope.log_records() {
+                        severity_sum += record.severity_number() as i64;
+                    }
+                }
+            }
+            severity_sum
         })
     });
 
-    group.bench_function("otlp_bytes_lazy_parse", |b| {
+    group.bench_function("otlp_bytes_lazy_collect_records", |b| {
         b.iter(|| {
-            otlp_bytes_lazy::LogsDataParser::new(black_box(&encoded));
+            black_box(&bytes_logs_lazy)
+                .collect_records()
+                .iter()
+                .map(|flat| flat.record.severity_number() as i64)
+                .sum::<i64>()
         })
     });
-    
+
     group.finish();
 }
 
-fn bench_traversal_only_comparison(c: &mut Criterion) {
-    let logs = create_test_logs();
+fn bench_scope_metadata(c: &mut Criterion) {
+    let logs = create_large_test_logs();
     let encoded = encode_logs_data(&logs);
+    let bytes_logs_lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
     let mut bytes_logs = otlp_bytes::LogsData::new();
     bytes_logs.parse(&encoded);
 
+    let mut group = c.benchmark_group("scope_metadata");
+
+    group.bench_function("otlp_bytes_lazy_name_and_version", |b| {
+        b.iter(|| {
+            for resource in black_box(&bytes_logs_lazy).resource_logs() {
+                for scope in resource.scope_logs() {
+                    black_box(scope.scope_name());
+                    black_box(scope.scope_version());
+                }
+            }
+        })
+    });
+
+    group.bench_function("otlp_bytes_eager_name_and_version", |b| {
+        b.iter(|| {
+            for resource in black_box(&bytes_logs).resources() {
+                for scope in resource.scopes() {
+                    black_box(scope.scope());
+                    black_box(scope.version());
+                }
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_projection(c: &mut Criterion) {
+    let logs = create_large_test_logs();
+    let encoded = encode_logs_data(&logs);
     let bytes_logs_lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
-    
-    let mut group = c.benchmark_group("traversal_only_comparison");
-    
-    group.bench_function("prost_structs", |b| {
+    let projection = otlp_bytes_lazy::ProjectionMask::TIME_UNIX_NANO | otlp_bytes_lazy::ProjectionMask::SEVERITY_NUMBER;
+
+    let mut group = c.benchmark_group("projection");
+
+    group.bench_function("otlp_bytes_lazy_all_fields", |b| {
         b.iter(|| {
-            traverse_logs(black_box(&logs))
+            let mut sum = 0u64;
+            for resource in black_box(&bytes_logs_lazy).resource_logs() {
+                for scope in resource.scope_logs() {
+                    for record in scope.log_records() {
+                        sum = sum.wrapping_add(record.time_unix_nano()).wrapping_add(record.severity_number() as u64);
+                    }
+                }
+            }
+            sum
         })
     });
-    
-    group.bench_function("otlp_bytes", |b| {
+
+    group.bench_function("otlp_bytes_lazy_time_and_severity", |b| {
         b.iter(|| {
-            traverse_logs(black_box(&bytes_logs))
+            let mut sum = 0u64;
+            for resource in black_box(&bytes_logs_lazy).resource_logs() {
+                for scope in resource.scope_logs() {
+                    for record in scope.log_records_with_projection(projection) {
+                        sum = sum.wrapping_add(record.time_unix_nano()).wrapping_add(record.severity_number() as u64);
+                    }
+                }
+            }
+            sum
         })
     });
 
-    group.bench_function("otlp_bytes_lazy", |b| {
+    group.finish();
+}
+
+fn bench_arena_reuse(c: &mut Criterion) {
+    // Many small independent batches, as a collector sees them
+    let batches: Vec<Vec<u8>> = (1..=16)
+        .map(|n| encode_logs_data(&create_varied_large_logs(n)))
+        .collect();
+
+    let mut group = c.benchmark_group("arena_reuse");
+
+    group.bench_function("fresh_logs_data_per_batch", |b| {
         b.iter(|| {
-            traverse_otlp_bytes_lazy_logs(black_box(&bytes_logs_lazy))
+            for batch in &batches {
+                let mut logs = otlp_bytes::LogsData::new();
+                logs.parse(black_box(batch));
+                black_box(logs.used_count);
+            }
         })
     });
-    
+
+    let mut arena = otlp_bytes::LogsArena::new();
+    group.bench_function("logs_arena", |b| {
+        b.iter(|| {
+            for batch in &batches {
+                let logs = arena.parse_into(black_box(batch)).unwrap();
+                black_box(logs.used_count);
+            }
+        })
+    });
+
     group.finish();
 }
 
-fn bench_parse_and_traversal_comparison(c: &mut Criterion) {
-    let logs = create_test_logs();
+fn bench_value_type(c: &mut Criterion) {
+    let logs = create_varied_large_logs(200);
     let encoded = encode_logs_data(&logs);
-    
-    let mut group = c.benchmark_group("parse_and_traversal_comparison");
-    
-    group.bench_function("prost_parse_and_traversal", |b| {
+    let bytes_logs_lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+    let records = bytes_logs_lazy.collect_records();
+    let values: Vec<_> = records
+        .iter()
+        .flat_map(|flat| flat.record.attributes())
+        .filter_map(|attr| attr.value())
+        .collect();
+
+    let mut group = c.benchmark_group("value_type");
+
+    group.bench_function("otlp_bytes_lazy_value_type", |b| {
         b.iter(|| {
-            // Decode bytes back to prost structs
-            let decoded_logs = LogsData::decode(black_box(&encoded[..])).expect("Failed to decode");
-            traverse_logs(black_box(&decoded_logs))
+            for value in black_box(&values) {
+                black_box(value.value_type());
+            }
         })
     });
-    
+
+    group.bench_function("otlp_bytes_lazy_value_type_fast", |b| {
+        b.iter(|| {
+            for value in black_box(&values) {
+                black_box(value.value_type_fast());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_resource_attribute_access(c: &mut Criterion) {
+    let logs = create_large_test_logs();
+    let encoded = encode_logs_data(&logs);
     let mut bytes_logs = otlp_bytes::LogsData::new();
-    group.bench_function("otlp_bytes_parse_and_traversal", |b| {
+    bytes_logs.parse(&encoded);
+    let bytes_logs_lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+    let resources: Vec<_> = bytes_logs_lazy.resource_logs().collect();
+
+    // Each resource is asked for its service name several times, as when
+    // every record of a batch is tagged with it
+    const LOOKUPS: usize = 8;
+
+    let mut group = c.benchmark_group("resource_attribute_access");
+
+    group.bench_function("otlp_bytes_get_service_name", |b| {
         b.iter(|| {
-            bytes_logs.parse(black_box(&encoded));
-            traverse_logs(black_box(&bytes_logs))
+            let mut len = 0;
+            for resource in black_box(&bytes_logs).into_iter() {
+                for _ in 0..LOOKUPS {
+                    len += resource.resource.as_ref().and_then(|r| r.get_service_name()).map_or(0, str::len);
+                }
+            }
+            len
         })
     });
 
-    group.bench_function("otlp_bytes_lazy_parse_and_traversal", |b| {
+    group.bench_function("otlp_bytes_lazy_scan", |b| {
         b.iter(|| {
-            let bytes_logs = otlp_bytes_lazy::LogsDataParser::new(black_box(&encoded));
-            traverse_otlp_bytes_lazy_logs(black_box(&bytes_logs))
+            let mut len = 0;
+            for resource in black_box(&resources) {
+                for _ in 0..LOOKUPS {
+                    len += resource
+                        .attributes()
+                        .and_then(|mut attrs| attrs.find(|attr| attr.key() == Some("service.name")))
+                        .and_then(|attr| attr.value())
+                        .and_then(|value| value.string_value())
+                        .map_or(0, str::len);
+                }
+            }
+            len
         })
     });
-    
+
+    group.bench_function("otlp_bytes_lazy_cached", |b| {
+        b.iter(|| {
+            let mut len = 0;
+            for resource in black_box(&resources) {
+                for _ in 0..LOOKUPS {
+                    len += resource.service_name().map_or(0, str::len);
+                }
+            }
+            len
+        })
+    });
+
     group.finish();
 }
+
+fn bench_key_interning(c: &mut Criterion) {
+    let logs = create_large_test_logs();
+    let encoded = encode_logs_data(&logs);
+    let bytes_logs_lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+    let records = bytes_logs_lazy.collect_records();
+
+    let mut group = c.benchmark_group("key_interning");
+
+    group.bench_function("otlp_bytes_lazy_str_keys", |b| {
+        b.iter(|| {
+            let mut matches = 0;
+            for flat in black_box(&records) {
+                for attr in flat.record.attributes() {
+                    if attr.key() == Some("status_code") {
+                        matches += 1;
+                    }
+                }
+            }
+            matches
+        })
+    });
+
+    let mut interner = intern::KeyInterner::new();
+    let status_code = interner.intern("status_code");
+    group.bench_function("otlp_bytes_lazy_interned_keys", |b| {
+        b.iter(|| {
+            let mut matches = 0;
+            for flat in black_box(&records) {
+                for (id, _) in flat.record.attributes_interned(&mut interner) {
+                    if id == status_code {
+                        matches += 1;
+                    }
+                }
+            }
+            matches
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_string_field_access(c: &mut Criterion) {
+    let logs = create_large_test_logs();
+    let encoded = encode_logs_data(&logs);
+    let bytes_logs_lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+    let mut group = c.benchmark_group("string_field_access");
+
+    // Reads severity_text and event_name several times per record, as a
+    // formatter that checks, then prints, a field would
+    for (name, validated) in [("otlp_bytes_lazy_validate_per_call", false), ("otlp_bytes_lazy_validated_cache", true)] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let mut total = 0usize;
+                for resource in black_box(&bytes_logs_lazy).resource_logs() {
+                    for scope in resource.scope_logs() {
+                        for record in scope.log_records() {
+                            let record = if validated { record.with_validated_strings() } else { record };
+                            for _ in 0..3 {
+                                total += record.severity_text().map_or(0, str::len);
+                                total += record.event_name().map_or(0, str::len);
+                            }
+                        }
+                    }
+                }
+                total
+            })
+        });
+    }
+
+    group.finish();
+}
+
+/// Records in the batch through the view traits, touching nothing else
+fn count_records<'a, L: LogsView<'a>>(logs: &'a L) -> usize {
+    let mut record_count = 0;
+    for resource in logs.resources() {
+        for scope in resource.scopes() {
+            record_count += scope.log_records().count();
+        }
+    }
+    record_count
+}
+
+/// Records in the batch through the lazy parser. `count` tallies tags
+/// without building a parser per record.
+fn count_otlp_bytes_lazy_records(logs: &otlp_bytes_lazy::LogsDataParser) -> usize {
+    let mut record_count = 0;
+    for resource in logs.resource_logs() {
+        for scope in resource.scope_logs() {
+            record_count += scope.log_records().count();
+        }
+    }
+    record_count
+}
+
+/// Like `count_otlp_bytes_lazy_records`, but stepping through every record
+/// parser the way a generic loop would, bypassing the count fast path
+fn count_otlp_bytes_lazy_records_by_item(logs: &otlp_bytes_lazy::LogsDataParser) -> usize {
+    let mut record_count = 0;
+    for resource in logs.resource_logs() {
+        for scope in resource.scope_logs() {
+            for record in scope.log_records() {
+                black_box(&record);
+                record_count += 1;
+            }
+        }
+    }
+    record_count
+}
+
+fn bench_count_only(c: &mut Criterion) {
+    let mut group = c.benchmark_group("count_only");
+
+    for (suffix, logs) in comparison_datasets() {
+        let encoded = encode_logs_data(&logs);
+
+        // Every variant must agree, or the timings compare different work
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert!(bytes_logs.parse(&encoded));
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let expected = count_records(&logs);
+        assert_eq!(count_records(&bytes_logs), expected);
+        assert_eq!(count_otlp_bytes_lazy_records(&lazy), expected);
+        assert_eq!(count_otlp_bytes_lazy_records_by_item(&lazy), expected);
+
+        group.bench_function(format!("prost_decode_and_count{}", suffix), |b| {
+            b.iter(|| {
+                let logs = LogsData::decode(black_box(&encoded[..])).expect("Failed to decode");
+                count_records(&logs)
+            })
+        });
+
+        group.bench_function(format!("otlp_bytes_parse_and_count{}", suffix), |b| {
+            b.iter(|| {
+                let mut bytes_logs = otlp_bytes::LogsData::new();
+                bytes_logs.parse(black_box(&encoded));
+                count_records(&bytes_logs)
+            })
+        });
+
+        group.bench_function(format!("otlp_bytes_lazy_count{}", suffix), |b| {
+            b.iter(|| count_otlp_bytes_lazy_records(&otlp_bytes_lazy::LogsDataParser::new(black_box(&encoded))))
+        });
+
+        group.bench_function(format!("otlp_bytes_lazy_count_by_item{}", suffix), |b| {
+            b.iter(|| count_otlp_bytes_lazy_records_by_item(&otlp_bytes_lazy::LogsDataParser::new(black_box(&encoded))))
+        });
+    }
+
+    group.finish();
+}
+
 // Create a larger dataset for more meaningful benchmarks
 pub fn create_large_test_logs() -> LogsData {
     let mut logs: LogsData = create_test_logs();
@@ -193,6 +585,15 @@ criterion_group!(
     benches,
     bench_parsing_only_comparison,
     bench_traversal_only_comparison,
-    bench_parse_and_traversal_comparison
+    bench_parse_and_traversal_comparison,
+    bench_flat_vs_nested_records,
+    bench_scope_metadata,
+    bench_projection,
+    bench_arena_reuse,
+    bench_value_type,
+    bench_resource_attribute_access,
+    bench_key_interning,
+    bench_string_field_access,
+    bench_count_only
 );
 criterion_main!(benches);
\ No newline at end of file
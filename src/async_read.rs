@@ -0,0 +1,111 @@
+use std::fmt;
+use std::io;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Largest frame `AsyncLogsFrameReader` accepts unless configured otherwise
+pub const DEFAULT_MAX_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+/// Error reading a length-prefixed frame
+#[derive(Debug)]
+pub enum FrameReadError {
+    /// The underlying reader failed, or the stream ended inside a frame
+    Io(io::Error),
+    /// The length prefix is not a valid varint
+    InvalidLength,
+    /// The length prefix exceeds the configured maximum
+    TooLarge { len: u64, max: usize },
+}
+
+impl fmt::Display for FrameReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameReadError::Io(err) => write!(f, "reading frame: {}", err),
+            FrameReadError::InvalidLength => write!(f, "invalid varint frame length"),
+            FrameReadError::TooLarge { len, max } => write!(f, "frame of {} bytes exceeds maximum of {}", len, max),
+        }
+    }
+}
+
+impl std::error::Error for FrameReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FrameReadError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FrameReadError {
+    fn from(err: io::Error) -> Self {
+        FrameReadError::Io(err)
+    }
+}
+
+/// Reads varint length-prefixed OTLP messages (as written by prost's
+/// `encode_length_delimited`) from an `AsyncRead`. Only the I/O is async:
+/// each frame comes back as an owned buffer to hand to `LogsDataParser` or
+/// `otlp_bytes::LogsData::parse`.
+pub struct AsyncLogsFrameReader<R> {
+    reader: R,
+    max_frame_len: usize,
+}
+
+impl<R: AsyncRead + Unpin> AsyncLogsFrameReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            max_frame_len: DEFAULT_MAX_FRAME_LEN,
+        }
+    }
+
+    /// Reject frames longer than `max_frame_len` before allocating for them
+    pub fn with_max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.max_frame_len = max_frame_len;
+        self
+    }
+
+    /// Read the next frame. Returns `None` when the stream ends cleanly
+    /// between frames; ending partway through a frame is an error.
+    pub async fn next_frame(&mut self) -> Option<Result<Vec<u8>, FrameReadError>> {
+        let len = match self.read_length().await {
+            Ok(Some(len)) => len,
+            Ok(None) => return None,
+            Err(err) => return Some(Err(err)),
+        };
+        if len > self.max_frame_len as u64 {
+            return Some(Err(FrameReadError::TooLarge { len, max: self.max_frame_len }));
+        }
+
+        let mut frame = vec![0; len as usize];
+        Some(self.reader.read_exact(&mut frame).await.map(|_| frame).map_err(FrameReadError::from))
+    }
+
+    /// Give back the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the varint length prefix a byte at a time, so no bytes of the
+    /// following frame are consumed. `None` on EOF before the first byte.
+    async fn read_length(&mut self) -> Result<Option<u64>, FrameReadError> {
+        let mut len = 0u64;
+        for shift in (0..64).step_by(7) {
+            let mut byte = [0u8];
+            if self.reader.read(&mut byte).await? == 0 {
+                return match shift {
+                    0 => Ok(None),
+                    _ => Err(io::Error::from(io::ErrorKind::UnexpectedEof).into()),
+                };
+            }
+            // The 10th byte holds only bit 63; anything more overflows u64
+            if shift == 63 && byte[0] > 1 {
+                return Err(FrameReadError::InvalidLength);
+            }
+            len |= u64::from(byte[0] & 0x7f) << shift;
+            if byte[0] & 0x80 == 0 {
+                return Ok(Some(len));
+            }
+        }
+        Err(FrameReadError::InvalidLength)
+    }
+}
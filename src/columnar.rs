@@ -0,0 +1,156 @@
+use crate::otlp_bytes::is_scope_logs_tag;
+use crate::otlp_bytes_lazy::{AnyValueParser, KeyValueParser, ProtobufParser};
+use std::ops::Range;
+
+/// Logs laid out as parallel columns (struct-of-arrays) for scans that
+/// touch one field across every record, such as severity histograms or
+/// time-range filters. Index `i` of every per-record column describes the
+/// same record, in wire order across the whole batch.
+///
+/// Bodies and attribute values stay encoded: columns hold their byte
+/// ranges within the input, decoded on demand with `body` and
+/// `attributes`. Absent timestamps and severities read as 0, as in the
+/// lazy parser.
+#[derive(Debug, Default)]
+pub struct ColumnarLogs<'a> {
+    data: &'a [u8],
+    /// Index of each record's ResourceLogs within the batch
+    pub resource_indices: Vec<u32>,
+    /// Index of each record's ScopeLogs within its resource
+    pub scope_indices: Vec<u32>,
+    pub severity_numbers: Vec<i32>,
+    pub time_unix_nanos: Vec<u64>,
+    pub observed_time_unix_nanos: Vec<u64>,
+    /// Byte range of each record's encoded body AnyValue within the input
+    pub body_ranges: Vec<Option<Range<usize>>>,
+    pub attributes: AttributeColumns<'a>,
+}
+
+/// Attribute column store shared by all records, in compressed sparse row
+/// form: record `i`'s attributes are entries `offsets[i]..offsets[i + 1]`
+#[derive(Debug)]
+pub struct AttributeColumns<'a> {
+    /// One more entry than there are records
+    pub offsets: Vec<usize>,
+    /// Keys, empty when absent or not valid UTF-8
+    pub keys: Vec<&'a str>,
+    /// Byte range of each encoded AnyValue within the input
+    pub value_ranges: Vec<Option<Range<usize>>>,
+}
+
+impl<'a> Default for AttributeColumns<'a> {
+    fn default() -> Self {
+        Self {
+            offsets: vec![0],
+            keys: Vec::new(),
+            value_ranges: Vec::new(),
+        }
+    }
+}
+
+impl<'a> ColumnarLogs<'a> {
+    /// Number of records
+    pub fn len(&self) -> usize {
+        self.severity_numbers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.severity_numbers.is_empty()
+    }
+
+    /// Record `i`'s body, decoded lazily
+    pub fn body(&self, i: usize) -> Option<AnyValueParser<'a>> {
+        let range = self.body_ranges.get(i)?.clone()?;
+        Some(AnyValueParser::new(&self.data[range]))
+    }
+
+    /// Record `i`'s attributes as key and lazily decoded value, in wire order
+    pub fn attributes(&self, i: usize) -> impl Iterator<Item = (&'a str, Option<AnyValueParser<'a>>)> + '_ {
+        let columns = &self.attributes;
+        let entries = match (columns.offsets.get(i), columns.offsets.get(i + 1)) {
+            (Some(&start), Some(&end)) => start..end,
+            _ => 0..0,
+        };
+        entries.map(move |entry| {
+            let value = columns.value_ranges[entry]
+                .clone()
+                .map(|range| AnyValueParser::new(&self.data[range]));
+            (columns.keys[entry], value)
+        })
+    }
+}
+
+/// Parse an encoded `LogsData` straight into columns, walking each message
+/// once. Malformed trailing bytes end the walk of the message they are in,
+/// keeping what was read before them.
+pub fn parse_columnar(data: &[u8]) -> ColumnarLogs<'_> {
+    let mut columns = ColumnarLogs {
+        data,
+        ..ColumnarLogs::default()
+    };
+
+    for (resource_index, resource) in length_delimited(data, |tag| tag == 1).enumerate() {
+        for (scope_index, scope) in length_delimited(resource, is_scope_logs_tag).enumerate() {
+            for record in length_delimited(scope, |tag| tag == 2) {
+                columns.resource_indices.push(resource_index as u32);
+                columns.scope_indices.push(scope_index as u32);
+                push_record(&mut columns, record);
+            }
+        }
+    }
+
+    columns
+}
+
+/// Payloads of the length-delimited fields of `message` whose tag matches
+fn length_delimited(message: &[u8], tag_matches: impl Fn(u32) -> bool) -> impl Iterator<Item = &[u8]> {
+    ProtobufParser::new(message)
+        .walk_fields()
+        .filter(move |&(tag, wire_type, _)| wire_type == 2 && tag_matches(tag))
+        .map(|(_, _, payload)| payload)
+}
+
+/// Append one record's fields to every column. Singular fields follow
+/// protobuf's last-one-wins rule.
+fn push_record<'a>(columns: &mut ColumnarLogs<'a>, record: &'a [u8]) {
+    let mut severity_number = 0;
+    let mut time_unix_nano = 0;
+    let mut observed_time_unix_nano = 0;
+    let mut body = None;
+
+    for (tag, wire_type, payload) in ProtobufParser::new(record).walk_fields() {
+        match (tag, wire_type) {
+            (1, 1) => time_unix_nano = fixed64(payload),
+            (11, 1) => observed_time_unix_nano = fixed64(payload),
+            (2, 0) => {
+                severity_number = prost::encoding::decode_varint(&mut &payload[..]).map_or(0, |value| value as i32)
+            }
+            (5, 2) => body = Some(range_in(columns.data, payload)),
+            (6, 2) => {
+                let attr = KeyValueParser::new(payload);
+                columns.attributes.keys.push(attr.key().unwrap_or(""));
+                columns
+                    .attributes
+                    .value_ranges
+                    .push(attr.value().map(|value| range_in(columns.data, value.raw_bytes())));
+            }
+            _ => {}
+        }
+    }
+
+    columns.severity_numbers.push(severity_number);
+    columns.time_unix_nanos.push(time_unix_nano);
+    columns.observed_time_unix_nanos.push(observed_time_unix_nano);
+    columns.body_ranges.push(body);
+    columns.attributes.offsets.push(columns.attributes.keys.len());
+}
+
+fn fixed64(payload: &[u8]) -> u64 {
+    payload.try_into().map_or(0, u64::from_le_bytes)
+}
+
+/// Position of `slice`, a subslice of `data`, within `data`
+fn range_in(data: &[u8], slice: &[u8]) -> Range<usize> {
+    let start = slice.as_ptr() as usize - data.as_ptr() as usize;
+    start..start + slice.len()
+}
@@ -0,0 +1,258 @@
+use std::borrow::Borrow;
+use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView, ValueType};
+
+/// A single structural mismatch between two logs hierarchies
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// Location of the mismatch, e.g. `resource[0].scope[1].record[0].attribute[2]`
+    pub path: String,
+    /// Name of the field that differs
+    pub field: &'static str,
+    pub left: String,
+    pub right: String,
+}
+
+impl Difference {
+    fn new(path: &str, field: &'static str, left: impl ToString, right: impl ToString) -> Self {
+        Self {
+            path: path.to_string(),
+            field,
+            left: left.to_string(),
+            right: right.to_string(),
+        }
+    }
+}
+
+/// Check whether two logs hierarchies are structurally equal, regardless of backend
+pub fn logs_equal<'a, 'b, A, B>(a: &'a A, b: &'b B) -> bool
+where
+    A: LogsView<'a>,
+    B: LogsView<'b>,
+{
+    diff(a, b).is_empty()
+}
+
+/// Walk both hierarchies and report every mismatching resource, scope,
+/// record and attribute. When child counts differ, the common prefix is
+/// still compared.
+pub fn diff<'a, 'b, A, B>(a: &'a A, b: &'b B) -> Vec<Difference>
+where
+    A: LogsView<'a>,
+    B: LogsView<'b>,
+{
+    let mut differences = Vec::new();
+
+    let left: Vec<_> = a.resources().collect();
+    let right: Vec<_> = b.resources().collect();
+    if left.len() != right.len() {
+        differences.push(Difference::new("", "resource_count", left.len(), right.len()));
+    }
+
+    for (resource_idx, (left, right)) in left.into_iter().zip(right).enumerate() {
+        let path = format!("resource[{}]", resource_idx);
+        diff_resources(&path, left, right, &mut differences);
+    }
+
+    differences
+}
+
+fn diff_resources<'a, 'b, A, B>(path: &str, a: &'a A, b: &'b B, differences: &mut Vec<Difference>)
+where
+    A: ResourceLogsView<'a>,
+    B: ResourceLogsView<'b>,
+{
+    if a.resource() != b.resource() {
+        differences.push(Difference::new(path, "resource", a.resource(), b.resource()));
+    }
+    if a.schema_url() != b.schema_url() {
+        differences.push(Difference::new(path, "schema_url", format!("{:?}", a.schema_url()), format!("{:?}", b.schema_url())));
+    }
+    if a.dropped_attributes_count() != b.dropped_attributes_count() {
+        differences.push(Difference::new(path, "dropped_attributes_count", a.dropped_attributes_count(), b.dropped_attributes_count()));
+    }
+    diff_attributes(path, "resource_attribute", "resource_attribute_count", a.resource_attributes(), b.resource_attributes(), differences);
+
+    let left: Vec<_> = a.scopes().collect();
+    let right: Vec<_> = b.scopes().collect();
+    if left.len() != right.len() {
+        differences.push(Difference::new(path, "scope_count", left.len(), right.len()));
+    }
+
+    for (scope_idx, (left, right)) in left.into_iter().zip(right).enumerate() {
+        let path = format!("{}.scope[{}]", path, scope_idx);
+        diff_scopes(&path, left, right, differences);
+    }
+}
+
+fn diff_scopes<'a, 'b, A, B>(path: &str, a: &'a A, b: &'b B, differences: &mut Vec<Difference>)
+where
+    A: ScopeLogsView<'a>,
+    B: ScopeLogsView<'b>,
+{
+    if a.scope() != b.scope() {
+        differences.push(Difference::new(path, "scope", a.scope(), b.scope()));
+    }
+    if a.version() != b.version() {
+        differences.push(Difference::new(path, "version", format!("{:?}", a.version()), format!("{:?}", b.version())));
+    }
+    if a.schema_url() != b.schema_url() {
+        differences.push(Difference::new(path, "schema_url", format!("{:?}", a.schema_url()), format!("{:?}", b.schema_url())));
+    }
+    if a.dropped_attributes_count() != b.dropped_attributes_count() {
+        differences.push(Difference::new(path, "dropped_attributes_count", a.dropped_attributes_count(), b.dropped_attributes_count()));
+    }
+
+    let left: Vec<_> = a.log_records().collect();
+    let right: Vec<_> = b.log_records().collect();
+    if left.len() != right.len() {
+        differences.push(Difference::new(path, "record_count", left.len(), right.len()));
+    }
+
+    for (record_idx, (left, right)) in left.into_iter().zip(right).enumerate() {
+        let path = format!("{}.record[{}]", path, record_idx);
+        diff_records(&path, left, right, differences);
+    }
+}
+
+fn diff_records<'a, 'b, A, B>(path: &str, a: &'a A, b: &'b B, differences: &mut Vec<Difference>)
+where
+    A: LogRecordView<'a>,
+    B: LogRecordView<'b>,
+{
+    if a.name() != b.name() {
+        differences.push(Difference::new(path, "name", a.name(), b.name()));
+    }
+    if a.time_unix_nano() != b.time_unix_nano() {
+        differences.push(Difference::new(path, "time_unix_nano", format!("{:?}", a.time_unix_nano()), format!("{:?}", b.time_unix_nano())));
+    }
+    if a.observed_time_unix_nano() != b.observed_time_unix_nano() {
+        differences.push(Difference::new(
+            path,
+            "observed_time_unix_nano",
+            format!("{:?}", a.observed_time_unix_nano()),
+            format!("{:?}", b.observed_time_unix_nano()),
+        ));
+    }
+    if a.severity_number() != b.severity_number() {
+        differences.push(Difference::new(path, "severity_number", a.severity_number(), b.severity_number()));
+    }
+    if a.severity_text() != b.severity_text() {
+        differences.push(Difference::new(path, "severity_text", format!("{:?}", a.severity_text()), format!("{:?}", b.severity_text())));
+    }
+    if !optional_values_equal(a.body(), b.body()) {
+        differences.push(Difference::new(
+            path,
+            "body",
+            a.body().map(describe_value).unwrap_or_default(),
+            b.body().map(describe_value).unwrap_or_default(),
+        ));
+    }
+    if a.trace_id() != b.trace_id() {
+        differences.push(Difference::new(path, "trace_id", format!("{:?}", a.trace_id()), format!("{:?}", b.trace_id())));
+    }
+    if a.span_id() != b.span_id() {
+        differences.push(Difference::new(path, "span_id", format!("{:?}", a.span_id()), format!("{:?}", b.span_id())));
+    }
+    if a.flags() != b.flags() {
+        differences.push(Difference::new(path, "flags", a.flags(), b.flags()));
+    }
+    if a.event_name() != b.event_name() {
+        differences.push(Difference::new(path, "event_name", format!("{:?}", a.event_name()), format!("{:?}", b.event_name())));
+    }
+    if a.dropped_attributes_count() != b.dropped_attributes_count() {
+        differences.push(Difference::new(path, "dropped_attributes_count", a.dropped_attributes_count(), b.dropped_attributes_count()));
+    }
+    diff_attributes(path, "attribute", "attribute_count", a.attributes(), b.attributes(), differences);
+}
+
+/// Compare attribute lists pairwise, reporting entries as `<path>.<element>[i]`
+/// and a length mismatch under `count_field`
+fn diff_attributes<'x, 'y, X, Y>(
+    path: &str,
+    element: &str,
+    count_field: &'static str,
+    left: impl Iterator<Item = &'x X>,
+    right: impl Iterator<Item = &'y Y>,
+    differences: &mut Vec<Difference>,
+) where
+    X: AttributeView + 'x,
+    Y: AttributeView + 'y,
+{
+    let left: Vec<_> = left.collect();
+    let right: Vec<_> = right.collect();
+    if left.len() != right.len() {
+        differences.push(Difference::new(path, count_field, left.len(), right.len()));
+    }
+
+    for (attr_idx, (left, right)) in left.into_iter().zip(right).enumerate() {
+        let path = format!("{}.{}[{}]", path, element, attr_idx);
+        if left.key() != right.key() {
+            differences.push(Difference::new(&path, "key", left.key(), right.key()));
+        }
+        if !optional_values_equal(left.value(), right.value()) {
+            differences.push(Difference::new(
+                &path,
+                "value",
+                left.value().map(describe_value).unwrap_or_default(),
+                right.value().map(describe_value).unwrap_or_default(),
+            ));
+        }
+    }
+}
+
+fn optional_values_equal<X: AnyValueView, Y: AnyValueView>(x: Option<&X>, y: Option<&Y>) -> bool {
+    match (x, y) {
+        (Some(x), Some(y)) => values_equal(x, y),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Compare two values from (possibly) different backends, recursing into arrays and kvlists
+fn values_equal<X: AnyValueView, Y: AnyValueView>(x: &X, y: &Y) -> bool {
+    if x.value_type() != y.value_type() {
+        return false;
+    }
+
+    match x.value_type() {
+        ValueType::String => x.as_string() == y.as_string(),
+        ValueType::Bool => x.as_bool() == y.as_bool(),
+        ValueType::Int64 => x.as_int64() == y.as_int64(),
+        ValueType::Double => x.as_double() == y.as_double(),
+        ValueType::Bytes => x.as_bytes() == y.as_bytes(),
+        ValueType::Array => match (x.array_iter(), y.array_iter()) {
+            (Some(xs), Some(ys)) => {
+                let xs: Vec<_> = xs.collect();
+                let ys: Vec<_> = ys.collect();
+                xs.len() == ys.len()
+                    && xs.iter().zip(&ys).all(|(x, y)| values_equal(x.borrow(), y.borrow()))
+            }
+            (xs, ys) => xs.is_none() && ys.is_none(),
+        },
+        ValueType::KeyValueList => match (x.kvlist_iter(), y.kvlist_iter()) {
+            (Some(xs), Some(ys)) => {
+                let xs: Vec<_> = xs.collect();
+                let ys: Vec<_> = ys.collect();
+                xs.len() == ys.len()
+                    && xs.iter().zip(&ys).all(|(x, y)| {
+                        let (x, y) = (x.borrow(), y.borrow());
+                        x.key() == y.key()
+                            && optional_values_equal(x.value(), y.value())
+                    })
+            }
+            (xs, ys) => xs.is_none() && ys.is_none(),
+        },
+    }
+}
+
+fn describe_value<V: AnyValueView>(value: &V) -> String {
+    match value.value_type() {
+        ValueType::String => format!("{:?}", value.as_string().unwrap_or_default()),
+        ValueType::Bool => format!("{}", value.as_bool().unwrap_or_default()),
+        ValueType::Int64 => format!("{}", value.as_int64().unwrap_or_default()),
+        ValueType::Double => format!("{}", value.as_double().unwrap_or_default()),
+        ValueType::Bytes => format!("bytes[{}]", value.as_bytes().map(|b| b.len()).unwrap_or(0)),
+        ValueType::Array => format!("array[{}]", value.array_iter().map(|a| a.count()).unwrap_or(0)),
+        ValueType::KeyValueList => format!("kvlist[{}]", value.kvlist_iter().map(|kv| kv.count()).unwrap_or(0)),
+    }
+}
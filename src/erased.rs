@@ -0,0 +1,272 @@
+use crate::otlp_bytes;
+use crate::otlp_bytes_lazy::{self, NestedAttributePolicy};
+use crate::proto::opentelemetry::proto::logs::v1 as pb;
+use crate::util::{flatten_attributes, ScalarValue};
+use crate::{AnyValueView, LogRecordView, LogsView, ResourceLogsView, ScopeLogsView};
+use prost::Message;
+use std::fmt;
+
+/// Parser implementation to decode with, e.g. as picked by a config flag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Owned prost structs
+    Prost,
+    /// Reusable eager parser (`otlp_bytes`)
+    Eager,
+    /// Zero-copy lazy parser (`otlp_bytes_lazy`)
+    Lazy,
+}
+
+/// Logs decoded by whichever backend `parse_logs` was asked for
+pub enum BoxedLogs<'a> {
+    Prost(pb::LogsData),
+    Eager(otlp_bytes::LogsData<'a>),
+    Lazy(otlp_bytes_lazy::LogsDataParser<'a>),
+}
+
+impl<'a> BoxedLogs<'a> {
+    /// The logs behind the backend-independent facade
+    pub fn as_view(&self) -> &dyn ErasedLogsView {
+        match self {
+            BoxedLogs::Prost(logs) => logs,
+            BoxedLogs::Eager(logs) => logs,
+            BoxedLogs::Lazy(logs) => logs,
+        }
+    }
+}
+
+/// Error from `parse_logs`
+#[derive(Debug)]
+pub enum ParseLogsError {
+    /// prost rejected the message
+    Decode(prost::DecodeError),
+    /// The eager parser found a malformed message
+    InvalidProtobuf,
+}
+
+impl fmt::Display for ParseLogsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLogsError::Decode(err) => write!(f, "decoding logs: {}", err),
+            ParseLogsError::InvalidProtobuf => write!(f, "malformed protobuf logs"),
+        }
+    }
+}
+
+impl std::error::Error for ParseLogsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseLogsError::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Decode an encoded `LogsData` with `backend`. The lazy backend defers all
+/// decoding, so it never fails here; malformed parts read as absent later.
+pub fn parse_logs(backend: Backend, data: &[u8]) -> Result<BoxedLogs<'_>, ParseLogsError> {
+    match backend {
+        Backend::Prost => pb::LogsData::decode(data)
+            .map(BoxedLogs::Prost)
+            .map_err(ParseLogsError::Decode),
+        Backend::Eager => {
+            let mut logs = otlp_bytes::LogsData::new();
+            if logs.parse(data) {
+                Ok(BoxedLogs::Eager(logs))
+            } else {
+                Err(ParseLogsError::InvalidProtobuf)
+            }
+        }
+        Backend::Lazy => Ok(BoxedLogs::Lazy(otlp_bytes_lazy::LogsDataParser::new(data))),
+    }
+}
+
+/// Object-safe counterpart of `LogsView`. The view traits can't be used as
+/// `dyn` because of their associated iterator types; here every iterator
+/// is boxed and yields boxed children, at the cost of an allocation per
+/// item. Prefer the generic traits when the backend is known statically.
+pub trait ErasedLogsView {
+    fn resources(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedResourceLogs + '_>> + '_>;
+}
+
+/// Object-safe counterpart of `ResourceLogsView`
+pub trait ErasedResourceLogs {
+    fn resource(&self) -> &str;
+    fn scopes(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedScopeLogs + '_>> + '_>;
+    fn schema_url(&self) -> Option<&str>;
+}
+
+/// Object-safe counterpart of `ScopeLogsView`
+pub trait ErasedScopeLogs {
+    fn scope(&self) -> &str;
+    fn version(&self) -> Option<&str>;
+    fn log_records(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedLogRecord + '_>> + '_>;
+    fn schema_url(&self) -> Option<&str>;
+}
+
+/// Object-safe counterpart of `LogRecordView`. Attributes come back owned
+/// and flattened, since `AnyValueView` is not object-safe either.
+pub trait ErasedLogRecord {
+    fn name(&self) -> &str;
+    fn time_unix_nano(&self) -> Option<u64>;
+    fn observed_time_unix_nano(&self) -> Option<u64>;
+    fn severity_number(&self) -> i32;
+    fn severity_text(&self) -> Option<&str>;
+    /// The body if it is a string, `None` for other or absent bodies
+    fn body_str(&self) -> Option<&str>;
+    /// Attributes flattened into dotted keys, as `util::flatten_attributes`
+    fn attributes(&self) -> Vec<(String, ScalarValue)>;
+}
+
+/// Adapts a `&'r` view-trait implementation to the erased traits
+struct View<'r, T>(&'r T);
+
+impl ErasedLogsView for pb::LogsData {
+    fn resources(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedResourceLogs + '_>> + '_> {
+        Box::new(LogsView::resources(self).map(|resource| Box::new(View(resource)) as Box<dyn ErasedResourceLogs + '_>))
+    }
+}
+
+impl<'a> ErasedLogsView for otlp_bytes::LogsData<'a> {
+    fn resources(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedResourceLogs + '_>> + '_> {
+        Box::new(LogsView::resources(self).map(|resource| Box::new(View(resource)) as Box<dyn ErasedResourceLogs + '_>))
+    }
+}
+
+impl<'r, R: ResourceLogsView<'r>> ErasedResourceLogs for View<'r, R>
+where
+    R::ScopeLogs: 'r,
+{
+    fn resource(&self) -> &str {
+        self.0.resource()
+    }
+
+    fn scopes(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedScopeLogs + '_>> + '_> {
+        Box::new(self.0.scopes().map(|scope| Box::new(View(scope)) as Box<dyn ErasedScopeLogs + '_>))
+    }
+
+    fn schema_url(&self) -> Option<&str> {
+        self.0.schema_url()
+    }
+}
+
+impl<'r, S: ScopeLogsView<'r>> ErasedScopeLogs for View<'r, S>
+where
+    S::LogRecord: 'r,
+{
+    fn scope(&self) -> &str {
+        self.0.scope()
+    }
+
+    fn version(&self) -> Option<&str> {
+        self.0.version()
+    }
+
+    fn log_records(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedLogRecord + '_>> + '_> {
+        Box::new(self.0.log_records().map(|record| Box::new(View(record)) as Box<dyn ErasedLogRecord + '_>))
+    }
+
+    fn schema_url(&self) -> Option<&str> {
+        self.0.schema_url()
+    }
+}
+
+impl<'r, L: LogRecordView<'r>> ErasedLogRecord for View<'r, L> {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn time_unix_nano(&self) -> Option<u64> {
+        self.0.time_unix_nano()
+    }
+
+    fn observed_time_unix_nano(&self) -> Option<u64> {
+        self.0.observed_time_unix_nano()
+    }
+
+    fn severity_number(&self) -> i32 {
+        self.0.severity_number()
+    }
+
+    fn severity_text(&self) -> Option<&str> {
+        self.0.severity_text()
+    }
+
+    fn body_str(&self) -> Option<&str> {
+        self.0.body().and_then(|body| body.as_string())
+    }
+
+    fn attributes(&self) -> Vec<(String, ScalarValue)> {
+        flatten_attributes(self.0)
+    }
+}
+
+// The lazy parsers don't implement the view traits, so they are adapted
+// directly. Defaults for absent names follow the eager backend.
+impl<'a> ErasedLogsView for otlp_bytes_lazy::LogsDataParser<'a> {
+    fn resources(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedResourceLogs + '_>> + '_> {
+        Box::new(self.resource_logs().map(|resource| Box::new(resource) as Box<dyn ErasedResourceLogs + '_>))
+    }
+}
+
+impl<'a> ErasedResourceLogs for otlp_bytes_lazy::ResourceLogsParser<'a> {
+    fn resource(&self) -> &str {
+        self.service_name().unwrap_or("unknown-service")
+    }
+
+    fn scopes(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedScopeLogs + '_>> + '_> {
+        Box::new(self.scope_logs().map(|scope| Box::new(scope) as Box<dyn ErasedScopeLogs + '_>))
+    }
+
+    fn schema_url(&self) -> Option<&str> {
+        otlp_bytes_lazy::ResourceLogsParser::schema_url(self)
+    }
+}
+
+impl<'a> ErasedScopeLogs for otlp_bytes_lazy::ScopeLogsParser<'a> {
+    fn scope(&self) -> &str {
+        Some(self.scope_name()).filter(|name| !name.is_empty()).unwrap_or("unknown-scope")
+    }
+
+    fn version(&self) -> Option<&str> {
+        self.scope_version()
+    }
+
+    fn log_records(&self) -> Box<dyn Iterator<Item = Box<dyn ErasedLogRecord + '_>> + '_> {
+        Box::new(otlp_bytes_lazy::ScopeLogsParser::log_records(self).map(|record| Box::new(record) as Box<dyn ErasedLogRecord + '_>))
+    }
+
+    fn schema_url(&self) -> Option<&str> {
+        otlp_bytes_lazy::ScopeLogsParser::schema_url(self)
+    }
+}
+
+impl<'a> ErasedLogRecord for otlp_bytes_lazy::LogRecordParser<'a> {
+    fn name(&self) -> &str {
+        otlp_bytes_lazy::LogRecordParser::name(self)
+    }
+
+    fn time_unix_nano(&self) -> Option<u64> {
+        Some(otlp_bytes_lazy::LogRecordParser::time_unix_nano(self)).filter(|&t| t != 0)
+    }
+
+    fn observed_time_unix_nano(&self) -> Option<u64> {
+        Some(otlp_bytes_lazy::LogRecordParser::observed_time_unix_nano(self)).filter(|&t| t != 0)
+    }
+
+    fn severity_number(&self) -> i32 {
+        otlp_bytes_lazy::LogRecordParser::severity_number(self)
+    }
+
+    fn severity_text(&self) -> Option<&str> {
+        otlp_bytes_lazy::LogRecordParser::severity_text(self).filter(|text| !text.is_empty())
+    }
+
+    fn body_str(&self) -> Option<&str> {
+        self.body_value().and_then(|body| body.string_value())
+    }
+
+    fn attributes(&self) -> Vec<(String, ScalarValue)> {
+        self.to_compact_with(NestedAttributePolicy::Flatten).attributes
+    }
+}
@@ -0,0 +1,130 @@
+use crate::proto::opentelemetry::proto::{common::v1::*, logs::v1::*};
+use crate::{otlp_bytes, otlp_bytes_lazy, util, create_test_logs, encode_logs_data};
+use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView};
+use prost::Message;
+use std::hint::black_box;
+
+/// Limits for rendering values while fuzzing, so deep inputs stay cheap
+const MAX_DEPTH: usize = 8;
+const MAX_ELEMS: usize = 8;
+
+/// The eager parser recurses once per nesting level, so unbounded depth
+/// would let a deeply nested body overflow the stack
+const PARSE_LIMITS: otlp_bytes::ParseLimits = otlp_bytes::ParseLimits {
+    max_depth: util::MAX_HASH_DEPTH,
+    ..otlp_bytes::ParseLimits::UNLIMITED
+};
+
+/// Fuzz target: parse arbitrary bytes with both the eager and the lazy
+/// parser and touch every accessor reachable from the result. Must never
+/// panic, whatever the input; `cargo fuzz` targets call straight into it.
+pub fn fuzz_parse_logs(data: &[u8]) {
+    let mut logs = otlp_bytes::LogsData::new();
+    let _ = logs.parse_with_limits(data, &PARSE_LIMITS);
+    traverse_view(&logs);
+
+    let lazy = otlp_bytes_lazy::LogsDataParser::new(data);
+    traverse_lazy(&lazy);
+    black_box(lazy.collect_records().len());
+}
+
+fn traverse_view<'a, L: LogsView<'a>>(logs: &'a L) {
+    for resource in logs.resources() {
+        black_box(resource.resource());
+        for attr in resource.resource_attributes() {
+            touch_attribute(attr);
+        }
+        for scope in resource.scopes() {
+            black_box((scope.scope(), scope.version()));
+            for record in scope.log_records() {
+                black_box((record.name(), record.timestamp(), record.severity_number(), record.severity_text()));
+                if let Some(body) = record.body() {
+                    black_box(util::display_value_deep(body, MAX_DEPTH, MAX_ELEMS));
+                }
+                for attr in record.attributes() {
+                    touch_attribute(attr);
+                }
+                black_box(record.content_hash());
+                black_box(util::flatten_attributes(record));
+            }
+        }
+    }
+}
+
+fn touch_attribute<A: AttributeView>(attr: &A) {
+    black_box(attr.key());
+    if let Some(value) = attr.value() {
+        touch_value(value);
+    }
+}
+
+fn touch_value<V: AnyValueView>(value: &V) {
+    black_box((value.value_type(), value.as_string(), value.as_bool(), value.as_int64(), value.as_double(), value.as_bytes()));
+    black_box(util::display_value_deep(value, MAX_DEPTH, MAX_ELEMS));
+}
+
+fn traverse_lazy(logs: &otlp_bytes_lazy::LogsDataParser) {
+    for resource in logs.resource_logs() {
+        black_box((resource.resource(), resource.schema_url()));
+        for attr in resource.attributes().into_iter().flatten() {
+            touch_attribute(&attr);
+        }
+        for scope in resource.scope_logs() {
+            black_box((scope.scope_name(), scope.scope_version(), scope.schema_url()));
+            for record in scope.log_records() {
+                black_box((record.name(), record.effective_timestamp(), record.severity_number()));
+                black_box((record.flags(), record.trace_id(), record.span_id(), record.dropped_attributes_count()));
+                if let Some(body) = record.body() {
+                    touch_value(&otlp_bytes_lazy::AnyValueParser::new(body));
+                }
+                for attr in record.attributes() {
+                    touch_attribute(&attr);
+                }
+            }
+        }
+    }
+}
+
+/// Seed corpus for `cargo fuzz`: the sample logs and variations of them
+/// (single resources, nested bodies, empty messages) plus truncated copies
+/// that cut fields off mid-way
+pub fn generate_corpus() -> Vec<Vec<u8>> {
+    let sample = create_test_logs();
+    let mut corpus = vec![encode_logs_data(&sample), Vec::new()];
+
+    for resource in &sample.resource_logs {
+        corpus.push(encode_logs_data(&LogsData {
+            resource_logs: vec![resource.clone()],
+        }));
+    }
+
+    let mut nested = sample.clone();
+    let record = &mut nested.resource_logs[0].scope_logs[0].log_records[0];
+    record.body = Some(AnyValue {
+        value: Some(any_value::Value::KvlistValue(KeyValueList {
+            values: vec![KeyValue {
+                key: "lines".to_string(),
+                value: Some(AnyValue {
+                    value: Some(any_value::Value::ArrayValue(ArrayValue {
+                        values: vec![
+                            AnyValue { value: Some(any_value::Value::StringValue("first".to_string())) },
+                            AnyValue { value: Some(any_value::Value::BytesValue(vec![0xde, 0xad])) },
+                        ],
+                    })),
+                }),
+            }],
+        })),
+    });
+    record.trace_id = vec![0x5b; 16];
+    record.span_id = vec![0x8e; 8];
+    corpus.push(encode_logs_data(&nested));
+
+    corpus.push(LogRecord::default().encode_to_vec());
+
+    let full = encode_logs_data(&sample);
+    for len in [1, 2, full.len() / 3, full.len() / 2, full.len() - 1] {
+        corpus.push(full[..len].to_vec());
+    }
+
+    corpus
+}
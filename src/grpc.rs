@@ -0,0 +1,93 @@
+use std::fmt;
+
+/// Length-prefixed message frame header: one flag byte plus a big-endian u32
+const FRAME_HEADER_LEN: usize = 5;
+/// Flag bit marking a frame as compressed
+const FLAG_COMPRESSED: u8 = 0x01;
+/// Flag bit marking a gRPC-Web trailer frame rather than a message
+const FLAG_TRAILER: u8 = 0x80;
+
+/// Error decoding a gRPC or gRPC-Web request body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrpcDecodeError {
+    /// The gRPC-Web text body is not valid base64
+    InvalidBase64,
+    /// A frame header or payload runs past the end of the body
+    Truncated { offset: usize },
+    /// The message frame is compressed, which is not supported
+    Compressed,
+    /// The body holds no message frame, only trailers (or nothing)
+    NoMessage,
+}
+
+impl fmt::Display for GrpcDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GrpcDecodeError::InvalidBase64 => write!(f, "invalid base64 in gRPC-Web body"),
+            GrpcDecodeError::Truncated { offset } => write!(f, "truncated gRPC frame at offset {}", offset),
+            GrpcDecodeError::Compressed => write!(f, "compressed gRPC frames are not supported"),
+            GrpcDecodeError::NoMessage => write!(f, "gRPC body contains no message frame"),
+        }
+    }
+}
+
+impl std::error::Error for GrpcDecodeError {}
+
+/// Strip gRPC length-prefixed framing, returning the message payloads
+/// concatenated (for `LogsData`, concatenated messages merge into one).
+/// gRPC-Web trailer frames are skipped.
+pub fn decode_grpc_frames(body: &[u8]) -> Result<Vec<u8>, GrpcDecodeError> {
+    let mut message = Vec::new();
+    let mut found = false;
+    let mut pos = 0;
+
+    while pos < body.len() {
+        let truncated = || GrpcDecodeError::Truncated { offset: pos };
+        let payload_start = pos.checked_add(FRAME_HEADER_LEN).ok_or_else(truncated)?;
+        let header = body.get(pos..payload_start).ok_or_else(truncated)?;
+        let flags = header[0];
+        let len = u32::from_be_bytes([header[1], header[2], header[3], header[4]]) as usize;
+        let payload_end = payload_start.checked_add(len).ok_or_else(truncated)?;
+        let payload = body.get(payload_start..payload_end).ok_or_else(truncated)?;
+        pos = payload_end;
+
+        if flags & FLAG_TRAILER != 0 {
+            continue;
+        }
+        if flags & FLAG_COMPRESSED != 0 {
+            return Err(GrpcDecodeError::Compressed);
+        }
+        message.extend_from_slice(payload);
+        found = true;
+    }
+
+    if found {
+        Ok(message)
+    } else {
+        Err(GrpcDecodeError::NoMessage)
+    }
+}
+
+/// Decode a `application/grpc-web-text` body: base64-decode it, then strip
+/// the framing, yielding protobuf bytes ready for `LogsDataParser`. Bodies
+/// streamed as several separately padded base64 chunks are accepted.
+#[cfg(feature = "base64")]
+pub fn decode_grpc_web(body: &[u8]) -> Result<Vec<u8>, GrpcDecodeError> {
+    use base64::Engine;
+
+    let mut framed = Vec::with_capacity(body.len() / 4 * 3);
+    let mut rest = body.trim_ascii();
+    while !rest.is_empty() {
+        // A chunk ends after its padding, or at the end of the body
+        let end = match rest.iter().position(|&b| b == b'=') {
+            Some(first_pad) => first_pad + rest[first_pad..].iter().take_while(|&&b| b == b'=').count(),
+            None => rest.len(),
+        };
+        base64::engine::general_purpose::STANDARD
+            .decode_vec(&rest[..end], &mut framed)
+            .map_err(|_| GrpcDecodeError::InvalidBase64)?;
+        rest = &rest[end..];
+    }
+
+    decode_grpc_frames(&framed)
+}
@@ -0,0 +1,64 @@
+use crate::otlp_bytes;
+#[cfg(feature = "serde")]
+use crate::{json, proto::opentelemetry::proto::logs::v1::LogsData};
+use std::fmt;
+
+/// Logs decoded from an OTLP/HTTP request body, tagged by wire format
+pub enum ParsedLogs<'a> {
+    /// `application/x-protobuf`, borrowed from the request body
+    Protobuf(otlp_bytes::LogsData<'a>),
+    /// `application/json`, decoded into owned prost structs
+    #[cfg(feature = "serde")]
+    Json(LogsData),
+}
+
+/// Error decoding an OTLP/HTTP request body
+#[derive(Debug)]
+pub enum HttpDecodeError {
+    /// The content type is neither protobuf nor (with the `serde` feature) JSON
+    UnsupportedContentType(String),
+    /// The body is not a well-formed protobuf `LogsData` message
+    InvalidProtobuf,
+    #[cfg(feature = "serde")]
+    Json(json::JsonDecodeError),
+}
+
+impl fmt::Display for HttpDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpDecodeError::UnsupportedContentType(content_type) => {
+                write!(f, "unsupported content type `{}`", content_type)
+            }
+            HttpDecodeError::InvalidProtobuf => write!(f, "malformed protobuf body"),
+            #[cfg(feature = "serde")]
+            HttpDecodeError::Json(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for HttpDecodeError {}
+
+/// Decode an OTLP/HTTP logs request body, picking the decoder from the
+/// `Content-Type` header. Media type parameters such as `charset` are
+/// ignored and matching is case-insensitive.
+pub fn decode_otlp_http<'a>(content_type: &str, body: &'a [u8]) -> Result<ParsedLogs<'a>, HttpDecodeError> {
+    let media_type = content_type.split(';').next().unwrap_or_default().trim();
+
+    if media_type.eq_ignore_ascii_case("application/x-protobuf") {
+        let mut logs = otlp_bytes::LogsData::new();
+        return if logs.parse(body) {
+            Ok(ParsedLogs::Protobuf(logs))
+        } else {
+            Err(HttpDecodeError::InvalidProtobuf)
+        };
+    }
+
+    #[cfg(feature = "serde")]
+    if media_type.eq_ignore_ascii_case("application/json") {
+        return json::decode_logs_data(body)
+            .map(ParsedLogs::Json)
+            .map_err(HttpDecodeError::Json);
+    }
+
+    Err(HttpDecodeError::UnsupportedContentType(content_type.to_string()))
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+/// Handle for an interned attribute key. Comparing two handles from the same
+/// interner is an integer comparison; handles from different interners are
+/// unrelated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KeyId(u32);
+
+impl KeyId {
+    /// Position of the key in interning order, for indexing side tables
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Table of attribute keys keyed by their raw bytes. A key's bytes are
+/// UTF-8-validated once, when first interned; later lookups of the same
+/// bytes only hash them.
+#[derive(Debug, Default)]
+pub struct KeyInterner {
+    ids: HashMap<Box<[u8]>, KeyId>,
+    keys: Vec<Box<str>>,
+    max_keys: Option<usize>,
+}
+
+impl KeyInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop admitting new keys once `max_keys` are interned, bounding memory
+    /// when keys are high-cardinality. Keys already interned keep resolving.
+    pub fn with_max_keys(mut self, max_keys: usize) -> Self {
+        self.max_keys = Some(max_keys);
+        self
+    }
+
+    /// Intern a key given as raw bytes. Returns `None` if the bytes are not
+    /// valid UTF-8, or if they are new and the table is full.
+    pub fn intern_bytes(&mut self, bytes: &[u8]) -> Option<KeyId> {
+        if let Some(&id) = self.ids.get(bytes) {
+            return Some(id);
+        }
+        if self.max_keys.is_some_and(|max| self.keys.len() >= max) {
+            return None;
+        }
+        let key = std::str::from_utf8(bytes).ok()?;
+        let id = KeyId(u32::try_from(self.keys.len()).ok()?);
+        self.ids.insert(bytes.into(), id);
+        self.keys.push(key.into());
+        Some(id)
+    }
+
+    /// Intern a key. Returns `None` only if it is new and the table is full.
+    pub fn intern(&mut self, key: &str) -> Option<KeyId> {
+        self.intern_bytes(key.as_bytes())
+    }
+
+    /// Look a key up without interning it
+    pub fn get(&self, key: &str) -> Option<KeyId> {
+        self.ids.get(key.as_bytes()).copied()
+    }
+
+    /// The key a handle stands for
+    pub fn resolve(&self, id: KeyId) -> &str {
+        &self.keys[id.index()]
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
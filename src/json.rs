@@ -0,0 +1,456 @@
+use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView, ValueType};
+use std::borrow::Borrow;
+use std::io::{self, Write};
+
+/// Write newline-delimited JSON, one log record per line. Each line is an
+/// OTLP/JSON-style record object flattened with its inherited `resource`
+/// attributes and `scope` name/version. Following OTLP/JSON rules, 64-bit
+/// integers and timestamps are written as strings, trace/span ids as hex
+/// and other bytes as base64, so `parse_logs` reads the records back.
+pub fn write_ndjson<'a, L, W>(logs: &'a L, w: &mut W) -> io::Result<()>
+where
+    L: LogsView<'a>,
+    W: Write,
+{
+    for resource in logs.resources() {
+        for scope in resource.scopes() {
+            for record in scope.log_records() {
+                w.write_all(b"{\"resource\":{\"attributes\":")?;
+                write_attributes(w, resource.resource_attributes())?;
+                w.write_all(b"},\"scope\":{\"name\":")?;
+                write_string(w, scope.scope())?;
+                if let Some(version) = scope.version() {
+                    w.write_all(b",\"version\":")?;
+                    write_string(w, version)?;
+                }
+                w.write_all(b"}")?;
+                write_record_fields(w, record)?;
+                w.write_all(b"}\n")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_record_fields<'a, R, W>(w: &mut W, record: &'a R) -> io::Result<()>
+where
+    R: LogRecordView<'a>,
+    W: Write,
+{
+    if let Some(time) = record.time_unix_nano() {
+        write!(w, ",\"timeUnixNano\":\"{}\"", time)?;
+    }
+    if let Some(observed) = record.observed_time_unix_nano() {
+        write!(w, ",\"observedTimeUnixNano\":\"{}\"", observed)?;
+    }
+    if record.severity_number() != 0 {
+        write!(w, ",\"severityNumber\":{}", record.severity_number())?;
+    }
+    if let Some(text) = record.severity_text() {
+        w.write_all(b",\"severityText\":")?;
+        write_string(w, text)?;
+    }
+    if let Some(body) = record.body() {
+        w.write_all(b",\"body\":")?;
+        write_any_value(w, body)?;
+    }
+    w.write_all(b",\"attributes\":")?;
+    write_attributes(w, record.attributes())?;
+    if record.dropped_attributes_count() != 0 {
+        write!(w, ",\"droppedAttributesCount\":{}", record.dropped_attributes_count())?;
+    }
+    if record.flags() != 0 {
+        write!(w, ",\"flags\":{}", record.flags())?;
+    }
+    // Ids are hex in OTLP/JSON, unlike other bytes fields
+    if let Some(trace_id) = record.trace_id() {
+        w.write_all(b",\"traceId\":")?;
+        write_hex(w, trace_id)?;
+    }
+    if let Some(span_id) = record.span_id() {
+        w.write_all(b",\"spanId\":")?;
+        write_hex(w, span_id)?;
+    }
+    if let Some(event_name) = record.event_name() {
+        w.write_all(b",\"eventName\":")?;
+        write_string(w, event_name)?;
+    }
+    Ok(())
+}
+
+fn write_attributes<'v, A, I, W>(w: &mut W, attributes: I) -> io::Result<()>
+where
+    A: AttributeView + 'v,
+    I: Iterator<Item = &'v A>,
+    W: Write,
+{
+    w.write_all(b"[")?;
+    for (idx, attr) in attributes.enumerate() {
+        if idx > 0 {
+            w.write_all(b",")?;
+        }
+        write_key_value(w, attr)?;
+    }
+    w.write_all(b"]")
+}
+
+fn write_key_value<A: AttributeView, W: Write>(w: &mut W, attr: &A) -> io::Result<()> {
+    w.write_all(b"{\"key\":")?;
+    write_string(w, attr.key())?;
+    if let Some(value) = attr.value() {
+        w.write_all(b",\"value\":")?;
+        write_any_value(w, value)?;
+    }
+    w.write_all(b"}")
+}
+
+fn write_any_value<V: AnyValueView, W: Write>(w: &mut W, value: &V) -> io::Result<()> {
+    match value.value_type() {
+        ValueType::String => {
+            w.write_all(b"{\"stringValue\":")?;
+            write_string(w, value.as_string().unwrap_or_default())?;
+        }
+        ValueType::Bool => write!(w, "{{\"boolValue\":{}", value.as_bool().unwrap_or_default())?,
+        ValueType::Int64 => write!(w, "{{\"intValue\":\"{}\"", value.as_int64().unwrap_or_default())?,
+        ValueType::Double => {
+            let d = value.as_double().unwrap_or_default();
+            // JSON has no NaN/Infinity literals; OTLP/JSON spells them as strings
+            if d.is_nan() {
+                w.write_all(b"{\"doubleValue\":\"NaN\"")?;
+            } else if d.is_infinite() {
+                let name = if d > 0.0 { "Infinity" } else { "-Infinity" };
+                write!(w, "{{\"doubleValue\":\"{}\"", name)?;
+            } else {
+                write!(w, "{{\"doubleValue\":{:?}", d)?;
+            }
+        }
+        ValueType::Bytes => {
+            w.write_all(b"{\"bytesValue\":\"")?;
+            write_base64(w, value.as_bytes().unwrap_or_default())?;
+            w.write_all(b"\"")?;
+        }
+        ValueType::Array => {
+            w.write_all(b"{\"arrayValue\":{\"values\":[")?;
+            for (idx, element) in value.array_iter().into_iter().flatten().enumerate() {
+                if idx > 0 {
+                    w.write_all(b",")?;
+                }
+                write_any_value(w, element.borrow())?;
+            }
+            w.write_all(b"]}")?;
+        }
+        ValueType::KeyValueList => {
+            w.write_all(b"{\"kvlistValue\":{\"values\":[")?;
+            for (idx, entry) in value.kvlist_iter().into_iter().flatten().enumerate() {
+                if idx > 0 {
+                    w.write_all(b",")?;
+                }
+                write_key_value(w, entry.borrow())?;
+            }
+            w.write_all(b"]}")?;
+        }
+    }
+    w.write_all(b"}")
+}
+
+/// Write a JSON string literal, escaping quotes, backslashes and control characters
+fn write_string<W: Write>(w: &mut W, s: &str) -> io::Result<()> {
+    let bytes = s.as_bytes();
+    w.write_all(b"\"")?;
+    let mut start = 0;
+    // Every byte needing an escape is ASCII, so splitting on them keeps UTF-8 intact
+    for (idx, &byte) in bytes.iter().enumerate() {
+        let escaped: &[u8] = match byte {
+            b'"' => b"\\\"",
+            b'\\' => b"\\\\",
+            b'\n' => b"\\n",
+            b'\r' => b"\\r",
+            b'\t' => b"\\t",
+            0x00..=0x1F => {
+                w.write_all(&bytes[start..idx])?;
+                write!(w, "\\u{:04x}", byte)?;
+                start = idx + 1;
+                continue;
+            }
+            _ => continue,
+        };
+        w.write_all(&bytes[start..idx])?;
+        w.write_all(escaped)?;
+        start = idx + 1;
+    }
+    w.write_all(&bytes[start..])?;
+    w.write_all(b"\"")
+}
+
+/// Lowercase hex as a JSON string literal
+fn write_hex<W: Write>(w: &mut W, bytes: &[u8]) -> io::Result<()> {
+    w.write_all(b"\"")?;
+    for byte in bytes {
+        write!(w, "{:02x}", byte)?;
+    }
+    w.write_all(b"\"")
+}
+
+/// Standard padded base64, as required for OTLP/JSON bytes values
+fn write_base64<W: Write>(w: &mut W, bytes: &[u8]) -> io::Result<()> {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    // Writing into a String cannot fail
+    let _ = crate::util::write_base64(&mut encoded, bytes);
+    w.write_all(encoded.as_bytes())
+}
+
+#[cfg(feature = "serde")]
+pub use decode::JsonDecodeError;
+
+/// Owned logs hierarchy produced by `parse_logs`: the prost structs, which
+/// implement `LogsView` like the byte-backed parsers
+#[cfg(feature = "serde")]
+pub type OwnedLogsData = crate::proto::opentelemetry::proto::logs::v1::LogsData;
+
+/// Parse an OTLP/JSON `LogsData` document into an owned hierarchy, the JSON
+/// counterpart of parsing protobuf bytes. Field names are the lowerCamelCase
+/// ones OTLP/JSON mandates; 64-bit integers are accepted as strings or
+/// numbers, trace/span ids as hex and bytes as base64.
+#[cfg(feature = "serde")]
+pub fn parse_logs(json: &str) -> Result<OwnedLogsData, JsonDecodeError> {
+    let root = serde_json::from_str(json).map_err(JsonDecodeError::Syntax)?;
+    decode::logs_data(&root)
+}
+
+/// Decode an OTLP/JSON body as received over HTTP, without first checking
+/// it is UTF-8; otherwise the same as `parse_logs`
+#[cfg(feature = "serde")]
+pub fn decode_logs_data(body: &[u8]) -> Result<OwnedLogsData, JsonDecodeError> {
+    let root = serde_json::from_slice(body).map_err(JsonDecodeError::Syntax)?;
+    decode::logs_data(&root)
+}
+
+/// OTLP/JSON decoding into the prost structs, which already implement `LogsView`
+#[cfg(feature = "serde")]
+mod decode {
+    use crate::proto::opentelemetry::proto::{common::v1::*, logs::v1::*, resource::v1::*};
+    use serde_json::{Map, Value};
+    use std::fmt;
+
+    /// Error decoding an OTLP/JSON document
+    #[derive(Debug)]
+    pub enum JsonDecodeError {
+        /// The body is not valid JSON
+        Syntax(serde_json::Error),
+        /// A field is present but does not have the shape OTLP/JSON requires
+        InvalidField(&'static str),
+    }
+
+    impl fmt::Display for JsonDecodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                JsonDecodeError::Syntax(err) => write!(f, "invalid JSON: {}", err),
+                JsonDecodeError::InvalidField(field) => write!(f, "invalid OTLP/JSON field `{}`", field),
+            }
+        }
+    }
+
+    impl std::error::Error for JsonDecodeError {}
+
+    type Result<T> = std::result::Result<T, JsonDecodeError>;
+
+    /// Decode a parsed OTLP/JSON `LogsData` document
+    pub(super) fn logs_data(root: &Value) -> Result<LogsData> {
+        let root = object(root, "LogsData")?;
+
+        Ok(LogsData {
+            resource_logs: array(root, "resourceLogs", resource_logs)?,
+        })
+    }
+
+    fn resource_logs(value: &Value) -> Result<ResourceLogs> {
+        let obj = object(value, "resourceLogs")?;
+        let resource = match obj.get("resource") {
+            Some(value) => {
+                let obj = object(value, "resource")?;
+                Some(Resource {
+                    attributes: array(obj, "attributes", key_value)?,
+                    dropped_attributes_count: uint32(obj, "droppedAttributesCount")?,
+                    entity_refs: Vec::new(),
+                })
+            }
+            None => None,
+        };
+
+        Ok(ResourceLogs {
+            resource,
+            scope_logs: array(obj, "scopeLogs", scope_logs)?,
+            schema_url: string(obj, "schemaUrl")?,
+        })
+    }
+
+    fn scope_logs(value: &Value) -> Result<ScopeLogs> {
+        let obj = object(value, "scopeLogs")?;
+        let scope = match obj.get("scope") {
+            Some(value) => {
+                let obj = object(value, "scope")?;
+                Some(InstrumentationScope {
+                    name: string(obj, "name")?,
+                    version: string(obj, "version")?,
+                    attributes: array(obj, "attributes", key_value)?,
+                    dropped_attributes_count: uint32(obj, "droppedAttributesCount")?,
+                })
+            }
+            None => None,
+        };
+
+        Ok(ScopeLogs {
+            scope,
+            log_records: array(obj, "logRecords", log_record)?,
+            schema_url: string(obj, "schemaUrl")?,
+        })
+    }
+
+    fn log_record(value: &Value) -> Result<LogRecord> {
+        let obj = object(value, "logRecords")?;
+        Ok(LogRecord {
+            time_unix_nano: uint(obj, "timeUnixNano")?,
+            observed_time_unix_nano: uint(obj, "observedTimeUnixNano")?,
+            severity_number: int32(obj, "severityNumber")?,
+            severity_text: string(obj, "severityText")?,
+            body: obj.get("body").map(any_value).transpose()?,
+            attributes: array(obj, "attributes", key_value)?,
+            dropped_attributes_count: uint32(obj, "droppedAttributesCount")?,
+            flags: uint32(obj, "flags")?,
+            trace_id: hex(obj, "traceId")?,
+            span_id: hex(obj, "spanId")?,
+            event_name: string(obj, "eventName")?,
+        })
+    }
+
+    fn key_value(value: &Value) -> Result<KeyValue> {
+        let obj = object(value, "attributes")?;
+        Ok(KeyValue {
+            key: string(obj, "key")?,
+            value: obj.get("value").map(any_value).transpose()?,
+        })
+    }
+
+    fn any_value(value: &Value) -> Result<AnyValue> {
+        use any_value::Value as V;
+
+        let obj = object(value, "value")?;
+        let value = if let Some(s) = obj.get("stringValue") {
+            Some(V::StringValue(s.as_str().ok_or(JsonDecodeError::InvalidField("stringValue"))?.to_string()))
+        } else if let Some(b) = obj.get("boolValue") {
+            Some(V::BoolValue(b.as_bool().ok_or(JsonDecodeError::InvalidField("boolValue"))?))
+        } else if obj.contains_key("intValue") {
+            Some(V::IntValue(int(obj, "intValue")?))
+        } else if let Some(d) = obj.get("doubleValue") {
+            Some(V::DoubleValue(double(d).ok_or(JsonDecodeError::InvalidField("doubleValue"))?))
+        } else if let Some(b) = obj.get("bytesValue") {
+            let encoded = b.as_str().ok_or(JsonDecodeError::InvalidField("bytesValue"))?;
+            Some(V::BytesValue(base64_decode(encoded).ok_or(JsonDecodeError::InvalidField("bytesValue"))?))
+        } else if let Some(array_value) = obj.get("arrayValue") {
+            let obj = object(array_value, "arrayValue")?;
+            Some(V::ArrayValue(ArrayValue {
+                values: array(obj, "values", any_value)?,
+            }))
+        } else if let Some(kvlist_value) = obj.get("kvlistValue") {
+            let obj = object(kvlist_value, "kvlistValue")?;
+            Some(V::KvlistValue(KeyValueList {
+                values: array(obj, "values", key_value)?,
+            }))
+        } else {
+            None
+        };
+
+        Ok(AnyValue { value })
+    }
+
+    fn object<'v>(value: &'v Value, field: &'static str) -> Result<&'v Map<String, Value>> {
+        value.as_object().ok_or(JsonDecodeError::InvalidField(field))
+    }
+
+    /// Decode an optional repeated field, defaulting to empty
+    fn array<T>(obj: &Map<String, Value>, field: &'static str, decode: fn(&Value) -> Result<T>) -> Result<Vec<T>> {
+        match obj.get(field) {
+            Some(Value::Array(items)) => items.iter().map(decode).collect(),
+            Some(Value::Null) | None => Ok(Vec::new()),
+            Some(_) => Err(JsonDecodeError::InvalidField(field)),
+        }
+    }
+
+    fn string(obj: &Map<String, Value>, field: &'static str) -> Result<String> {
+        match obj.get(field) {
+            Some(Value::String(s)) => Ok(s.clone()),
+            Some(Value::Null) | None => Ok(String::new()),
+            Some(_) => Err(JsonDecodeError::InvalidField(field)),
+        }
+    }
+
+    /// 64-bit integers may be encoded as JSON strings or numbers
+    fn int(obj: &Map<String, Value>, field: &'static str) -> Result<i64> {
+        match obj.get(field) {
+            Some(Value::String(s)) => s.parse().map_err(|_| JsonDecodeError::InvalidField(field)),
+            Some(Value::Number(n)) => n.as_i64().ok_or(JsonDecodeError::InvalidField(field)),
+            Some(Value::Null) | None => Ok(0),
+            Some(_) => Err(JsonDecodeError::InvalidField(field)),
+        }
+    }
+
+    fn int32(obj: &Map<String, Value>, field: &'static str) -> Result<i32> {
+        i32::try_from(int(obj, field)?).map_err(|_| JsonDecodeError::InvalidField(field))
+    }
+
+    fn uint(obj: &Map<String, Value>, field: &'static str) -> Result<u64> {
+        match obj.get(field) {
+            Some(Value::String(s)) => s.parse().map_err(|_| JsonDecodeError::InvalidField(field)),
+            Some(Value::Number(n)) => n.as_u64().ok_or(JsonDecodeError::InvalidField(field)),
+            Some(Value::Null) | None => Ok(0),
+            Some(_) => Err(JsonDecodeError::InvalidField(field)),
+        }
+    }
+
+    fn uint32(obj: &Map<String, Value>, field: &'static str) -> Result<u32> {
+        u32::try_from(uint(obj, field)?).map_err(|_| JsonDecodeError::InvalidField(field))
+    }
+
+    /// Doubles are numbers, except the non-finite ones which OTLP/JSON spells as strings
+    fn double(value: &Value) -> Option<f64> {
+        match value {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => match s.as_str() {
+                "NaN" => Some(f64::NAN),
+                "Infinity" => Some(f64::INFINITY),
+                "-Infinity" => Some(f64::NEG_INFINITY),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Trace and span ids are hex strings in OTLP/JSON. Exporters that apply
+    /// the generic protobuf JSON mapping send them as base64 instead, which
+    /// is accepted when the string is not valid hex.
+    fn hex(obj: &Map<String, Value>, field: &'static str) -> Result<Vec<u8>> {
+        let s = match obj.get(field) {
+            Some(Value::String(s)) => s,
+            Some(Value::Null) | None => return Ok(Vec::new()),
+            Some(_) => return Err(JsonDecodeError::InvalidField(field)),
+        };
+        let from_hex = || {
+            if !s.len().is_multiple_of(2) {
+                return None;
+            }
+            (0..s.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+                .collect::<Option<Vec<u8>>>()
+        };
+        from_hex()
+            .or_else(|| base64_decode(s))
+            .ok_or(JsonDecodeError::InvalidField(field))
+    }
+
+    /// Standard padded base64; padding is only accepted at the end
+    fn base64_decode(s: &str) -> Option<Vec<u8>> {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD.decode(s).ok()
+    }
+}
@@ -16,6 +16,11 @@ pub mod proto {
                     include!("proto/opentelemetry.proto.resource.v1.rs");
                 }
             }
+            pub mod profiles {
+                pub mod v1development {
+                    include!("proto/opentelemetry.proto.profiles.v1development.rs");
+                }
+            }
         }
     }
 }
@@ -24,10 +29,31 @@ mod prost_structs;
 // mod bytes_view;
 pub mod otlp_bytes;
 pub mod otlp_bytes_lazy;
+pub mod diff;
+pub mod matcher;
+pub mod json;
+pub mod http;
+pub mod grpc;
+pub mod util;
+pub mod intern;
+pub mod columnar;
+pub mod erased;
+pub mod profiles;
+pub mod signal;
+pub mod stream_writer;
+pub mod print;
+#[cfg(feature = "async")]
+pub mod async_read;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 use crate::proto::opentelemetry::proto::{common::v1::*, logs::v1::*, resource::v1::*};
 // use crate::bytes_view::LogsDataBytes;
 use prost::Message;
+use std::borrow::{Borrow, Cow};
+use std::collections::{BTreeMap, HashSet};
 
 // View traits for each hierarchy level (zero-cost iterator-based)
 pub trait LogsView<'a> {
@@ -36,14 +62,359 @@ pub trait LogsView<'a> {
     type ResourcesIter: Iterator<Item = &'a Self::ResourceLogs> where Self::ResourceLogs: 'a;
     
     fn resources(&'a self) -> Self::ResourcesIter;
+
+    /// The resource at index `n`, for random access. Backends with indexed
+    /// storage override this to avoid walking the iterator.
+    fn nth_resource(&'a self, n: usize) -> Option<&'a Self::ResourceLogs> {
+        self.resources().nth(n)
+    }
+
+    /// Collect every record across all resources and scopes, sorted by
+    /// resolved timestamp. Records without a timestamp sort first; ties keep
+    /// their original order.
+    fn records_sorted_by_time(&'a self) -> Vec<RecordRef<'a, LogRecordOf<'a, Self>>>
+    where
+        Self::ResourceLogs: 'a,
+        <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs: 'a,
+        LogRecordOf<'a, Self>: 'a,
+    {
+        let mut records = Vec::new();
+        for (resource_index, resource) in self.resources().enumerate() {
+            for (scope_index, scope) in resource.scopes().enumerate() {
+                for (record_index, record) in scope.log_records().enumerate() {
+                    records.push(RecordRef {
+                        resource_index,
+                        scope_index,
+                        record_index,
+                        timestamp: record.timestamp(),
+                        record,
+                    });
+                }
+            }
+        }
+        records.sort_by_key(|record| record.timestamp);
+        records
+    }
+
+    /// Iterate at most `n` records across the whole hierarchy, in order,
+    /// for previews and capped exports. Iteration stops at the `n`th record,
+    /// leaving later scopes and resources unvisited.
+    fn take_records(&'a self, n: usize) -> impl Iterator<Item = &'a LogRecordOf<'a, Self>>
+    where
+        Self::ResourceLogs: 'a,
+        <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs: 'a,
+        LogRecordOf<'a, Self>: 'a,
+    {
+        self.resources()
+            .flat_map(|resource| resource.scopes())
+            .flat_map(|scope| scope.log_records())
+            .take(n)
+    }
+
+    /// Every scope in the batch, in order, paired with the resource that
+    /// owns it
+    fn all_scopes(
+        &'a self,
+    ) -> impl Iterator<Item = (&'a Self::ResourceLogs, &'a <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs)>
+    where
+        Self::ResourceLogs: 'a,
+        <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs: 'a,
+    {
+        self.resources()
+            .flat_map(|resource| resource.scopes().map(move |scope| (resource, scope)))
+    }
+
+    /// Stable digest of the whole batch for cache invalidation. Built from
+    /// each record's `content_hash` plus its timestamps and trace id, and
+    /// each resource's and scope's identity (attributes, name, version,
+    /// schema URL). It is order-sensitive: reordering resources, scopes or
+    /// records changes the digest, though the attribute order within a
+    /// record does not.
+    fn logs_hash(&'a self) -> u64
+    where
+        Self::ResourceLogs: 'a,
+        <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs: 'a,
+        LogRecordOf<'a, Self>: 'a,
+    {
+        use std::hash::Hasher;
+
+        fn hash_opt_str<H: Hasher>(value: Option<&str>, hasher: &mut H) {
+            match value {
+                Some(value) => {
+                    hasher.write_u8(1);
+                    util::hash_bytes(value.as_bytes(), hasher);
+                }
+                None => hasher.write_u8(0),
+            }
+        }
+
+        let mut hasher = util::Fnv1aHasher::default();
+        // Child counts terminate each level so boundaries can't shift
+        let mut resource_count = 0u64;
+        for resource in self.resources() {
+            let attributes: Vec<_> = resource.resource_attributes().collect();
+            util::hash_attributes(&attributes, &mut hasher);
+            hash_opt_str(resource.schema_url(), &mut hasher);
+
+            let mut scope_count = 0u64;
+            for scope in resource.scopes() {
+                util::hash_bytes(scope.scope().as_bytes(), &mut hasher);
+                hash_opt_str(scope.version(), &mut hasher);
+                hash_opt_str(scope.schema_url(), &mut hasher);
+
+                let mut record_count = 0u64;
+                for record in scope.log_records() {
+                    hasher.write_u64(record.content_hash());
+                    hasher.write_u64(record.time_unix_nano().unwrap_or_default());
+                    hasher.write_u64(record.observed_time_unix_nano().unwrap_or_default());
+                    util::hash_bytes(record.trace_id().unwrap_or_default(), &mut hasher);
+                    record_count += 1;
+                }
+                hasher.write_u64(record_count);
+                scope_count += 1;
+            }
+            hasher.write_u64(scope_count);
+            resource_count += 1;
+        }
+        hasher.write_u64(resource_count);
+        hasher.finish()
+    }
+
+    /// Every distinct record attribute key across all resources and scopes.
+    /// Resource and scope attributes are not included.
+    fn attribute_keys(&'a self) -> HashSet<&'a str>
+    where
+        Self::ResourceLogs: 'a,
+        <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs: 'a,
+        LogRecordOf<'a, Self>: 'a,
+    {
+        let mut keys = HashSet::new();
+        for resource in self.resources() {
+            for scope in resource.scopes() {
+                for record in scope.log_records() {
+                    keys.extend(record.attributes().map(|attr| attr.key()));
+                }
+            }
+        }
+        keys
+    }
+
+    /// Number of distinct record attribute keys, for spotting key explosions
+    fn distinct_attribute_keys(&'a self) -> usize
+    where
+        Self::ResourceLogs: 'a,
+        <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs: 'a,
+        LogRecordOf<'a, Self>: 'a,
+    {
+        self.attribute_keys().len()
+    }
+
+    /// Number of distinct values seen per record attribute key. Values are
+    /// compared by content hash, and counting for a key stops at `cap`, so
+    /// memory stays bounded on high-cardinality keys such as ids.
+    fn attribute_value_cardinality(&'a self, cap: usize) -> BTreeMap<&'a str, ValueCardinality>
+    where
+        Self::ResourceLogs: 'a,
+        <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs: 'a,
+        LogRecordOf<'a, Self>: 'a,
+    {
+        use std::hash::Hasher;
+
+        let mut seen: BTreeMap<&'a str, HashSet<u64>> = BTreeMap::new();
+        for resource in self.resources() {
+            for scope in resource.scopes() {
+                for record in scope.log_records() {
+                    for attr in record.attributes() {
+                        let values = seen.entry(attr.key()).or_default();
+                        if values.len() >= cap {
+                            continue;
+                        }
+                        let mut hasher = util::Fnv1aHasher::default();
+                        match attr.value() {
+                            Some(value) => util::hash_any_value(value, &mut hasher),
+                            None => hasher.write_u8(0),
+                        }
+                        values.insert(hasher.finish());
+                    }
+                }
+            }
+        }
+
+        seen.into_iter()
+            .map(|(key, values)| {
+                let cardinality = match values.len() {
+                    count if count >= cap => ValueCardinality::AtLeast(count),
+                    count => ValueCardinality::Exact(count),
+                };
+                (key, cardinality)
+            })
+            .collect()
+    }
+
+    /// Counts per level, the timestamp span and a severity histogram,
+    /// gathered in one traversal for monitoring
+    fn summarize(&'a self) -> LogsStats
+    where
+        Self::ResourceLogs: 'a,
+        <Self::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs: 'a,
+        LogRecordOf<'a, Self>: 'a,
+    {
+        let mut summary = LogsStats::default();
+        for resource in self.resources() {
+            let mut resource_records = 0;
+            for scope in resource.scopes() {
+                let mut scope_records = 0;
+                for record in scope.log_records() {
+                    if let Some(timestamp) = record.timestamp() {
+                        summary.min_timestamp = Some(summary.min_timestamp.map_or(timestamp, |min| min.min(timestamp)));
+                        summary.max_timestamp = Some(summary.max_timestamp.map_or(timestamp, |max| max.max(timestamp)));
+                    }
+                    *summary.severity_counts.entry(record.severity_number()).or_default() += 1;
+                    scope_records += 1;
+                }
+                summary.records_per_scope.push(scope_records);
+                resource_records += scope_records;
+            }
+            summary.records_per_resource.push(resource_records);
+            summary.record_count += resource_records;
+        }
+        summary
+    }
+}
+
+/// Aggregate counts from `LogsView::summarize`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogsStats {
+    /// Records in each resource, in order
+    pub records_per_resource: Vec<usize>,
+    /// Records in each scope, in order across all resources
+    pub records_per_scope: Vec<usize>,
+    pub record_count: usize,
+    /// Earliest resolved timestamp (see `LogRecordView::timestamp`), `None`
+    /// if no record has one
+    pub min_timestamp: Option<u64>,
+    /// Latest resolved timestamp
+    pub max_timestamp: Option<u64>,
+    /// Records per severity number; 0 counts records with none
+    pub severity_counts: BTreeMap<i32, usize>,
+}
+
+impl LogsStats {
+    pub fn resource_count(&self) -> usize {
+        self.records_per_resource.len()
+    }
+
+    pub fn scope_count(&self) -> usize {
+        self.records_per_scope.len()
+    }
+}
+
+/// Distinct-value count for one attribute key, from
+/// `LogsView::attribute_value_cardinality`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueCardinality {
+    Exact(usize),
+    /// The cap was reached; the true count may be higher
+    AtLeast(usize),
+}
+
+/// The log record type reached by walking a `LogsView` hierarchy
+pub type LogRecordOf<'a, L> = <<<L as LogsView<'a>>::ResourceLogs as ResourceLogsView<'a>>::ScopeLogs as ScopeLogsView<'a>>::LogRecord;
+
+/// A borrowed log record together with its position in the hierarchy
+#[derive(Debug)]
+pub struct RecordRef<'a, R> {
+    pub resource_index: usize,
+    pub scope_index: usize,
+    pub record_index: usize,
+    pub timestamp: Option<u64>,
+    pub record: &'a R,
 }
 
 pub trait ResourceLogsView<'a> {
     type ScopeLogs: ScopeLogsView<'a>;
     type ScopesIter: Iterator<Item = &'a Self::ScopeLogs> where Self::ScopeLogs: 'a;
+    type ResourceAttribute: AttributeView;
+    type ResourceAttributesIter: Iterator<Item = &'a Self::ResourceAttribute> where Self::ResourceAttribute: 'a;
+    type EntityRef: EntityRefView;
+    type EntityRefsIter: Iterator<Item = &'a Self::EntityRef> where Self::EntityRef: 'a;
     
     fn resource(&self) -> &str;
     fn scopes(&'a self) -> Self::ScopesIter;
+    /// The scope at index `n`; see `LogsView::nth_resource`
+    fn nth_scope(&'a self, n: usize) -> Option<&'a Self::ScopeLogs> {
+        self.scopes().nth(n)
+    }
+    /// Attributes of the resource in wire order; empty when the resource is absent
+    fn resource_attributes(&'a self) -> Self::ResourceAttributesIter;
+    /// Entity refs of the resource in wire order; empty when the resource is absent
+    fn entity_refs(&'a self) -> Self::EntityRefsIter;
+    /// Schema URL of the resource's data; `None` when absent or empty
+    fn schema_url(&self) -> Option<&str>;
+    /// Resource attributes the producer discarded (e.g. over a limit); 0
+    /// when absent or the resource is unset
+    fn dropped_attributes_count(&self) -> u32;
+
+    /// The resource's identity per the `service.*` semantic conventions.
+    /// Unlike `resource()`, which flattens to a display name with a
+    /// placeholder, absent attributes read as `None`.
+    fn resource_info(&'a self) -> ResourceInfo<'a, Self> {
+        ResourceInfo { resource: self }
+    }
+}
+
+/// Semantic-convention view of a resource's attributes, from
+/// `ResourceLogsView::resource_info`
+pub struct ResourceInfo<'a, R: ResourceLogsView<'a> + ?Sized> {
+    resource: &'a R,
+}
+
+impl<'a, R: ResourceLogsView<'a> + ?Sized> Clone for ResourceInfo<'a, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, R: ResourceLogsView<'a> + ?Sized> Copy for ResourceInfo<'a, R> {}
+
+impl<'a, R: ResourceLogsView<'a> + ?Sized> ResourceInfo<'a, R> {
+    /// Value of the first resource attribute with `key`
+    pub fn get(&self, key: &str) -> Option<&'a <R::ResourceAttribute as AttributeView>::AnyValue> {
+        self.resource
+            .resource_attributes()
+            .find(|attr| attr.key() == key)
+            .and_then(|attr| attr.value())
+    }
+
+    /// String value of the first resource attribute with `key`
+    pub fn get_str(&self, key: &str) -> Option<&'a str> {
+        self.get(key).and_then(|value| value.as_string())
+    }
+
+    /// `service.name`
+    pub fn service_name(&self) -> Option<&'a str> {
+        self.get_str("service.name")
+    }
+
+    /// `service.version`
+    pub fn service_version(&self) -> Option<&'a str> {
+        self.get_str("service.version")
+    }
+
+    /// `service.namespace`
+    pub fn service_namespace(&self) -> Option<&'a str> {
+        self.get_str("service.namespace")
+    }
+
+    /// `service.instance.id`
+    pub fn service_instance_id(&self) -> Option<&'a str> {
+        self.get_str("service.instance.id")
+    }
+
+    /// All resource attributes, in wire order
+    pub fn attributes(&self) -> R::ResourceAttributesIter {
+        self.resource.resource_attributes()
+    }
 }
 
 pub trait ScopeLogsView<'a> {
@@ -53,15 +424,210 @@ pub trait ScopeLogsView<'a> {
     fn scope(&self) -> &str;
     fn version(&self) -> Option<&str>;
     fn log_records(&'a self) -> Self::LogRecordsIter;
+    /// The record at index `n`; see `LogsView::nth_resource`
+    fn nth_record(&'a self, n: usize) -> Option<&'a Self::LogRecord> {
+        self.log_records().nth(n)
+    }
+    /// Schema URL of the scope's data; `None` when absent or empty
+    fn schema_url(&self) -> Option<&str>;
+    /// Instrumentation scope attributes the producer discarded; 0 when
+    /// absent or the scope is unset
+    fn dropped_attributes_count(&self) -> u32;
+
+    /// Iterate the records whose resolved timestamp (`effective_timestamp`)
+    /// lies in `start..end`; records without a timestamp are skipped
+    fn records_in_range(&'a self, start: u64, end: u64) -> impl Iterator<Item = &'a Self::LogRecord>
+    where
+        Self::LogRecord: 'a,
+    {
+        self.log_records()
+            .filter(move |record| record.effective_timestamp().is_some_and(|ts| (start..end).contains(&ts)))
+    }
+
+    /// Collapse runs of consecutive records with equal `content_hash` into
+    /// the run's first record and its length, like `slice::dedup` but
+    /// counting. Timestamps are not part of the hash, so a burst of the
+    /// same message at different times collapses; non-adjacent repeats
+    /// are kept apart.
+    fn dedup_records(&'a self) -> impl Iterator<Item = (&'a Self::LogRecord, usize)>
+    where
+        Self::LogRecord: 'a,
+    {
+        let mut records = self.log_records().map(|record| (record, record.content_hash())).peekable();
+        std::iter::from_fn(move || {
+            let (first, hash) = records.next()?;
+            let mut count = 1;
+            while records.next_if(|&(_, next)| next == hash).is_some() {
+                count += 1;
+            }
+            Some((first, count))
+        })
+    }
+
+    /// Instrumentation library identifier in the `name@version` form
+    /// backends key on; just `name` when the version is absent or empty
+    fn instrumentation_library(&self) -> String {
+        match self.version().filter(|v| !v.is_empty()) {
+            Some(version) => format!("{}@{}", self.scope(), version),
+            None => self.scope().to_string(),
+        }
+    }
 }
 
 pub trait LogRecordView<'a> {
     type Attribute: AttributeView;
     type AttributesIter: Iterator<Item = &'a Self::Attribute> where Self::Attribute: 'a;
     
+    /// Display name for the record: `event_name` if set, otherwise
+    /// `severity_text` if set, otherwise the constant `"log_record"`
     fn name(&self) -> &str;
-    fn timestamp(&self) -> Option<u64>;
+    /// Event time, `None` when unset (absent or zero)
+    fn time_unix_nano(&self) -> Option<u64>;
+    /// Collector observation time, `None` when unset (absent or zero)
+    fn observed_time_unix_nano(&self) -> Option<u64>;
+    /// Severity number, 0 (UNSPECIFIED) when absent
+    fn severity_number(&self) -> i32;
+    /// Severity text, `None` when absent or empty
+    fn severity_text(&self) -> Option<&str>;
+    fn body(&self) -> Option<&<Self::Attribute as AttributeView>::AnyValue>;
+    /// Attributes in wire order, duplicates included. Every backend must
+    /// preserve this order; `diff` compares attributes positionally.
     fn attributes(&'a self) -> Self::AttributesIter;
+    /// Whether `attributes()` would yield anything, without setting up an iterator
+    fn has_attributes(&self) -> bool;
+    /// Attributes the producer discarded (e.g. over a limit), 0 when absent
+    fn dropped_attributes_count(&self) -> u32;
+    /// Raw trace id, `None` when absent or empty
+    fn trace_id(&self) -> Option<&[u8]>;
+    /// Raw span id, `None` when absent or empty
+    fn span_id(&self) -> Option<&[u8]>;
+    /// W3C trace flags, 0 when absent
+    fn flags(&self) -> u32;
+    /// Event name, `None` when absent or empty
+    fn event_name(&self) -> Option<&str>;
+
+    /// Resolve the record's timestamp per OTLP semantics: `time_unix_nano`
+    /// if set, otherwise `observed_time_unix_nano` if set, otherwise `None`.
+    /// A zero value counts as unset.
+    fn effective_timestamp(&self) -> Option<u64> {
+        self.time_unix_nano()
+            .filter(|&t| t != 0)
+            .or_else(|| self.observed_time_unix_nano().filter(|&t| t != 0))
+    }
+
+    /// Same as `effective_timestamp`
+    fn timestamp(&self) -> Option<u64> {
+        self.effective_timestamp()
+    }
+
+    /// Stable fingerprint of the record's content for deduplication, covering
+    /// severity number and text, body and attributes. Attributes are sorted
+    /// by key first, so their order does not affect the hash; timestamps are
+    /// not included.
+    fn content_hash(&'a self) -> u64 {
+        use std::hash::Hasher;
+
+        let mut hasher = util::Fnv1aHasher::default();
+        hasher.write_i32(self.severity_number());
+        match self.severity_text() {
+            Some(text) => {
+                hasher.write_u8(1);
+                util::hash_bytes(text.as_bytes(), &mut hasher);
+            }
+            None => hasher.write_u8(0),
+        }
+        match self.body() {
+            Some(body) => util::hash_any_value(body, &mut hasher),
+            None => hasher.write_u8(0),
+        }
+
+        let mut attributes: Vec<_> = self.attributes().collect();
+        attributes.sort_by(|a, b| a.key().cmp(b.key()));
+        util::hash_attributes(&attributes, &mut hasher);
+        hasher.finish()
+    }
+
+    /// 64-bit key for consistent sampling. A record with a valid trace id
+    /// (16 bytes, not all zero) hashes only its trace id, so every record of
+    /// a trace gets the same key and is kept or dropped together; otherwise
+    /// the key hashes severity number and body.
+    fn sampling_key(&self) -> u64 {
+        use std::hash::Hasher;
+
+        let mut hasher = util::Fnv1aHasher::default();
+        match self.trace_id().filter(|id| id.len() == 16 && id.iter().any(|&b| b != 0)) {
+            Some(trace_id) => hasher.write(trace_id),
+            None => {
+                hasher.write_i32(self.severity_number());
+                match self.body() {
+                    Some(body) => util::hash_any_value(body, &mut hasher),
+                    None => hasher.write_u8(0),
+                }
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Severity text for display: the explicit `severity_text` if set,
+    /// otherwise the OTLP short name for `severity_number` (`"INFO"`,
+    /// `"ERROR3"`, ...), or `"UNSPECIFIED"` for 0 and out-of-range numbers
+    fn severity_text_or_inferred(&self) -> Cow<'_, str> {
+        match self.severity_text() {
+            Some(text) => Cow::Borrowed(text),
+            None => Cow::Borrowed(
+                usize::try_from(self.severity_number())
+                    .ok()
+                    .and_then(|number| SEVERITY_SHORT_NAMES.get(number))
+                    .copied()
+                    .unwrap_or("UNSPECIFIED"),
+            ),
+        }
+    }
+
+    /// Bucket attributes by namespace: the part of the key before the first
+    /// `sep` (e.g. `http` for `http.method`). Keys without `sep` go under
+    /// `""`. Attributes keep their record order within a bucket.
+    fn attributes_grouped_by_prefix(&'a self, sep: char) -> BTreeMap<&'a str, Vec<&'a Self::Attribute>> {
+        let mut groups: BTreeMap<&'a str, Vec<&'a Self::Attribute>> = BTreeMap::new();
+        for attr in self.attributes() {
+            let prefix = attr.key().split_once(sep).map_or("", |(prefix, _)| prefix);
+            groups.entry(prefix).or_default().push(attr);
+        }
+        groups
+    }
+
+    /// Fold the attributes, in wire order, into an accumulator. Equivalent
+    /// to `attributes().fold(init, f)`; backends that can walk their
+    /// attributes more cheaply than through the iterator override it.
+    fn fold_attributes<B>(&'a self, init: B, f: impl FnMut(B, &'a Self::Attribute) -> B) -> B
+    where
+        Self::Attribute: 'a,
+    {
+        self.attributes().fold(init, f)
+    }
+
+    /// Attributes with their values already extracted, in wire order, so
+    /// callers match once instead of calling `value_type()` and then the
+    /// matching getter. Attributes without a value are skipped.
+    fn typed_attributes(&'a self) -> impl Iterator<Item = (&'a str, TypedValue<'a, <Self::Attribute as AttributeView>::AnyValue>)>
+    where
+        Self::Attribute: 'a,
+    {
+        self.attributes()
+            .filter_map(|attr| Some((attr.key(), TypedValue::from_value(attr.value()?)?)))
+    }
+}
+
+/// A resource's reference to one of the entities it describes
+pub trait EntityRefView {
+    /// Schema URL of the entity type; `None` when absent or empty
+    fn schema_url(&self) -> Option<&str>;
+    /// Entity type, e.g. `service`; `None` when absent or empty
+    fn entity_type(&self) -> Option<&str>;
+    /// Resource attribute keys that identify the entity
+    fn id_keys(&self) -> impl Iterator<Item = &str>;
+    /// Resource attribute keys that describe the entity
+    fn description_keys(&self) -> impl Iterator<Item = &str>;
 }
 
 pub trait AttributeView {
@@ -72,6 +638,8 @@ pub trait AttributeView {
 
 pub trait AnyValueView {
     type KeyValue: AttributeView;
+    /// Element type of array values, as yielded by `array_iter`
+    type ArrayElement: AnyValueView;
     
     fn value_type(&self) -> ValueType;
     fn as_string(&self) -> Option<&str>;
@@ -79,8 +647,24 @@ pub trait AnyValueView {
     fn as_int64(&self) -> Option<i64>;
     fn as_double(&self) -> Option<f64>;
     fn as_bytes(&self) -> Option<&[u8]>;
+    /// Array elements as a slice; `None` on backends that don't materialize
+    /// elements, so prefer `array_iter` in backend-agnostic code
     fn as_array(&self) -> Option<&[Self]> where Self: Sized;
+    /// Kvlist entries as a slice; `None` on backends that don't materialize
+    /// entries, so prefer `kvlist_iter` in backend-agnostic code
     fn as_kvlist(&self) -> Option<&[Self::KeyValue]>;
+    /// Value of the first kvlist entry with `key`, for map-like lookup in
+    /// structured bodies and attributes. `None` if this is not a kvlist, no
+    /// entry has the key, or that entry's value is unset.
+    fn kvlist_get(&self, key: &str) -> Option<&<Self::KeyValue as AttributeView>::AnyValue> {
+        self.as_kvlist()?.iter().find(|entry| entry.key() == key)?.value()
+    }
+    /// Iterate array elements on any backend. Materialized backends yield
+    /// references, lazy ones yield elements by value, hence `Borrow`.
+    fn array_iter(&self) -> Option<impl Iterator<Item = impl Borrow<Self::ArrayElement>>>;
+    /// Iterate kvlist entries on any backend, in wire order, like
+    /// `array_iter` does for arrays
+    fn kvlist_iter(&self) -> Option<impl Iterator<Item = impl Borrow<Self::KeyValue>>>;
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -94,45 +678,160 @@ pub enum ValueType {
     KeyValueList,
 }
 
-// Function to inspect logs data using the traits
-pub fn inspect_logs<'a, L: LogsView<'a>>(logs: &'a L) {
-    println!("🔍 Inspecting Logs Data");
-    println!("========================");
-    
-    for (resource_idx, resource) in logs.resources().enumerate() {
-        println!("📦 Resource {}: {}", resource_idx + 1, resource.resource());
-        
-        for (scope_idx, scope) in resource.scopes().enumerate() {
-            println!("  🔧 Scope {}: {} (version: {:?})", 
-                     scope_idx + 1, scope.scope(), scope.version());
-            
-            for (record_idx, record) in scope.log_records().enumerate() {
-                println!("    📝 Log Record {}: {}", record_idx + 1, record.name());
-                
-                if let Some(ts) = record.timestamp() {
-                    println!("       ⏰ Timestamp: {}", ts);
-                }
-                
-                for attr in record.attributes() {
-                    print!("       🏷️  {}: ", attr.key());
-                    
-                    let value = attr.value();
-                    if let Some(value) = value {
-                        match value.value_type() {
-                            ValueType::String => println!("\"{}\"", value.as_string().unwrap_or("N/A")),
-                            ValueType::Bool => println!("{}", value.as_bool().unwrap_or(false)),
-                            ValueType::Int64 => println!("{}", value.as_int64().unwrap_or(0)),
-                            ValueType::Double => println!("{}", value.as_double().unwrap_or(0.0)),
-                            ValueType::Bytes => println!("bytes[{}]", value.as_bytes().map(|b| b.len()).unwrap_or(0)),
-                            ValueType::Array => println!("array[{}]", value.as_array().map(|a| a.len()).unwrap_or(0)),
-                            ValueType::KeyValueList => println!("kvlist[{}]", value.as_kvlist().map(|kv| kv.len()).unwrap_or(0)),
-                        }
-                    }
-                }
-                println!();
-            }
+/// An attribute value with its payload extracted. Containers keep a
+/// reference to the value itself, for `array_iter` or `kvlist_iter`.
+#[derive(Debug)]
+pub enum TypedValue<'v, V> {
+    String(&'v str),
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+    Bytes(&'v [u8]),
+    Array(&'v V),
+    KeyValueList(&'v V),
+}
+
+impl<'v, V: AnyValueView> TypedValue<'v, V> {
+    /// Extract `value` according to its `value_type()`. `None` if the value
+    /// is unset, as when an AnyValue carries no variant.
+    pub fn from_value(value: &'v V) -> Option<Self> {
+        Some(match value.value_type() {
+            ValueType::String => TypedValue::String(value.as_string()?),
+            ValueType::Bool => TypedValue::Bool(value.as_bool()?),
+            ValueType::Int64 => TypedValue::Int(value.as_int64()?),
+            ValueType::Double => TypedValue::Double(value.as_double()?),
+            ValueType::Bytes => TypedValue::Bytes(value.as_bytes()?),
+            ValueType::Array => TypedValue::Array(value),
+            ValueType::KeyValueList => TypedValue::KeyValueList(value),
+        })
+    }
+}
+
+impl<'v, V> Clone for TypedValue<'v, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'v, V> Copy for TypedValue<'v, V> {}
+
+impl<'v, V> PartialEq for TypedValue<'v, V> {
+    /// Scalars compare by value; containers by identity, since comparing
+    /// their contents is backend-specific
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TypedValue::String(a), TypedValue::String(b)) => a == b,
+            (TypedValue::Bool(a), TypedValue::Bool(b)) => a == b,
+            (TypedValue::Int(a), TypedValue::Int(b)) => a == b,
+            (TypedValue::Double(a), TypedValue::Double(b)) => a == b,
+            (TypedValue::Bytes(a), TypedValue::Bytes(b)) => a == b,
+            (TypedValue::Array(a), TypedValue::Array(b)) => std::ptr::eq(*a, *b),
+            (TypedValue::KeyValueList(a), TypedValue::KeyValueList(b)) => std::ptr::eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+/// Short names for `SeverityNumber` values, indexed by number, as given in
+/// the OTLP logs data model
+const SEVERITY_SHORT_NAMES: [&str; 25] = [
+    "UNSPECIFIED",
+    "TRACE", "TRACE2", "TRACE3", "TRACE4",
+    "DEBUG", "DEBUG2", "DEBUG3", "DEBUG4",
+    "INFO", "INFO2", "INFO3", "INFO4",
+    "WARN", "WARN2", "WARN3", "WARN4",
+    "ERROR", "ERROR2", "ERROR3", "ERROR4",
+    "FATAL", "FATAL2", "FATAL3", "FATAL4",
+];
+
+/// Raw `LogRecord.flags` value. The low byte holds the W3C trace flags; bits
+/// 8 and 9 follow OTLP's `SpanFlags` for whether the parent context is remote.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LogRecordFlags(pub u32);
+
+impl LogRecordFlags {
+    pub const TRACE_FLAGS_MASK: u32 = 0x0000_00FF;
+    pub const CONTEXT_HAS_IS_REMOTE_MASK: u32 = 0x0000_0100;
+    pub const CONTEXT_IS_REMOTE_MASK: u32 = 0x0000_0200;
+    /// W3C `sampled` flag within the trace flags
+    pub const SAMPLED: u8 = 0x01;
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Lower 8 bits: the W3C trace flags
+    pub fn trace_flags(&self) -> u8 {
+        (self.0 & Self::TRACE_FLAGS_MASK) as u8
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        self.trace_flags() & Self::SAMPLED != 0
+    }
+
+    /// Whether the producer recorded if the parent context is remote
+    pub fn has_is_remote(&self) -> bool {
+        self.0 & Self::CONTEXT_HAS_IS_REMOTE_MASK != 0
+    }
+
+    /// Whether the parent context is remote; `None` when the producer did
+    /// not record it
+    pub fn is_remote(&self) -> Option<bool> {
+        self.has_is_remote().then_some(self.0 & Self::CONTEXT_IS_REMOTE_MASK != 0)
+    }
+}
+
+impl std::fmt::Debug for LogRecordFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("LogRecordFlags");
+        debug.field("trace_flags", &format_args!("{:#04x}", self.trace_flags()));
+        debug.field("sampled", &self.is_sampled());
+        debug.field("is_remote", &self.is_remote());
+        let known = Self::TRACE_FLAGS_MASK | Self::CONTEXT_HAS_IS_REMOTE_MASK | Self::CONTEXT_IS_REMOTE_MASK;
+        if self.0 & !known != 0 {
+            debug.field("unknown", &format_args!("{:#x}", self.0 & !known));
+        }
+        debug.finish()
+    }
+}
+
+/// A record's trace correlation fields with their lengths checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TraceContext {
+    /// `None` unless the trace id is 16 bytes and not all zero
+    pub trace_id: Option<[u8; 16]>,
+    /// `None` unless the span id is 8 bytes and not all zero
+    pub span_id: Option<[u8; 8]>,
+    /// W3C trace flags, the low byte of `LogRecord.flags`; 0 when unset
+    pub flags: u8,
+}
+
+impl TraceContext {
+    /// Build from raw record fields, dropping ids of the wrong length and
+    /// all-zero (invalid) ids
+    pub fn from_raw(trace_id: Option<&[u8]>, span_id: Option<&[u8]>, flags: Option<u32>) -> Self {
+        Self {
+            trace_id: fixed_id(trace_id),
+            span_id: fixed_id(span_id),
+            flags: flags.map_or(0, |flags| LogRecordFlags(flags).trace_flags()),
         }
     }
+
+    pub fn is_sampled(&self) -> bool {
+        self.flags & LogRecordFlags::SAMPLED != 0
+    }
+}
+
+fn fixed_id<const N: usize>(id: Option<&[u8]>) -> Option<[u8; N]> {
+    id.and_then(|id| <[u8; N]>::try_from(id).ok())
+        .filter(|id| id.iter().any(|&b| b != 0))
+}
+
+// Function to inspect logs data using the traits
+pub fn inspect_logs<'a, L: LogsView<'a>>(logs: &'a L) {
+    let mut out = String::new();
+    print::write_logs(&mut out, logs).expect("writing to a String cannot fail");
+    print!("{}", out);
 }
 
 pub fn create_test_logs() -> LogsData {
@@ -380,6 +1079,125 @@ pub fn create_test_logs() -> LogsData {
     }
 }
 
+/// Generate `resource_count` distinct resources shaped like real traffic:
+/// unique service names, 1-3 scopes per resource, 1-12 records per scope,
+/// and attributes covering every value type (negative ints, bytes, arrays
+/// and kvlists included). Deterministic, so benchmark runs are comparable.
+pub fn create_varied_large_logs(resource_count: usize) -> LogsData {
+    fn attr(key: &str, value: any_value::Value) -> KeyValue {
+        KeyValue {
+            key: key.to_string(),
+            value: Some(AnyValue { value: Some(value) }),
+        }
+    }
+
+    fn string(value: impl Into<String>) -> any_value::Value {
+        any_value::Value::StringValue(value.into())
+    }
+
+    const SEVERITIES: [(i32, &str); 5] = [(5, "DEBUG"), (9, "INFO"), (9, "INFO"), (13, "WARN"), (17, "ERROR")];
+    const METHODS: [&str; 4] = ["GET", "POST", "PUT", "DELETE"];
+
+    // Small LCG so record counts and values vary without a rand dependency
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move |bound: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % bound
+    };
+
+    let resource_logs = (0..resource_count)
+        .map(|r| {
+            let resource = Resource {
+                attributes: vec![
+                    attr("service.name", string(format!("service-{}", r))),
+                    attr("service.version", string(format!("{}.{}.{}", r % 3, r % 7, r % 11))),
+                    attr("host.cpu_count", any_value::Value::IntValue(1 + next(64) as i64)),
+                    attr("cloud.region", string(["us-east-1", "eu-west-1", "ap-south-1"][r % 3])),
+                ],
+                dropped_attributes_count: 0,
+                entity_refs: vec![],
+            };
+
+            let scope_logs = (0..1 + next(3))
+                .map(|s| {
+                    let log_records = (0..1 + next(12))
+                        .map(|i| {
+                            let (severity_number, severity_text) = SEVERITIES[next(SEVERITIES.len() as u64) as usize];
+                            let timestamp = 1718380800000000000 + (r as u64 * 1_000 + i) * 1_000_000;
+                            let mut attributes = vec![
+                                attr("http.method", string(METHODS[next(4) as usize])),
+                                attr("http.status_code", any_value::Value::IntValue(200 + next(400) as i64)),
+                                attr("duration_ms", any_value::Value::DoubleValue(next(10_000) as f64 / 7.0)),
+                                attr("retry", any_value::Value::BoolValue(next(2) == 1)),
+                                attr("offset_delta", any_value::Value::IntValue(-(next(1 << 20) as i64) - 1)),
+                            ];
+                            match next(4) {
+                                0 => attributes.push(attr(
+                                    "payload.digest",
+                                    any_value::Value::BytesValue((0..16).map(|_| next(256) as u8).collect()),
+                                )),
+                                1 => attributes.push(attr(
+                                    "tags",
+                                    any_value::Value::ArrayValue(ArrayValue {
+                                        values: (0..1 + next(4))
+                                            .map(|t| AnyValue { value: Some(string(format!("tag-{}", t))) })
+                                            .collect(),
+                                    }),
+                                )),
+                                2 => attributes.push(attr(
+                                    "user",
+                                    any_value::Value::KvlistValue(KeyValueList {
+                                        values: vec![
+                                            attr("id", any_value::Value::IntValue(next(100_000) as i64)),
+                                            attr("name", string(format!("user-{}", next(1_000)))),
+                                        ],
+                                    }),
+                                )),
+                                _ => {}
+                            }
+
+                            LogRecord {
+                                time_unix_nano: timestamp,
+                                observed_time_unix_nano: timestamp + next(1_000_000),
+                                severity_number,
+                                severity_text: severity_text.to_string(),
+                                body: Some(AnyValue {
+                                    value: Some(string(format!("request {} handled by service-{}", i, r))),
+                                }),
+                                attributes,
+                                dropped_attributes_count: 0,
+                                flags: 1,
+                                trace_id: (0..16).map(|_| next(256) as u8).collect(),
+                                span_id: (0..8).map(|_| next(256) as u8).collect(),
+                                event_name: String::new(),
+                            }
+                        })
+                        .collect();
+
+                    ScopeLogs {
+                        scope: Some(InstrumentationScope {
+                            name: format!("scope-{}", s),
+                            version: format!("{}.0.0", s + 1),
+                            attributes: vec![],
+                            dropped_attributes_count: 0,
+                        }),
+                        log_records,
+                        schema_url: String::new(),
+                    }
+                })
+                .collect();
+
+            ResourceLogs {
+                resource: Some(resource),
+                scope_logs,
+                schema_url: String::new(),
+            }
+        })
+        .collect();
+
+    LogsData { resource_logs }
+}
+
 // Helper function to encode LogsData to bytes
 pub fn encode_logs_data(logs: &LogsData) -> Vec<u8> {
     let mut buf = Vec::new();
@@ -471,6 +1289,24 @@ mod tests {
     use super::*;
     use crate::proto::opentelemetry::proto::common::v1::any_value;
 
+    // Fixture builders shared by the attribute and kvlist tests
+
+    fn string(value: &str) -> AnyValue {
+        AnyValue { value: Some(any_value::Value::StringValue(value.to_string())) }
+    }
+
+    fn int(value: i64) -> AnyValue {
+        AnyValue { value: Some(any_value::Value::IntValue(value)) }
+    }
+
+    fn entry(key: &str, value: Option<AnyValue>) -> KeyValue {
+        KeyValue { key: key.to_string(), value }
+    }
+
+    fn kvlist(values: Vec<KeyValue>) -> AnyValue {
+        AnyValue { value: Some(any_value::Value::KvlistValue(KeyValueList { values })) }
+    }
+
     #[test]
     fn test_logs_view_iteration() {
         let logs = create_test_logs();
@@ -527,14 +1363,14 @@ mod tests {
         let records: Vec<_> = scopes[0].log_records().collect();
         
         // Test first log record
-        assert_eq!(records[0].name(), "log_record");
+        assert_eq!(records[0].name(), "HTTP Request");
         assert_eq!(records[0].timestamp(), Some(1718380800000000000));
         
         let attributes: Vec<_> = records[0].attributes().collect();
         assert_eq!(attributes.len(), 4);
         
         // Test second log record
-        assert_eq!(records[1].name(), "log_record");
+        assert_eq!(records[1].name(), "HTTP Error");
         assert_eq!(records[1].timestamp(), Some(1718380801000000000));
     }
 
@@ -637,7 +1473,7 @@ mod tests {
         
         let records: Vec<_> = scopes[0].log_records().collect();
         assert_eq!(records.len(), 1);
-        assert_eq!(records[0].name(), "log_record");
+        assert_eq!(records[0].name(), "Job Processing");
     }
 
     #[test]
@@ -750,14 +1586,14 @@ mod tests {
         let records: Vec<_> = scopes[0].log_records().collect();
         
         // Test first log record
-        assert_eq!(records[0].name(), "log_record");
+        assert_eq!(records[0].name(), "HTTP Request");
         assert_eq!(records[0].timestamp(), Some(1718380800000000000));
         
         let attributes: Vec<_> = records[0].attributes().collect();
         assert_eq!(attributes.len(), 4);
         
         // Test second log record
-        assert_eq!(records[1].name(), "log_record");
+        assert_eq!(records[1].name(), "HTTP Error");
         assert_eq!(records[1].timestamp(), Some(1718380801000000000));
     }
 
@@ -828,4 +1664,3790 @@ mod tests {
         assert_eq!(total_records, 4); // 2 HTTP + 1 DB + 1 background worker record 
         assert_eq!(total_attributes, 13); // 4 + 3 + 3 + 3 (simplified count)
     }
-}
\ No newline at end of file
+    #[test]
+    fn test_lazy_body_lines() {
+        let string_value = |s: &str| AnyValue {
+            value: Some(any_value::Value::StringValue(s.to_string())),
+        };
+        let logs = LogsData {
+            resource_logs: vec![ResourceLogs {
+                resource: None,
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::ArrayValue(ArrayValue {
+                                values: vec![
+                                    string_value("Traceback (most recent call last):"),
+                                    string_value("  File \"app.py\", line 1"),
+                                    string_value("ValueError: bad input"),
+                                ],
+                            })),
+                        }),
+                        ..Default::default()
+                    }],
+                    schema_url: "".to_string(),
+                }],
+                schema_url: "".to_string(),
+            }],
+        };
+        let encoded = encode_logs_data(&logs);
+        let lazy_logs = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        let resource = lazy_logs.resource_logs().next().unwrap();
+        let scope = resource.scope_logs().next().unwrap();
+        let record = scope.log_records().next().unwrap();
+        let lines: Vec<_> = record.body_lines().unwrap().collect();
+
+        assert_eq!(lines, vec![
+            "Traceback (most recent call last):",
+            "  File \"app.py\", line 1",
+            "ValueError: bad input",
+        ]);
+
+        // Non-string elements are skipped; a non-array body has no lines
+        let mixed = LogRecord {
+            body: Some(AnyValue {
+                value: Some(any_value::Value::ArrayValue(ArrayValue {
+                    values: vec![
+                        string_value("first"),
+                        AnyValue { value: Some(any_value::Value::IntValue(2)) },
+                        AnyValue { value: Some(any_value::Value::BoolValue(true)) },
+                        string_value("last"),
+                    ],
+                })),
+            }),
+            ..Default::default()
+        };
+        let encoded = mixed.encode_to_vec();
+        let record = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+        assert_eq!(record.body_lines().unwrap().collect::<Vec<_>>(), ["first", "last"]);
+
+        let single = LogRecord { body: Some(string_value("one line")), ..Default::default() };
+        let encoded = single.encode_to_vec();
+        assert!(otlp_bytes_lazy::LogRecordParser::new(&encoded).body_lines().is_none());
+    }
+
+    #[test]
+    fn test_lazy_container_values_unwrap_their_message() {
+        // ArrayValue and KeyValueList are messages of their own: the elements
+        // are tag 1 inside the tag 5/6 payload, not repeated tag 5/6 fields
+        let array = AnyValue {
+            value: Some(any_value::Value::ArrayValue(ArrayValue {
+                values: vec![
+                    AnyValue { value: Some(any_value::Value::IntValue(1)) },
+                    AnyValue { value: Some(any_value::Value::StringValue("a".to_string())) },
+                ],
+            })),
+        };
+        let encoded = array.encode_to_vec();
+        let lazy = otlp_bytes_lazy::AnyValueParser::new(&encoded);
+        let elements: Vec<_> = lazy.array_value().unwrap().collect();
+        assert_eq!(elements.len(), 2);
+        assert_eq!(elements[0].int_value(), Some(1));
+        assert_eq!(elements[1].string_value(), Some("a"));
+        assert!(lazy.kvlist_value().is_none());
+
+        let kvlist = AnyValue {
+            value: Some(any_value::Value::KvlistValue(KeyValueList {
+                values: vec![
+                    KeyValue { key: "x".to_string(), value: Some(AnyValue { value: Some(any_value::Value::BoolValue(true)) }) },
+                    KeyValue { key: "y".to_string(), value: None },
+                ],
+            })),
+        };
+        let encoded = kvlist.encode_to_vec();
+        let lazy = otlp_bytes_lazy::AnyValueParser::new(&encoded);
+        let keys: Vec<_> = lazy.kvlist_value().unwrap().map(|entry| entry.key()).collect();
+        assert_eq!(keys, [Some("x"), Some("y")]);
+        assert!(lazy.array_value().is_none());
+
+        // An empty container is present but yields nothing
+        let empty = AnyValue { value: Some(any_value::Value::ArrayValue(ArrayValue { values: Vec::new() })) };
+        let encoded = empty.encode_to_vec();
+        assert_eq!(otlp_bytes_lazy::AnyValueParser::new(&encoded).array_value().unwrap().count(), 0);
+
+        // Tag 5 with a non-length-delimited wire type is not an array
+        assert!(otlp_bytes_lazy::AnyValueParser::new(&[0x28, 0x01]).array_value().is_none());
+    }
+
+    #[test]
+    fn test_bytes_hex_and_base64_rendering() {
+        let value = AnyValue {
+            value: Some(any_value::Value::BytesValue(vec![0xde, 0xad, 0xbe, 0xef, 0x01])),
+        };
+        let encoded = value.encode_to_vec();
+
+        let mut eager = otlp_bytes::AnyValue::new();
+        assert!(eager.parse(&encoded));
+        assert_eq!(eager.bytes_as_hex().as_deref(), Some("deadbeef01"));
+
+        let lazy = otlp_bytes_lazy::AnyValueParser::new(&encoded);
+        assert_eq!(lazy.bytes_as_hex().as_deref(), Some("deadbeef01"));
+
+        let mut buf = String::from("id=");
+        lazy.write_bytes_hex(&mut buf).unwrap();
+        assert_eq!(buf, "id=deadbeef01");
+
+        assert_eq!(eager.bytes_as_base64().as_deref(), Some("3q2+7wE="));
+        assert_eq!(lazy.bytes_as_base64().as_deref(), Some("3q2+7wE="));
+
+        // The built-in encoder agrees with the base64 crate the decoders use
+        #[cfg(feature = "base64")]
+        {
+            use base64::Engine;
+            let bytes: Vec<u8> = (0..=255).collect();
+            for len in [0, 1, 2, 3, 4, 5, 254, 255, 256] {
+                let mut encoded = String::new();
+                util::write_base64(&mut encoded, &bytes[..len]).unwrap();
+                assert_eq!(encoded, base64::engine::general_purpose::STANDARD.encode(&bytes[..len]));
+            }
+        }
+
+        let not_bytes = AnyValue {
+            value: Some(any_value::Value::IntValue(7)),
+        }
+        .encode_to_vec();
+        assert_eq!(otlp_bytes_lazy::AnyValueParser::new(&not_bytes).bytes_as_hex(), None);
+    }
+
+    #[test]
+    fn test_lazy_field_wire_type() {
+        // time_unix_nano (tag 1, fixed64) followed by severity_number (tag 2)
+        // mis-encoded as a length-delimited string
+        let mut record_bytes = vec![0x09];
+        record_bytes.extend_from_slice(&1718380800000000000u64.to_le_bytes());
+        record_bytes.extend_from_slice(&[0x12, 0x04, b'W', b'A', b'R', b'N']);
+
+        let record = otlp_bytes_lazy::LogRecordParser::new(&record_bytes);
+
+        assert_eq!(record.field_wire_type(2), Some(2));
+        assert_eq!(record.severity_number(), 0);
+        assert_eq!(record.field_wire_type(1), Some(1));
+        assert_eq!(record.field_wire_type(3), None);
+        assert_eq!(record.field_wire_type(99), None);
+    }
+
+    #[test]
+    fn test_records_sorted_by_time() {
+        let mut logs = create_test_logs();
+        // Put the background worker (latest record) first so sorting has work to do
+        logs.resource_logs.reverse();
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+
+        let expected = vec![
+            1718380800000000000,
+            1718380801000000000,
+            1718380802000000000,
+            1718380803000000000,
+        ];
+
+        let sorted = logs.records_sorted_by_time();
+        let timestamps: Vec<_> = sorted.iter().filter_map(|r| r.timestamp).collect();
+        assert_eq!(timestamps, expected);
+        assert_eq!((sorted[3].resource_index, sorted[3].scope_index, sorted[3].record_index), (0, 0, 0));
+        assert_eq!(sorted[0].record.event_name, "HTTP Request");
+
+        let sorted = bytes_logs.records_sorted_by_time();
+        let timestamps: Vec<_> = sorted.iter().filter_map(|r| r.timestamp).collect();
+        assert_eq!(timestamps, expected);
+        assert_eq!((sorted[2].resource_index, sorted[2].scope_index, sorted[2].record_index), (1, 1, 0));
+    }
+
+    #[test]
+    fn test_parse_with_limits() {
+        use otlp_bytes::{LimitExceeded, LimitPolicy, ParseLimits};
+
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+
+        // The first HTTP record has 4 attributes, more than the limit of 3
+        let limits = ParseLimits { max_attributes: 3, ..ParseLimits::default() };
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert_eq!(bytes_logs.parse_with_limits(&encoded, &limits), Ok(true));
+        assert_eq!(bytes_logs.limit_exceeded, Some(LimitExceeded::Attributes));
+        let resources: Vec<_> = bytes_logs.resources().collect();
+        assert_eq!(resources.len(), 2);
+        let scopes: Vec<_> = resources[0].scopes().collect();
+        let records: Vec<_> = scopes[0].log_records().collect();
+        let keys: Vec<_> = records[0].attributes().map(|a| a.key()).collect();
+        assert_eq!(keys, vec!["method", "status_code", "response_time_ms"]);
+
+        let limits = ParseLimits { policy: LimitPolicy::Error, ..limits };
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert_eq!(bytes_logs.parse_with_limits(&encoded, &limits), Err(LimitExceeded::Attributes));
+
+        // The last record runs past the byte budget; its resource and scope
+        // start within it, so they are still kept
+        let limits = ParseLimits { max_total_bytes: encoded.len() - 1, ..ParseLimits::default() };
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert_eq!(bytes_logs.parse_with_limits(&encoded, &limits), Ok(true));
+        assert_eq!(bytes_logs.limit_exceeded, Some(LimitExceeded::TotalBytes));
+        assert_eq!(bytes_logs.resources().count(), 2);
+        assert_eq!(bytes_logs.summarize().record_count, 3);
+
+        // A resource starting past the budget is dropped entirely
+        let second_start = encoded.len() - logs.resource_logs[1].encoded_len();
+        let limits = ParseLimits { max_total_bytes: second_start, ..ParseLimits::default() };
+        assert_eq!(bytes_logs.parse_with_limits(&encoded, &limits), Ok(true));
+        assert_eq!(bytes_logs.limit_exceeded, Some(LimitExceeded::TotalBytes));
+        assert_eq!(bytes_logs.resources().count(), 1);
+
+        // The budget is also checked per record, inside a single resource
+        let single = LogsData { resource_logs: vec![logs.resource_logs[0].clone()] };
+        let encoded_single = encode_logs_data(&single);
+        let first = single.resource_logs[0].scope_logs[0].log_records[0].encode_to_vec();
+        let first_start = encoded_single.windows(first.len()).position(|w| w == &first[..]).unwrap();
+        let limits = ParseLimits { max_total_bytes: first_start + first.len(), ..ParseLimits::default() };
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert_eq!(bytes_logs.parse_with_limits(&encoded_single, &limits), Ok(true));
+        assert_eq!(bytes_logs.limit_exceeded, Some(LimitExceeded::TotalBytes));
+        let scope = bytes_logs.resources().next().unwrap().scopes().next().unwrap();
+        assert_eq!(scope.log_records().count(), 1);
+        let limits = ParseLimits { policy: LimitPolicy::Error, ..limits };
+        assert_eq!(bytes_logs.parse_with_limits(&encoded_single, &limits), Err(LimitExceeded::TotalBytes));
+
+        // Within limits, nothing is reported
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert_eq!(bytes_logs.parse_with_limits(&encoded, &ParseLimits::default()), Ok(true));
+        assert_eq!(bytes_logs.limit_exceeded, None);
+        assert_eq!(bytes_logs.resources().count(), 2);
+    }
+
+    #[test]
+    fn test_parse_with_depth_limit() {
+        use otlp_bytes::{LimitExceeded, ParseLimits};
+
+        let kvlist = |values: Vec<KeyValue>| AnyValue {
+            value: Some(any_value::Value::KvlistValue(KeyValueList { values })),
+        };
+        // http = { headers = { accept = "*/*" } }
+        let attribute = KeyValue {
+            key: "http".to_string(),
+            value: Some(kvlist(vec![KeyValue {
+                key: "headers".to_string(),
+                value: Some(kvlist(vec![KeyValue {
+                    key: "accept".to_string(),
+                    value: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue("*/*".to_string())),
+                    }),
+                }])),
+            }])),
+        };
+        let record = LogRecord {
+            attributes: vec![attribute],
+            ..Default::default()
+        };
+        let encoded = record.encode_to_vec();
+
+        let mut unlimited = otlp_bytes::LogRecord::new();
+        assert!(unlimited.parse(&encoded));
+        let http = unlimited.attributes[0].value.as_ref().unwrap().kvlist_value().unwrap();
+        let headers = http[0].value.as_ref().unwrap().kvlist_value().unwrap();
+        assert_eq!(headers[0].key, "accept");
+
+        let logs = LogsData {
+            resource_logs: vec![ResourceLogs {
+                resource: None,
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![record],
+                    schema_url: "".to_string(),
+                }],
+                schema_url: "".to_string(),
+            }],
+        };
+        let encoded = encode_logs_data(&logs);
+        let limits = ParseLimits { max_depth: 1, ..ParseLimits::default() };
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert_eq!(bytes_logs.parse_with_limits(&encoded, &limits), Ok(true));
+        assert_eq!(bytes_logs.limit_exceeded, Some(LimitExceeded::Depth));
+        let record = &bytes_logs.resource_logs[0].scope_logs[0].log_records[0];
+        let http = record.attributes[0].value.as_ref().unwrap().kvlist_value().unwrap();
+        assert_eq!(http[0].key, "headers");
+        assert!(http[0].value.as_ref().unwrap().kvlist_value().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_bytes_into_iterator() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+
+        let mut resource_count = 0;
+        let mut record_count = 0;
+        for resource in &bytes_logs {
+            resource_count += 1;
+            for scope in resource {
+                for record in scope {
+                    record_count += 1;
+                    assert!(record.timestamp().is_some());
+                }
+            }
+        }
+        assert_eq!(resource_count, 2);
+        assert_eq!(record_count, 4);
+
+        // Coexists with the trait methods
+        assert_eq!((&bytes_logs).into_iter().count(), bytes_logs.resources().count());
+    }
+
+    #[test]
+    fn test_bytes_reset_clears_stale_slots() {
+        let with_body = create_test_logs();
+        let encoded_with_body = encode_logs_data(&with_body);
+
+        let mut without_body = create_test_logs();
+        without_body.resource_logs.truncate(1);
+        for scope in &mut without_body.resource_logs[0].scope_logs {
+            for record in &mut scope.log_records {
+                record.body = None;
+            }
+        }
+        let encoded_without_body = encode_logs_data(&without_body);
+
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded_with_body);
+        assert!(bytes_logs.resource_logs[0].scope_logs[0].log_records[0].body.is_some());
+
+        bytes_logs.reset();
+        assert_eq!(bytes_logs.used_count, 0);
+        for resource_log in &bytes_logs.resource_logs {
+            assert!(resource_log.resource.is_none());
+            for scope_log in &resource_log.scope_logs {
+                assert!(scope_log.scope.is_none());
+                for record in &scope_log.log_records {
+                    assert!(record.body.is_none());
+                    assert!(record.attributes.iter().all(|attr| attr.value.is_none()));
+                }
+            }
+        }
+        // Preallocated slots are kept for reuse
+        assert_eq!(bytes_logs.resource_logs.len(), 2);
+
+        bytes_logs.parse(&encoded_without_body);
+        assert_eq!(bytes_logs.resources().count(), 1);
+        for resource in bytes_logs.resources() {
+            for scope in resource.scopes() {
+                for record in scope.log_records() {
+                    assert!(record.body.is_none());
+                }
+            }
+        }
+        assert!(bytes_logs.resource_logs[1].resource.is_none());
+    }
+
+    #[test]
+    fn test_bytes_resource_entity_refs() {
+        let mut logs = create_test_logs();
+        logs.resource_logs[0].resource.as_mut().unwrap().entity_refs = vec![EntityRef {
+            schema_url: "https://opentelemetry.io/schemas/1.26.0".to_string(),
+            r#type: "service".to_string(),
+            id_keys: vec!["service.name".to_string()],
+            description_keys: vec!["service.version".to_string(), "deployment.environment".to_string()],
+        }];
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+
+        let resource = bytes_logs.resource_logs[0].resource.as_ref().unwrap();
+        let entity_refs = resource.entity_refs();
+        assert_eq!(entity_refs.len(), 1);
+        assert_eq!(entity_refs[0].schema_url, Some("https://opentelemetry.io/schemas/1.26.0"));
+        assert_eq!(entity_refs[0].r#type, Some("service"));
+        assert_eq!(entity_refs[0].id_keys, vec!["service.name"]);
+        assert_eq!(entity_refs[0].description_keys, vec!["service.version", "deployment.environment"]);
+
+        let second = bytes_logs.resource_logs[1].resource.as_ref().unwrap();
+        assert!(second.entity_refs().is_empty());
+
+        // Both backends expose the refs the same way through the view trait
+        type EntityRefFields<'a> = (Option<&'a str>, Option<&'a str>, Vec<&'a str>, Vec<&'a str>);
+        fn view_refs<'a, R: ResourceLogsView<'a>>(resource: &'a R) -> Vec<EntityRefFields<'a>> {
+            resource
+                .entity_refs()
+                .map(|entity| (entity.schema_url(), entity.entity_type(), entity.id_keys().collect(), entity.description_keys().collect()))
+                .collect()
+        }
+        let expected = vec![(
+            Some("https://opentelemetry.io/schemas/1.26.0"),
+            Some("service"),
+            vec!["service.name"],
+            vec!["service.version", "deployment.environment"],
+        )];
+        assert_eq!(view_refs(&logs.resource_logs[0]), expected);
+        assert_eq!(view_refs(&bytes_logs.resource_logs[0]), expected);
+        assert!(view_refs(&logs.resource_logs[1]).is_empty());
+        assert!(view_refs(&bytes_logs.resource_logs[1]).is_empty());
+
+        // Entity refs count against the attribute limit like attributes do
+        let resource = logs.resource_logs[0].resource.as_mut().unwrap();
+        resource.entity_refs = vec![resource.entity_refs[0].clone(); 3];
+        let encoded = encode_logs_data(&logs);
+        let limits = otlp_bytes::ParseLimits { max_attributes: 2, ..Default::default() };
+        assert_eq!(bytes_logs.parse_with_limits(&encoded, &limits), Ok(true));
+        assert_eq!(bytes_logs.resource_logs[0].resource.as_ref().unwrap().entity_ref_count(), 2);
+        assert_eq!(bytes_logs.limit_exceeded, Some(otlp_bytes::LimitExceeded::Attributes));
+    }
+
+    #[test]
+    fn test_diff_prost_against_bytes() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let decoded = LogsData::decode(&encoded[..]).unwrap();
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+
+        assert!(diff::logs_equal(&decoded, &bytes_logs));
+        assert!(diff::diff(&decoded, &bytes_logs).is_empty());
+
+        let mut changed = create_test_logs();
+        changed.resource_logs[0].scope_logs[0].log_records[1].attributes[1].value = Some(AnyValue {
+            value: Some(any_value::Value::IntValue(503)),
+        });
+        changed.resource_logs[1].scope_logs.clear();
+
+        let differences = diff::diff(&changed, &bytes_logs);
+        assert!(!diff::logs_equal(&changed, &bytes_logs));
+        assert_eq!(differences, vec![
+            diff::Difference {
+                path: "resource[0].scope[0].record[1].attribute[1]".to_string(),
+                field: "value",
+                left: "503".to_string(),
+                right: "500".to_string(),
+            },
+            diff::Difference {
+                path: "resource[1]".to_string(),
+                field: "scope_count",
+                left: "0".to_string(),
+                right: "1".to_string(),
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_diff_reports_body_only_change() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert!(bytes_logs.parse(&encoded));
+
+        let mut changed = create_test_logs();
+        changed.resource_logs[0].scope_logs[0].log_records[0].body = Some(AnyValue {
+            value: Some(any_value::Value::StringValue("changed".to_string())),
+        });
+
+        let differences = diff::diff(&changed, &bytes_logs);
+        assert_eq!(differences.len(), 1);
+        assert_eq!(differences[0].path, "resource[0].scope[0].record[0]");
+        assert_eq!(differences[0].field, "body");
+        assert_eq!(differences[0].left, "\"changed\"");
+
+        // Fields outside the name and timestamp are compared too
+        let mut changed = create_test_logs();
+        changed.resource_logs[1].schema_url = "https://example.com/other".to_string();
+        changed.resource_logs[0].scope_logs[0].log_records[1].trace_id = vec![7; 16];
+        let fields: Vec<_> = diff::diff(&changed, &bytes_logs).into_iter().map(|d| d.field).collect();
+        assert_eq!(fields, ["trace_id", "schema_url"]);
+    }
+
+    #[test]
+    fn test_lazy_collect_records() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let lazy_logs = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        let records = lazy_logs.collect_records();
+        assert_eq!(records.len(), 4);
+
+        let severities: Vec<_> = records.iter().map(|r| r.record.severity_number()).collect();
+        assert_eq!(severities, vec![9, 13, 5, 9]);
+
+        let paths: Vec<_> = records.iter().map(|r| (r.resource_index, r.scope_index)).collect();
+        assert_eq!(paths, vec![(0, 0), (0, 0), (0, 1), (1, 0)]);
+
+        // Offsets point at each record's payload within the original buffer
+        for flat in &records {
+            let record = otlp_bytes_lazy::LogRecordParser::new(&encoded[flat.offset..flat.offset + flat.len]);
+            assert_eq!(record.time_unix_nano(), flat.record.time_unix_nano());
+        }
+        assert!(records.windows(2).all(|w| w[0].offset < w[1].offset));
+    }
+
+    #[test]
+    fn test_effective_timestamp_precedence() {
+        let only_time = LogRecord { time_unix_nano: 100, ..Default::default() };
+        let only_observed = LogRecord { observed_time_unix_nano: 200, ..Default::default() };
+        let both = LogRecord { time_unix_nano: 100, observed_time_unix_nano: 200, ..Default::default() };
+        let neither = LogRecord::default();
+
+        for (record, expected) in [
+            (only_time, Some(100)),
+            (only_observed, Some(200)),
+            (both, Some(100)),
+            (neither, None),
+        ] {
+            assert_eq!(record.effective_timestamp(), expected);
+            assert_eq!(record.timestamp(), expected);
+
+            let encoded = record.encode_to_vec();
+            let mut eager = otlp_bytes::LogRecord::new();
+            assert!(eager.parse(&encoded));
+            assert_eq!(eager.effective_timestamp(), expected);
+            assert_eq!(eager.timestamp(), expected);
+
+            let lazy = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+            assert_eq!(lazy.effective_timestamp(), expected);
+        }
+    }
+
+    #[test]
+    fn test_attribute_matcher() {
+        use matcher::AttributeMatcher;
+
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+
+        let method_get = AttributeMatcher::equals("method", "GET");
+        let status_ok = AttributeMatcher::equals("status_code", 200i64);
+        let method_post = AttributeMatcher::equals("method", "POST");
+        let has_success = AttributeMatcher::key_exists("success");
+        let status_as_string = AttributeMatcher::equals("status_code", "200");
+
+        let prost_record = &logs.resource_logs[0].scope_logs[0].log_records[0];
+        let bytes_record = &bytes_logs.resource_logs[0].scope_logs[0].log_records[0];
+
+        assert!(method_get.matches_record(prost_record));
+        assert!(status_ok.matches_record(prost_record));
+        assert!(!method_post.matches_record(prost_record));
+        assert!(has_success.matches_record(prost_record));
+        assert!(!status_as_string.matches_record(prost_record));
+
+        assert!(method_get.matches_record(bytes_record));
+        assert!(status_ok.matches_record(bytes_record));
+        assert!(!method_post.matches_record(bytes_record));
+        assert!(has_success.matches_record(bytes_record));
+        assert!(!status_as_string.matches_record(bytes_record));
+
+        let method_attr = bytes_record.attributes().next().unwrap();
+        assert!(method_get.matches(method_attr));
+        assert!(!status_ok.matches(method_attr));
+    }
+
+    #[test]
+    fn test_write_ndjson() {
+        let mut logs = create_test_logs();
+        logs.resource_logs[1].scope_logs[0].log_records[0].attributes.push(KeyValue {
+            key: "payload".to_string(),
+            value: Some(AnyValue {
+                value: Some(any_value::Value::BytesValue(vec![0xde, 0xad, 0xbe, 0xef, 0x01])),
+            }),
+        });
+        logs.resource_logs[0].scope_logs[0].log_records[1].body = Some(AnyValue {
+            value: Some(any_value::Value::StringValue("line \"one\"\n\tline two".to_string())),
+        });
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+
+        let mut prost_out = Vec::new();
+        json::write_ndjson(&logs, &mut prost_out).unwrap();
+        let mut bytes_out = Vec::new();
+        json::write_ndjson(&bytes_logs, &mut bytes_out).unwrap();
+        assert_eq!(prost_out, bytes_out);
+
+        let output = String::from_utf8(bytes_out).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 4);
+
+        assert_eq!(lines[0]["resource"]["attributes"][0]["key"], "service.name");
+        assert_eq!(lines[0]["resource"]["attributes"][0]["value"]["stringValue"], "web-server");
+        assert_eq!(lines[0]["scope"]["name"], "http-handler");
+        assert_eq!(lines[0]["timeUnixNano"], "1718380800000000000");
+        assert_eq!(lines[0]["severityNumber"], 9);
+        assert_eq!(lines[0]["attributes"][1]["value"]["intValue"], "200");
+        assert_eq!(lines[0]["attributes"][2]["value"]["doubleValue"], 45.7);
+        assert_eq!(lines[1]["body"]["stringValue"], "line \"one\"\n\tline two");
+        assert_eq!(lines[3]["resource"]["attributes"][0]["value"]["stringValue"], "background-worker");
+        assert_eq!(lines[3]["attributes"][3]["value"]["bytesValue"], "3q2+7wE=");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_write_ndjson_round_trips() {
+        let mut logs = create_test_logs();
+        let record = &mut logs.resource_logs[0].scope_logs[0].log_records[0];
+        record.trace_id = vec![0x5b; 16];
+        record.span_id = vec![0x05; 8];
+        record.flags = 1;
+        record.event_name = "http.request".to_string();
+        record.dropped_attributes_count = 2;
+
+        let mut out = Vec::new();
+        json::write_ndjson(&logs, &mut out).unwrap();
+        let output = String::from_utf8(out).unwrap();
+        let first: serde_json::Value = serde_json::from_str(output.lines().next().unwrap()).unwrap();
+        assert_eq!(first["traceId"], "5b".repeat(16));
+        assert_eq!(first["spanId"], "0505050505050505");
+
+        // Each line, wrapped back into a LogsData document, decodes to the original record
+        let records = logs.resource_logs.iter().flat_map(|r| &r.scope_logs).flat_map(|s| &s.log_records);
+        for (line, expected) in output.lines().zip(records) {
+            let line: serde_json::Value = serde_json::from_str(line).unwrap();
+            let document = serde_json::json!({
+                "resourceLogs": [{"scopeLogs": [{"logRecords": [line]}]}]
+            });
+            let decoded = json::parse_logs(&document.to_string()).unwrap();
+            assert_eq!(&decoded.resource_logs[0].scope_logs[0].log_records[0], expected);
+        }
+    }
+
+    #[test]
+    fn test_decode_otlp_http_protobuf() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+
+        let parsed = http::decode_otlp_http("application/x-protobuf", &encoded).unwrap();
+        // Only refutable once the serde feature adds the Json variant
+        #[allow(irrefutable_let_patterns)]
+        let http::ParsedLogs::Protobuf(bytes_logs) = parsed else {
+            panic!("expected the protobuf decoder");
+        };
+        assert!(diff::logs_equal(&logs, &bytes_logs));
+
+        // Parameters and case are ignored
+        assert!(matches!(
+            http::decode_otlp_http("Application/X-Protobuf; charset=binary", &encoded),
+            Ok(http::ParsedLogs::Protobuf(_))
+        ));
+        assert!(matches!(
+            http::decode_otlp_http("text/plain", &encoded),
+            Err(http::HttpDecodeError::UnsupportedContentType(_))
+        ));
+        assert!(matches!(
+            http::decode_otlp_http("application/x-protobuf", &[0xFF]),
+            Err(http::HttpDecodeError::InvalidProtobuf)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_decode_otlp_http_json() {
+        let body = br#"{"resourceLogs":[{
+            "resource":{"attributes":[{"key":"service.name","value":{"stringValue":"web-server"}}]},
+            "scopeLogs":[{
+                "scope":{"name":"http-handler","version":"1.0.0"},
+                "logRecords":[{
+                    "timeUnixNano":"1718380800000000000",
+                    "severityNumber":9,
+                    "severityText":"INFO",
+                    "traceId":"5b8efff798038103d269b633813fc60c",
+                    "body":{"stringValue":"request served"},
+                    "attributes":[
+                        {"key":"status_code","value":{"intValue":"200"}},
+                        {"key":"ratio","value":{"doubleValue":0.5}},
+                        {"key":"payload","value":{"bytesValue":"3q2+7wE="}},
+                        {"key":"tags","value":{"arrayValue":{"values":[{"stringValue":"a"},{"boolValue":true}]}}}
+                    ]
+                }]
+            }]
+        }]}"#;
+
+        let parsed = http::decode_otlp_http("application/json; charset=utf-8", body).unwrap();
+        let http::ParsedLogs::Json(json_logs) = parsed else {
+            panic!("expected the JSON decoder");
+        };
+
+        let resource = &json_logs.resource_logs[0];
+        assert_eq!(resource.resource(), "web-server");
+        let scope = resource.scopes().next().unwrap();
+        assert_eq!(scope.scope(), "http-handler");
+        assert_eq!(scope.version(), Some("1.0.0"));
+
+        let record = &scope.log_records[0];
+        assert_eq!(record.time_unix_nano, 1718380800000000000);
+        assert_eq!(record.severity_number, 9);
+        assert_eq!(record.trace_id.len(), 16);
+        assert_eq!(record.body.as_ref().and_then(|b| b.as_string()), Some("request served"));
+
+        let attrs: Vec<_> = record.attributes().collect();
+        assert_eq!(attrs[0].value().and_then(|v| v.as_int64()), Some(200));
+        assert_eq!(attrs[1].value().and_then(|v| v.as_double()), Some(0.5));
+        assert_eq!(attrs[2].value().and_then(|v| v.as_bytes()), Some(&[0xde, 0xad, 0xbe, 0xef, 0x01][..]));
+        assert_eq!(attrs[3].value().and_then(|v| v.as_array()).map(|a| a.len()), Some(2));
+
+        assert!(matches!(
+            http::decode_otlp_http("application/json", b"{\"resourceLogs\":{}}"),
+            Err(http::HttpDecodeError::Json(json::JsonDecodeError::InvalidField("resourceLogs")))
+        ));
+
+        // 32-bit fields reject values that would be truncated
+        let invalid_field = |body: &str| match json::decode_logs_data(body.as_bytes()) {
+            Err(json::JsonDecodeError::InvalidField(field)) => field,
+            other => panic!("expected InvalidField, got {:?}", other),
+        };
+        let record = |fields: &str| format!(r#"{{"resourceLogs":[{{"scopeLogs":[{{"logRecords":[{{{}}}]}}]}}]}}"#, fields);
+        assert_eq!(invalid_field(&record(r#""flags":"4294967296""#)), "flags");
+        assert_eq!(invalid_field(&record(r#""droppedAttributesCount":4294967296"#)), "droppedAttributesCount");
+        assert_eq!(invalid_field(&record(r#""severityNumber":2147483648"#)), "severityNumber");
+        assert!(json::decode_logs_data(record(r#""flags":4294967295"#).as_bytes()).is_ok());
+
+        // Padding is only valid at the end of base64
+        let bytes_value = |encoded: &str| record(&format!(r#""body":{{"bytesValue":"{}"}}"#, encoded));
+        assert_eq!(invalid_field(&bytes_value("3q==7wE=")), "bytesValue");
+        assert!(json::decode_logs_data(bytes_value("3q2+7wE=").as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_flatten_attributes() {
+        use util::ScalarValue;
+
+        let record = LogRecord {
+            attributes: vec![
+                entry("method", Some(string("GET"))),
+                entry(
+                    "http.headers",
+                    Some(kvlist(vec![
+                        entry("content_type", Some(string("text/plain"))),
+                        entry("retry", Some(kvlist(vec![entry("count", Some(int(3)))]))),
+                    ])),
+                ),
+                KeyValue {
+                    key: "tags".to_string(),
+                    value: Some(AnyValue {
+                        value: Some(any_value::Value::ArrayValue(ArrayValue {
+                            values: vec![string("a"), AnyValue { value: Some(any_value::Value::BoolValue(true)) }],
+                        })),
+                    }),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let expected = vec![
+            ("method".to_string(), ScalarValue::String("GET".to_string())),
+            ("http.headers.content_type".to_string(), ScalarValue::String("text/plain".to_string())),
+            ("http.headers.retry.count".to_string(), ScalarValue::Int(3)),
+            ("tags.0".to_string(), ScalarValue::String("a".to_string())),
+            ("tags.1".to_string(), ScalarValue::Bool(true)),
+        ];
+        assert_eq!(util::flatten_attributes(&record), expected);
+
+        let encoded = record.encode_to_vec();
+        let mut eager = otlp_bytes::LogRecord::new();
+        eager.parse(&encoded);
+        assert_eq!(util::flatten_attributes(&eager), expected);
+
+        // Values nested past the depth guard are dropped instead of recursing forever
+        let mut deep = string("leaf");
+        for _ in 0..=util::MAX_FLATTEN_DEPTH {
+            deep = kvlist(vec![entry("k", Some(deep))]);
+        }
+        let record = LogRecord {
+            attributes: vec![entry("deep", Some(deep))],
+            ..Default::default()
+        };
+        assert!(util::flatten_attributes(&record).is_empty());
+    }
+
+    #[test]
+    fn test_lazy_log_records_min_severity() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let parser = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        let resource = parser.resource_logs().next().unwrap();
+        let scope = resource.scope_logs().next().unwrap();
+        assert_eq!(scope.scope_name(), "http-handler");
+
+        let records: Vec<_> = scope.log_records_min_severity(13).collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].severity_number(), 13);
+        assert_eq!(records[0].severity_text(), Some("ERROR"));
+
+        assert_eq!(scope.log_records_min_severity(0).count(), 2);
+        assert_eq!(scope.log_records_min_severity(14).count(), 0);
+    }
+
+    #[test]
+    fn test_log_record_name_precedence() {
+        let with_event = LogRecord {
+            event_name: "HTTP Request".to_string(),
+            severity_text: "INFO".to_string(),
+            ..Default::default()
+        };
+        let with_severity = LogRecord {
+            severity_text: "WARN".to_string(),
+            ..Default::default()
+        };
+        let neither = LogRecord::default();
+
+        for (record, expected) in [
+            (with_event, "HTTP Request"),
+            (with_severity, "WARN"),
+            (neither, "log_record"),
+        ] {
+            assert_eq!(record.name(), expected);
+
+            let encoded = record.encode_to_vec();
+            let mut eager = otlp_bytes::LogRecord::new();
+            eager.parse(&encoded);
+            assert_eq!(eager.name(), expected);
+
+            let lazy = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+            assert_eq!(lazy.name(), expected);
+        }
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let logs = create_test_logs();
+        let original = &logs.resource_logs[0].scope_logs[0].log_records[0];
+
+        let mut reordered = original.clone();
+        reordered.attributes.reverse();
+        reordered.time_unix_nano += 1;
+        assert_eq!(original.content_hash(), reordered.content_hash());
+
+        let mut other_body = original.clone();
+        other_body.body = Some(AnyValue {
+            value: Some(any_value::Value::StringValue("different body".to_string())),
+        });
+        assert_ne!(original.content_hash(), other_body.content_hash());
+
+        let mut other_severity = original.clone();
+        other_severity.severity_number = 13;
+        assert_ne!(original.content_hash(), other_severity.content_hash());
+
+        // The hash is backend independent
+        let encoded = reordered.encode_to_vec();
+        let mut eager = otlp_bytes::LogRecord::new();
+        eager.parse(&encoded);
+        assert_eq!(eager.content_hash(), original.content_hash());
+    }
+
+    #[test]
+    fn test_to_display_string_deep() {
+        use any_value::Value;
+
+        let value = |v: Value| AnyValue { value: Some(v) };
+        let nested = value(Value::ArrayValue(ArrayValue {
+            values: vec![
+                value(Value::IntValue(1)),
+                value(Value::StringValue("a".to_string())),
+                value(Value::BoolValue(true)),
+                value(Value::BytesValue(vec![1, 2, 3])),
+                value(Value::ArrayValue(ArrayValue {
+                    values: vec![value(Value::DoubleValue(2.5))],
+                })),
+                value(Value::KvlistValue(KeyValueList {
+                    values: vec![KeyValue { key: "k".to_string(), value: Some(value(Value::IntValue(7))) }],
+                })),
+            ],
+        }));
+        let record = LogRecord { body: Some(nested.clone()), ..Default::default() };
+        let encoded = record.encode_to_vec();
+        let mut eager = otlp_bytes::LogRecord::new();
+        eager.parse(&encoded);
+        let eager_body = eager.body.as_ref().unwrap();
+
+        let full = r#"[1, "a", true, bytes[3], [2.5], {k: 7}]"#;
+        assert_eq!(nested.to_display_string_deep(4, 10), full);
+        assert_eq!(eager_body.to_display_string_deep(4, 10), full);
+
+        assert_eq!(nested.to_display_string_deep(4, 3), r#"[1, "a", true, ...]"#);
+        assert_eq!(eager_body.to_display_string_deep(1, 10), r#"[1, "a", true, bytes[3], [...], {...}]"#);
+        assert_eq!(eager_body.to_display_string_deep(0, 10), "[...]");
+        assert_eq!(eager_body.to_display_string(), "array[6]");
+    }
+
+    #[test]
+    fn test_array_iter_across_backends() {
+        use std::borrow::Borrow;
+
+        fn collect_ints<V: AnyValueView>(value: &V) -> Vec<i64> {
+            value
+                .array_iter()
+                .unwrap()
+                .map(|element| element.borrow().as_int64().unwrap())
+                .collect()
+        }
+
+        let array = AnyValue {
+            value: Some(any_value::Value::ArrayValue(ArrayValue {
+                values: (1..=3)
+                    .map(|i| AnyValue { value: Some(any_value::Value::IntValue(i)) })
+                    .collect(),
+            })),
+        };
+        let record = LogRecord { body: Some(array.clone()), ..Default::default() };
+        let encoded = record.encode_to_vec();
+
+        let mut eager = otlp_bytes::LogRecord::new();
+        eager.parse(&encoded);
+        let lazy_record = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+        let lazy = otlp_bytes_lazy::AnyValueParser::new(lazy_record.body().unwrap());
+
+        assert_eq!(collect_ints(&array), vec![1, 2, 3]);
+        assert_eq!(collect_ints(eager.body.as_ref().unwrap()), vec![1, 2, 3]);
+        assert_eq!(collect_ints(&lazy), vec![1, 2, 3]);
+
+        // The lazy backend has no slice to lend, but generic code still sees the elements
+        assert!(AnyValueView::as_array(&lazy).is_none());
+        assert_eq!(util::display_value_deep(&lazy, 2, 10), "[1, 2, 3]");
+        assert!(AnyValueView::array_iter(&AnyValue {
+            value: Some(any_value::Value::IntValue(1)),
+        })
+        .is_none());
+    }
+
+    #[test]
+    fn test_kvlist_iter_across_backends() {
+        use std::borrow::Borrow;
+        use std::hash::Hasher;
+
+        fn hash<V: AnyValueView>(value: &V) -> u64 {
+            let mut hasher = util::Fnv1aHasher::default();
+            util::hash_any_value(value, &mut hasher);
+            hasher.finish()
+        }
+
+        let kvlist = AnyValue {
+            value: Some(any_value::Value::KvlistValue(KeyValueList {
+                values: vec![
+                    KeyValue { key: "b".into(), value: Some(AnyValue { value: Some(any_value::Value::IntValue(2)) }) },
+                    KeyValue { key: "a".into(), value: Some(AnyValue { value: Some(any_value::Value::StringValue("x".into())) }) },
+                ],
+            })),
+        };
+        let record = LogRecord { body: Some(kvlist.clone()), ..Default::default() };
+        let encoded = record.encode_to_vec();
+        let lazy_record = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+        let lazy = otlp_bytes_lazy::AnyValueParser::new(lazy_record.body().unwrap());
+
+        let keys: Vec<String> = AnyValueView::kvlist_iter(&lazy)
+            .unwrap()
+            .map(|entry| AttributeView::key(entry.borrow()).to_string())
+            .collect();
+        assert_eq!(keys, ["b", "a"]);
+        assert!(AnyValueView::as_kvlist(&lazy).is_none());
+
+        // Generic helpers see the lazy entries the same as the prost ones
+        assert_eq!(util::display_value_deep(&lazy, 2, 10), r#"{b: 2, a: "x"}"#);
+        assert_eq!(util::display_value_deep(&kvlist, 2, 10), r#"{b: 2, a: "x"}"#);
+        assert_eq!(util::display_value_deep(&lazy, 2, 1), "{b: 2, ...}");
+        assert_eq!(hash(&lazy), hash(&kvlist));
+        let mut flattened = Vec::new();
+        util::flatten_value("body".into(), &lazy, 0, &mut flattened);
+        assert_eq!(
+            flattened,
+            vec![
+                ("body.b".to_string(), util::ScalarValue::Int(2)),
+                ("body.a".to_string(), util::ScalarValue::String("x".into())),
+            ]
+        );
+    }
+
+    #[cfg(feature = "fuzz")]
+    #[test]
+    fn test_fuzz_parse_logs_regressions() {
+        for input in fuzz::generate_corpus() {
+            fuzz::fuzz_parse_logs(&input);
+        }
+
+        let encoded = encode_logs_data(&create_test_logs());
+        let regressions: Vec<&[u8]> = vec![
+            &[],
+            &[0x0A],
+            // Length prefix claiming far more bytes than follow
+            &[0x0A, 0xFF, 0x01, 0x12],
+            // Length prefix of 2^64 - 1, which overflows the end offset
+            &[0x0A, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            // Unterminated varint
+            &[0x08, 0xFF, 0xFF, 0xFF],
+            // Fixed64 field cut short
+            &[0x09, 0x01, 0x02],
+            // Invalid wire types
+            &[0x0B, 0x0C, 0x0F],
+            &encoded[..encoded.len() / 2],
+        ];
+        for input in regressions {
+            fuzz::fuzz_parse_logs(input);
+        }
+
+        // Every truncation and single-byte corruption of the sample
+        for len in 0..encoded.len() {
+            fuzz::fuzz_parse_logs(&encoded[..len]);
+        }
+        for idx in 0..encoded.len() {
+            let mut corrupted = encoded.clone();
+            corrupted[idx] ^= 0xFF;
+            fuzz::fuzz_parse_logs(&corrupted);
+        }
+
+        // A body nested 30000 array values deep. Lengths are computed
+        // inside out, then every header is written outside in.
+        let depth = 30_000;
+        let mut levels = Vec::new();
+        let mut value_len = 0;
+        for _ in 0..depth {
+            let array_len = 1 + prost::length_delimiter_len(value_len) + value_len;
+            levels.push((array_len, value_len));
+            value_len = 1 + prost::length_delimiter_len(array_len) + array_len;
+        }
+        let mut body = Vec::with_capacity(value_len);
+        for &(array_len, inner_len) in levels.iter().rev() {
+            // AnyValue.array_value (5), then ArrayValue.values (1)
+            body.push(0x2A);
+            prost::encoding::encode_varint(array_len as u64, &mut body);
+            body.push(0x0A);
+            prost::encoding::encode_varint(inner_len as u64, &mut body);
+        }
+        assert_eq!(body.len(), value_len);
+        // The lazy backend reads the whole depth; hashing must stop early
+        util::hash_any_value(&otlp_bytes_lazy::AnyValueParser::new(&body), &mut util::Fnv1aHasher::default());
+        let mut deep = body;
+        // LogRecord.body, ScopeLogs.log_records, ResourceLogs.scope_logs,
+        // LogsData.resource_logs
+        for tag in [0x2A, 0x12, 0x12, 0x0A] {
+            let mut outer = vec![tag];
+            prost::encoding::encode_varint(deep.len() as u64, &mut outer);
+            outer.extend(deep);
+            deep = outer;
+        }
+        fuzz::fuzz_parse_logs(&deep);
+    }
+
+    #[test]
+    fn test_lazy_raw_bytes_pass_through() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let parser = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        let resource = parser.resource_logs().next().unwrap();
+        assert_eq!(ResourceLogs::decode(resource.raw_bytes()).unwrap(), logs.resource_logs[0]);
+
+        let scope = resource.scope_logs().next().unwrap();
+        assert_eq!(ScopeLogs::decode(scope.raw_bytes()).unwrap(), logs.resource_logs[0].scope_logs[0]);
+
+        let record = scope.log_records().nth(1).unwrap();
+        let raw = record.raw_bytes();
+        assert_eq!(LogRecord::decode(raw).unwrap(), logs.resource_logs[0].scope_logs[0].log_records[1]);
+
+        let reparsed = otlp_bytes_lazy::LogRecordParser::new(raw);
+        assert_eq!(reparsed.severity_text(), Some("ERROR"));
+        assert_eq!(reparsed.attributes().count(), record.attributes().count());
+        // The slice borrows from the original buffer rather than copying
+        assert!(encoded.as_ptr_range().contains(&raw.as_ptr()));
+    }
+
+    #[test]
+    fn test_bytes_resource_attribute_accessors() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+
+        let resource = bytes_logs.resource_logs[0].resource.as_ref().unwrap();
+        assert_eq!(resource.attributes().len(), 3);
+        assert_eq!(
+            resource.get("deployment.environment").and_then(|v| v.string_value()),
+            Some("production")
+        );
+        assert_eq!(resource.get_service_name(), Some("web-server"));
+        assert!(resource.get("worker.id").is_none());
+
+        // Reparsing a smaller resource must not expose the stale slots
+        let second = LogsData { resource_logs: vec![logs.resource_logs[1].clone()] };
+        let encoded = encode_logs_data(&second);
+        bytes_logs.parse(&encoded);
+        let resource = bytes_logs.resource_logs[0].resource.as_ref().unwrap();
+        assert_eq!(resource.get("worker.id").and_then(|v| v.int_value()), Some(42));
+        assert!(resource.get("deployment.environment").is_none());
+    }
+
+    #[test]
+    fn test_bytes_parse_empty_logs_data() {
+        let empty = LogsData { resource_logs: vec![] };
+        let encoded = encode_logs_data(&empty);
+        assert!(encoded.is_empty());
+
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert!(bytes_logs.parse(&encoded));
+        assert_eq!(bytes_logs.resources().count(), 0);
+
+        // Unknown fields alone are still a valid, empty message
+        assert!(bytes_logs.parse(&[0x10, 0x01]));
+        assert_eq!(bytes_logs.resources().count(), 0);
+
+        // Malformed bytes are still rejected
+        assert!(!bytes_logs.parse(&[0x0A, 0x05, 0x01]));
+        assert!(!bytes_logs.parse(&[0x09, 0x01, 0x02]));
+        assert!(!bytes_logs.parse(&[0x0F]));
+
+        assert!(matches!(
+            http::decode_otlp_http("application/x-protobuf", &[]),
+            Ok(http::ParsedLogs::Protobuf(logs)) if logs.resources().count() == 0
+        ));
+    }
+
+    #[test]
+    fn test_lazy_scope_metadata_cache() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let parser = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        let expected: Vec<_> = logs
+            .resources()
+            .flat_map(|resource| resource.scopes().map(|scope| (scope.scope(), scope.version())))
+            .collect();
+        let mut expected = expected.into_iter();
+        for resource in parser.resource_logs() {
+            for scope in resource.scope_logs() {
+                let first = (scope.scope_name(), scope.scope_version());
+                assert_eq!(Some(first), expected.next());
+                // The second read comes from the cache
+                assert_eq!((scope.scope_name(), scope.scope_version()), first);
+            }
+        }
+        assert!(expected.next().is_none());
+
+        // Last occurrence wins like prost, an empty version reads as None, and other fields are skipped
+        let scope = InstrumentationScope {
+            name: "first".to_string(),
+            dropped_attributes_count: 3,
+            ..Default::default()
+        };
+        let mut scope_bytes = scope.encode_to_vec();
+        scope_bytes.extend_from_slice(&[0x0A, 0x06]);
+        scope_bytes.extend_from_slice(b"second");
+        scope_bytes.extend_from_slice(&[0x12, 0x00]);
+        let scope_logs = ScopeLogs {
+            scope: Some(InstrumentationScope::decode(&scope_bytes[..]).unwrap()),
+            ..Default::default()
+        };
+        let mut encoded = Vec::new();
+        prost::encoding::encode_key(1, prost::encoding::WireType::LengthDelimited, &mut encoded);
+        prost::encoding::encode_varint(scope_bytes.len() as u64, &mut encoded);
+        encoded.extend_from_slice(&scope_bytes);
+        let parser = otlp_bytes_lazy::ScopeLogsParser::new(&encoded);
+        assert_eq!(parser.scope_name(), "second");
+        assert_eq!(parser.scope_version(), None);
+        assert_eq!(scope_logs.scope(), parser.scope_name());
+    }
+
+    #[test]
+    fn test_lazy_projection_mask() {
+        use otlp_bytes_lazy::ProjectionMask;
+
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let parser = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let resource = parser.resource_logs().next().unwrap();
+        let scope = resource.scope_logs().next().unwrap();
+
+        let projection = ProjectionMask::TIME_UNIX_NANO | ProjectionMask::SEVERITY_NUMBER;
+        assert!(projection.contains(ProjectionMask::SEVERITY_NUMBER));
+        assert!(!projection.contains(ProjectionMask::ATTRIBUTES));
+
+        let records: Vec<_> = scope.log_records_with_projection(projection).collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].time_unix_nano(), 1718380800000000000);
+        assert_eq!(records[0].severity_number(), 9);
+        assert_eq!(records[1].severity_number(), 13);
+        assert_eq!(records[0].attributes().count(), 0);
+        assert!(records[0].body().is_none());
+        assert!(records[0].severity_text().is_none());
+
+        let full = scope.log_records().next().unwrap();
+        assert_eq!(full.attributes().count(), 4);
+        assert_eq!(full.severity_text(), Some("INFO"));
+    }
+
+    #[test]
+    fn test_bytes_to_prost_round_trip() {
+        let mut logs = create_test_logs();
+        let record = &mut logs.resource_logs[0].scope_logs[0].log_records[0];
+        record.trace_id = vec![0x5b; 16];
+        record.span_id = vec![0x8e; 8];
+        record.flags = 1;
+        record.attributes.push(KeyValue {
+            key: "nested".to_string(),
+            value: Some(AnyValue {
+                value: Some(any_value::Value::KvlistValue(KeyValueList {
+                    values: vec![KeyValue {
+                        key: "tags".to_string(),
+                        value: Some(AnyValue {
+                            value: Some(any_value::Value::ArrayValue(ArrayValue {
+                                values: vec![
+                                    AnyValue { value: Some(any_value::Value::BytesValue(vec![1, 2])) },
+                                    AnyValue { value: Some(any_value::Value::DoubleValue(0.25)) },
+                                ],
+                            })),
+                        }),
+                    }],
+                })),
+            }),
+        });
+        logs.resource_logs[1].resource.as_mut().unwrap().entity_refs.push(EntityRef {
+            r#type: "service".to_string(),
+            id_keys: vec!["service.name".to_string()],
+            ..Default::default()
+        });
+
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        assert!(bytes_logs.parse(&encoded));
+        assert_eq!(bytes_logs.to_prost(), logs);
+    }
+
+    #[test]
+    fn test_lazy_attribute_ranges() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let parser = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        for resource in parser.resource_logs() {
+            for scope in resource.scope_logs() {
+                for record in scope.log_records() {
+                    let base = record.base_offset(&encoded).unwrap();
+                    let ranges = record.attribute_ranges();
+                    assert_eq!(ranges.len(), record.attributes().count());
+
+                    for (&(wire_type, pos), attr) in ranges.iter().zip(record.attributes()) {
+                        assert_eq!(wire_type, 2);
+                        // Seek straight to the attribute in the original buffer
+                        let mut buf = &encoded[base + pos..];
+                        let len = prost::encoding::decode_varint(&mut buf).unwrap() as usize;
+                        let seeked = otlp_bytes_lazy::KeyValueParser::new(&buf[..len]);
+                        assert_eq!(seeked.key(), attr.key());
+                        assert_eq!(
+                            seeked.value().map(|v| v.to_display_string()),
+                            attr.value().map(|v| v.to_display_string())
+                        );
+                    }
+                }
+            }
+        }
+
+        let other = encode_logs_data(&logs);
+        let record = otlp_bytes_lazy::LogRecordParser::new(&encoded[10..]);
+        assert!(record.base_offset(&other).is_none());
+        assert_eq!(record.base_offset(&encoded), Some(10));
+    }
+
+    #[test]
+    fn test_lossy_utf8_accessors() {
+        fn length_delimited(tag: u32, payload: &[u8], out: &mut Vec<u8>) {
+            prost::encoding::encode_key(tag, prost::encoding::WireType::LengthDelimited, out);
+            prost::encoding::encode_varint(payload.len() as u64, out);
+            out.extend_from_slice(payload);
+        }
+
+        // KeyValue { key: b"a\xFFb", value: AnyValue { string_value: b"x\xFF" } }
+        let mut any_value = Vec::new();
+        length_delimited(1, b"x\xFF", &mut any_value);
+        let mut key_value = Vec::new();
+        length_delimited(1, b"a\xFFb", &mut key_value);
+        length_delimited(2, &any_value, &mut key_value);
+        let mut record = LogRecord {
+            severity_number: 9,
+            ..Default::default()
+        }
+        .encode_to_vec();
+        length_delimited(6, &key_value, &mut record);
+
+        let mut eager = otlp_bytes::LogRecord::new();
+        assert!(eager.parse(&record));
+        let attrs: Vec<_> = eager.attributes().collect();
+        assert_eq!(attrs.len(), 1);
+        assert_eq!(attrs[0].key, "");
+        assert_eq!(attrs[0].key_cow(), "a\u{FFFD}b");
+        let value = attrs[0].value.as_ref().unwrap();
+        assert_eq!(value.string_value(), Some(""));
+        assert_eq!(value.string_value_cow().as_deref(), Some("x\u{FFFD}"));
+
+        let lazy = otlp_bytes_lazy::LogRecordParser::new(&record);
+        let attr = lazy.attributes().next().unwrap();
+        assert_eq!(attr.key(), None);
+        assert_eq!(attr.key_cow().as_deref(), Some("a\u{FFFD}b"));
+        assert_eq!(attr.value().unwrap().string_value_cow().as_deref(), Some("x\u{FFFD}"));
+
+        // Valid UTF-8 is borrowed, not copied
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+        let first = &bytes_logs.resource_logs[0].scope_logs[0].log_records[0].attributes[0];
+        assert!(matches!(first.key_cow(), std::borrow::Cow::Borrowed("method")));
+        assert!(matches!(
+            first.value.as_ref().unwrap().string_value_cow(),
+            Some(std::borrow::Cow::Borrowed("GET"))
+        ));
+    }
+
+    #[test]
+    fn test_empty_key_attribute_is_retained() {
+        fn keys<'a, R: LogRecordView<'a>>(record: &'a R) -> Vec<(String, Option<String>)> {
+            record
+                .attributes()
+                .map(|attr| {
+                    let value = attr.value().and_then(|v| v.as_string()).map(str::to_string);
+                    (attr.key().to_string(), value)
+                })
+                .collect()
+        }
+
+        let record = LogRecord {
+            attributes: vec![
+                KeyValue {
+                    key: String::new(),
+                    value: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue("anonymous".to_string())),
+                    }),
+                },
+                KeyValue {
+                    key: "user".to_string(),
+                    value: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue("alice".to_string())),
+                    }),
+                },
+            ],
+            ..Default::default()
+        };
+        let encoded = record.encode_to_vec();
+        let expected = vec![
+            (String::new(), Some("anonymous".to_string())),
+            ("user".to_string(), Some("alice".to_string())),
+        ];
+
+        let mut eager = otlp_bytes::LogRecord::new();
+        assert!(eager.parse(&encoded));
+        assert_eq!(keys(&eager), expected);
+        assert!(eager.attributes[0].key_bytes.is_empty());
+
+        let lazy = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+        let lazy_keys: Vec<_> = lazy
+            .attributes()
+            .map(|attr| {
+                let value = attr.value().and_then(|v| v.string_value()).map(str::to_string);
+                (AttributeView::key(&attr).to_string(), value)
+            })
+            .collect();
+        assert_eq!(lazy_keys, expected);
+        assert_eq!(keys(&record), expected);
+    }
+
+    #[test]
+    fn test_encoded_len_accounting() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+
+        // Each ResourceLogs is framed by a one-byte tag and a varint length
+        let framing = |len: usize| 1 + prost::encoding::encoded_len_varint(len as u64);
+
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        assert_eq!(lazy.total_encoded_len(), encoded.len());
+        let framed: usize = lazy
+            .resource_logs()
+            .map(|resource| resource.encoded_len() + framing(resource.encoded_len()))
+            .sum();
+        assert_eq!(framed, encoded.len());
+        for resource in lazy.resource_logs() {
+            let scopes: usize = resource.scope_logs().map(|scope| scope.encoded_len()).sum();
+            assert!(scopes < resource.encoded_len());
+        }
+
+        let mut eager = otlp_bytes::LogsData::new();
+        assert!(eager.parse(&encoded));
+        assert_eq!(eager.encoded_len(), encoded.len());
+        let framed: usize = eager
+            .into_iter()
+            .map(|resource| resource.encoded_len() + framing(resource.encoded_len()))
+            .sum();
+        assert_eq!(framed, encoded.len());
+        let first_record = &eager.resource_logs[0].scope_logs[0].log_records[0];
+        assert_eq!(first_record.encoded_len(), logs.resource_logs[0].scope_logs[0].log_records[0].encoded_len());
+    }
+
+    #[test]
+    fn test_debug_fields() {
+        use otlp_bytes_lazy::ProtobufParser;
+
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+
+        let fields = ProtobufParser::debug_fields(&encoded);
+        assert_eq!(fields.len(), logs.resource_logs.len());
+        for ((tag, wire_type, len), resource) in fields.iter().zip(&logs.resource_logs) {
+            assert_eq!((*tag, *wire_type), (1, 2));
+            assert_eq!(*len, resource.encoded_len());
+        }
+
+        // A LogRecord mixes fixed64, varint and length-delimited fields
+        let record = LogRecord {
+            time_unix_nano: 1,
+            severity_number: 9,
+            severity_text: "INFO".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            ProtobufParser::debug_fields(&record.encode_to_vec()),
+            vec![(1, 1, 8), (2, 0, 1), (3, 2, 4)]
+        );
+
+        // Truncated payloads report the fields before the damage
+        assert_eq!(ProtobufParser::debug_fields(&encoded[..encoded.len() - 1]).len(), fields.len() - 1);
+        assert!(ProtobufParser::debug_fields(&[]).is_empty());
+
+        // The streaming walk stops at the same place
+        let truncated = ProtobufParser::new(&encoded[..encoded.len() - 1]);
+        assert_eq!(truncated.walk_fields().collect::<Vec<_>>(), truncated.top_level_fields());
+    }
+
+    #[test]
+    fn test_strict_mode_reports_wire_type_mismatch() {
+        use otlp_bytes_lazy::{LogRecordParser, ParseMode, WireTypeMismatch};
+
+        // time_unix_nano (tag 1) sent as a varint instead of fixed64
+        let mut record = Vec::new();
+        prost::encoding::uint64::encode(1, &1_700_000_000, &mut record);
+        prost::encoding::int32::encode(2, &9, &mut record);
+
+        let lenient = LogRecordParser::new(&record);
+        assert_eq!(lenient.time_unix_nano(), 0);
+        assert_eq!(lenient.severity_number(), 9);
+        assert!(lenient.errors().is_empty());
+
+        let strict = LogRecordParser::new(&record).with_mode(ParseMode::Strict);
+        assert_eq!(strict.time_unix_nano(), 0);
+        assert_eq!(strict.severity_number(), 9);
+        assert_eq!(
+            strict.errors(),
+            &[WireTypeMismatch { tag: 1, expected: 1, actual: 0, offset: 0 }]
+        );
+        assert_eq!(strict.errors()[0].to_string(), "field 1 at offset 0 has wire type 0, expected 1");
+
+        // Well-formed records are clean in strict mode too
+        let logs = create_test_logs();
+        let encoded = logs.resource_logs[0].scope_logs[0].log_records[0].encode_to_vec();
+        assert!(LogRecordParser::new(&encoded).with_mode(ParseMode::Strict).errors().is_empty());
+    }
+
+    #[test]
+    fn test_resource_logs_indexed_offsets() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let parser = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        let indexed: Vec<_> = parser.resource_logs_indexed().collect();
+        assert_eq!(indexed.len(), 2);
+
+        // Each offset points just past the resource's tag and length prefix
+        let first_len = indexed[0].1.encoded_len();
+        let prefix = |len: usize| 1 + prost::encoding::encoded_len_varint(len as u64);
+        assert_eq!(indexed[0].0, prefix(first_len));
+        assert_eq!(indexed[1].0, indexed[0].0 + first_len + prefix(indexed[1].1.encoded_len()));
+        assert!(indexed[1].0 < encoded.len());
+
+        for (offset, resource) in &indexed {
+            assert_eq!(&encoded[*offset..*offset + resource.encoded_len()], resource.raw_bytes());
+        }
+    }
+
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn test_wasm_logs_summary() {
+        use wasm::{summarize_logs, SeverityCount};
+
+        let encoded = encode_logs_data(&create_test_logs());
+        let summary = summarize_logs(&encoded);
+        assert_eq!(summary.resource_count, 2);
+        assert_eq!(summary.scope_count, 3);
+        assert_eq!(summary.record_count, 4);
+        assert_eq!(
+            summary.top_severities[0],
+            SeverityCount { severity_number: 9, severity_text: "INFO".to_string(), count: 2 }
+        );
+        // Ties are ordered by severity number
+        let rest: Vec<_> = summary.top_severities[1..].iter().map(|s| s.severity_text.as_str()).collect();
+        assert_eq!(rest, ["DEBUG", "ERROR"]);
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["recordCount"], 4);
+        assert_eq!(json["topSeverities"][0]["severityText"], "INFO");
+
+        assert_eq!(summarize_logs(&[]), wasm::LogsSummary::default());
+    }
+
+    #[test]
+    fn test_attributes_grouped_by_prefix() {
+        fn string_attr(key: &str, value: &str) -> KeyValue {
+            KeyValue {
+                key: key.to_string(),
+                value: Some(AnyValue {
+                    value: Some(any_value::Value::StringValue(value.to_string())),
+                }),
+            }
+        }
+
+        fn grouped_keys<'a, R: LogRecordView<'a>>(record: &'a R) -> Vec<(String, Vec<String>)> {
+            record
+                .attributes_grouped_by_prefix('.')
+                .into_iter()
+                .map(|(prefix, attrs)| {
+                    (prefix.to_string(), attrs.iter().map(|a| a.key().to_string()).collect())
+                })
+                .collect()
+        }
+
+        let record = LogRecord {
+            attributes: vec![
+                string_attr("db.name", "users"),
+                string_attr("http.method", "GET"),
+                string_attr("db.user", "admin"),
+                string_attr("retry", "3"),
+                string_attr("db.statement.kind", "select"),
+            ],
+            ..Default::default()
+        };
+        let expected = vec![
+            (String::new(), vec!["retry".to_string()]),
+            ("db".to_string(), vec!["db.name".to_string(), "db.user".to_string(), "db.statement.kind".to_string()]),
+            ("http".to_string(), vec!["http.method".to_string()]),
+        ];
+        assert_eq!(grouped_keys(&record), expected);
+
+        let encoded = record.encode_to_vec();
+        let mut eager = otlp_bytes::LogRecord::new();
+        assert!(eager.parse(&encoded));
+        assert_eq!(grouped_keys(&eager), expected);
+
+        let groups = record.attributes_grouped_by_prefix('.');
+        assert_eq!(groups["db"][1].value.as_ref().unwrap().as_string(), Some("admin"));
+        assert!(LogRecord::default().attributes_grouped_by_prefix('.').is_empty());
+    }
+
+    #[test]
+    fn test_create_varied_large_logs() {
+        fn collect_types(value: &AnyValue, seen: &mut std::collections::HashSet<String>) {
+            seen.insert(format!("{:?}", value.value_type()));
+            if let Some(values) = value.as_array() {
+                values.iter().for_each(|v| collect_types(v, seen));
+            }
+            for kv in value.as_kvlist().unwrap_or_default() {
+                kv.value.iter().for_each(|v| collect_types(v, seen));
+            }
+        }
+
+        let logs = create_varied_large_logs(50);
+        assert_eq!(logs.resource_logs.len(), 50);
+        assert_eq!(logs, create_varied_large_logs(50));
+
+        let services: std::collections::HashSet<_> = logs
+            .resource_logs
+            .iter()
+            .map(|r| r.resource.as_ref().unwrap().attributes[0].value.clone())
+            .map(|v| v.unwrap().as_string().unwrap().to_string())
+            .collect();
+        assert_eq!(services.len(), 50);
+
+        let mut seen = std::collections::HashSet::new();
+        let mut negative_int = false;
+        let mut record_counts = std::collections::HashSet::new();
+        for scope in logs.resource_logs.iter().flat_map(|r| &r.scope_logs) {
+            record_counts.insert(scope.log_records.len());
+            for attr in scope.log_records.iter().flat_map(|r| &r.attributes) {
+                let value = attr.value.as_ref().unwrap();
+                collect_types(value, &mut seen);
+                negative_int |= value.as_int64().is_some_and(|v| v < 0);
+            }
+        }
+        assert_eq!(seen.len(), 7, "missing value types: {:?}", seen);
+        assert!(negative_int);
+        assert!(record_counts.len() > 1);
+
+        // The eager and lazy parsers agree with prost on the varied shapes
+        let encoded = encode_logs_data(&logs);
+        let mut eager = otlp_bytes::LogsData::new();
+        assert!(eager.parse(&encoded));
+        assert!(diff::logs_equal(&logs, &eager));
+    }
+
+    #[test]
+    fn test_parse_error_offset() {
+        let logs = create_test_logs();
+        let first = LogsData { resource_logs: vec![logs.resource_logs[0].clone()] };
+        let valid = encode_logs_data(&first);
+        let corruption = valid.len();
+
+        // A second resource_logs field whose length runs past the end
+        let mut truncated = valid.clone();
+        truncated.extend_from_slice(&[0x0A, 0xFF, 0x01, 0x00]);
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(!parsed.parse(&truncated));
+        assert_eq!(parsed.parse_error_offset(), Some(corruption));
+        assert_eq!(parsed.used_count, 1);
+
+        // An unknown wire type is reported at its tag
+        let mut bad_wire_type = valid.clone();
+        bad_wire_type.push(0x0F);
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(!parsed.parse(&bad_wire_type));
+        assert_eq!(parsed.parse_error_offset(), Some(corruption));
+
+        // A later clean parse resets the breadcrumb
+        assert!(parsed.parse(&valid));
+        assert_eq!(parsed.parse_error_offset(), None);
+    }
+
+    #[test]
+    fn test_log_record_flags() {
+        let record = LogRecord {
+            // sampled, remote bit recorded and set
+            flags: 0x01 | LogRecordFlags::CONTEXT_HAS_IS_REMOTE_MASK | LogRecordFlags::CONTEXT_IS_REMOTE_MASK,
+            ..Default::default()
+        };
+        let encoded = record.encode_to_vec();
+
+        let mut eager = otlp_bytes::LogRecord::new();
+        assert!(eager.parse(&encoded));
+        let lazy = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+
+        for flags in [eager.log_record_flags().unwrap(), lazy.log_record_flags().unwrap()] {
+            assert_eq!(flags.bits(), 0x301);
+            assert_eq!(flags.trace_flags(), 0x01);
+            assert!(flags.is_sampled());
+            assert!(flags.has_is_remote());
+            assert_eq!(flags.is_remote(), Some(true));
+            assert_eq!(
+                format!("{:?}", flags),
+                "LogRecordFlags { trace_flags: 0x01, sampled: true, is_remote: Some(true) }"
+            );
+        }
+
+        // Remote bit without the "has" bit is not meaningful
+        assert_eq!(LogRecordFlags(LogRecordFlags::CONTEXT_IS_REMOTE_MASK).is_remote(), None);
+        assert_eq!(LogRecordFlags(0x100).is_remote(), Some(false));
+        assert!(format!("{:?}", LogRecordFlags(0x1000)).contains("unknown: 0x1000"));
+
+        let unflagged = LogRecord::default().encode_to_vec();
+        assert_eq!(otlp_bytes_lazy::LogRecordParser::new(&unflagged).log_record_flags(), None);
+    }
+
+    #[test]
+    fn test_logs_arena_reuse() {
+        let large_logs = create_varied_large_logs(20);
+        let sample_logs = create_test_logs();
+        let single_logs = LogsData {
+            resource_logs: vec![sample_logs.resource_logs[1].clone()],
+        };
+        let large = encode_logs_data(&large_logs);
+        let sample = encode_logs_data(&sample_logs);
+        let single = encode_logs_data(&single_logs);
+
+        let mut arena = otlp_bytes::LogsArena::new();
+        let (slots, resource_attributes, scope_attributes) = {
+            let view = arena.parse_into(&large).unwrap();
+            assert_eq!(view.used_count, 20);
+            assert!(diff::logs_equal(&large_logs, &*view));
+            let resource_logs = &view.resource_logs[0];
+            (
+                view.resource_logs.as_ptr(),
+                resource_logs.resource.as_ref().unwrap().attributes.as_ptr(),
+                resource_logs.scope_logs[0].scope.as_ref().unwrap().attributes.as_ptr(),
+            )
+        };
+        assert_eq!(arena.resource_capacity(), 20);
+
+        for (payload, expected) in [(&sample, &sample_logs), (&single, &single_logs)] {
+            let view = arena.parse_into(payload).unwrap();
+            assert_eq!(view.parse_error_offset(), None);
+            assert_eq!(view.used_count, expected.resource_logs.len());
+            assert!(diff::logs_equal(expected, &*view));
+            // Smaller payloads land in the slots allocated for the first one,
+            // down to the resource and scope attributes
+            assert_eq!(view.resource_logs.as_ptr(), slots);
+            let resource_logs = &view.resource_logs[0];
+            assert_eq!(resource_logs.resource.as_ref().unwrap().attributes.as_ptr(), resource_attributes);
+            assert_eq!(resource_logs.scope_logs[0].scope.as_ref().unwrap().attributes.as_ptr(), scope_attributes);
+        }
+        assert_eq!(arena.resource_capacity(), 20);
+
+        // Malformed input is reported and its slots still go back
+        assert!(arena.parse_into(&large[..large.len() - 1]).is_none());
+        assert_eq!(arena.resource_capacity(), 20);
+        assert_eq!(arena.parse_into(&single).unwrap().resource_logs.as_ptr(), slots);
+    }
+
+    #[test]
+    fn test_used_counts_after_reuse() {
+        let large = encode_logs_data(&create_test_logs());
+        let mut small_logs = create_test_logs();
+        small_logs.resource_logs.truncate(1);
+        small_logs.resource_logs[0].scope_logs.truncate(1);
+        let scope = &mut small_logs.resource_logs[0].scope_logs[0];
+        scope.log_records.truncate(1);
+        scope.log_records[0].attributes.truncate(1);
+        scope.scope.as_mut().unwrap().attributes.clear();
+        small_logs.resource_logs[0].resource.as_mut().unwrap().attributes.truncate(1);
+        let small = encode_logs_data(&small_logs);
+
+        let mut logs = otlp_bytes::LogsData::new();
+        assert!(logs.parse(&large));
+        let high_water = logs.resource_logs[0].scope_logs[0].log_records[0].attributes.len();
+        assert!(high_water > 1);
+
+        assert!(logs.parse(&small));
+        assert_eq!(logs.resource_logs_count(), 1);
+        assert_eq!(logs.resource_logs.len(), 2);
+
+        let resource_logs = &logs.resource_logs[0];
+        assert_eq!(resource_logs.scope_logs_count(), 1);
+        assert_eq!(resource_logs.resource.as_ref().unwrap().attribute_count(), 1);
+        assert_eq!(resource_logs.resource.as_ref().unwrap().entity_ref_count(), 0);
+
+        let scope_logs = &resource_logs.scope_logs[0];
+        assert_eq!(scope_logs.log_records_count(), 1);
+        assert_eq!(scope_logs.scope.as_ref().unwrap().attribute_count(), 0);
+
+        let record = &scope_logs.log_records[0];
+        assert_eq!(record.attribute_count(), 1);
+        assert_eq!(record.attributes.len(), high_water);
+        assert_eq!(record.attributes().count(), record.attribute_count());
+    }
+
+    #[test]
+    fn test_write_logs_to_string() {
+        let logs = create_test_logs();
+        let mut out = String::new();
+        print::write_logs(&mut out, &logs).unwrap();
+        assert!(out.starts_with("🔍 Inspecting Logs Data\n"));
+        assert!(out.contains("📦 Resource 1: web-server"));
+        assert!(out.contains("📝 Log Record 1: HTTP Request"));
+        assert!(out.contains("🏷️  method: \"GET\""));
+
+        // Every backend renders the same tree
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+        let mut bytes_out = String::new();
+        print::write_logs(&mut bytes_out, &bytes_logs).unwrap();
+        assert_eq!(bytes_out, out);
+    }
+
+    #[test]
+    fn test_write_logs_options() {
+        use print::{write_logs_with, PrintOptions};
+
+        let logs = create_test_logs();
+        let mut default_out = String::new();
+        print::write_logs(&mut default_out, &logs).unwrap();
+
+        let mut plain = String::new();
+        write_logs_with(&mut plain, &logs, &PrintOptions::default()).unwrap();
+        assert_eq!(plain, default_out);
+        assert!(!plain.contains('\x1b'));
+
+        let options = PrintOptions {
+            indent: "\t".to_string(),
+            max_attr_value_len: Some(4),
+            ..Default::default()
+        };
+        let mut out = String::new();
+        write_logs_with(&mut out, &logs, &options).unwrap();
+        assert!(!out.contains('\x1b'));
+        assert!(out.contains("\t🔧 Scope 1: http-handler"));
+        assert!(out.contains("\t\t📝 Log Record 1: HTTP Request"));
+        // Long values are cut to four characters; "POST" fits exactly
+        assert!(out.contains("🏷️  error_message: \"Data…\""));
+        assert!(out.contains("🏷️  method: \"POST\""));
+
+        let mut colored = String::new();
+        write_logs_with(&mut colored, &logs, &PrintOptions { color: true, ..Default::default() }).unwrap();
+        // Colors follow severity_number (13 is in the WARN range)
+        assert!(colored.contains("\x1b[33m📝 Log Record 2: HTTP Error\x1b[0m"));
+        assert!(colored.contains("\x1b[32m📝 Log Record 1: HTTP Request\x1b[0m"));
+    }
+
+    #[test]
+    fn test_instrumentation_library() {
+        let logs = create_test_logs();
+        let first_scope = &logs.resource_logs[0].scope_logs[0];
+        assert_eq!(first_scope.instrumentation_library(), "http-handler@1.0.0");
+
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+        assert_eq!(bytes_logs.resource_logs[0].scope_logs[0].instrumentation_library(), "http-handler@1.0.0");
+
+        // prost reports an unset version as "", the byte parser as None
+        let mut versionless = first_scope.clone();
+        versionless.scope.as_mut().unwrap().version.clear();
+        assert_eq!(versionless.instrumentation_library(), "http-handler");
+
+        let encoded = versionless.encode_to_vec();
+        let mut bytes_scope = otlp_bytes::ScopeLogs::new();
+        assert!(bytes_scope.parse(&encoded));
+        assert_eq!(bytes_scope.version(), None);
+        assert_eq!(bytes_scope.instrumentation_library(), "http-handler");
+    }
+
+    #[test]
+    fn test_get_all_repeated_keys() {
+        fn env(value: &str) -> KeyValue {
+            KeyValue {
+                key: "env".to_string(),
+                value: Some(AnyValue {
+                    value: Some(any_value::Value::StringValue(value.to_string())),
+                }),
+            }
+        }
+        let attributes = vec![env("staging"), KeyValue { key: "region".to_string(), value: None }, env("canary")];
+
+        let resource = Resource { attributes: attributes.clone(), ..Default::default() }.encode_to_vec();
+        let scope = InstrumentationScope { attributes: attributes.clone(), ..Default::default() }.encode_to_vec();
+        let record = LogRecord { attributes, ..Default::default() }.encode_to_vec();
+
+        let mut bytes_resource = otlp_bytes::Resource::new();
+        assert!(bytes_resource.parse(&resource));
+        let mut bytes_scope = otlp_bytes::InstrumentationScope::new();
+        assert!(bytes_scope.parse(&scope));
+        let mut bytes_record = otlp_bytes::LogRecord::new();
+        assert!(bytes_record.parse(&record));
+
+        let collect = |values: &mut dyn Iterator<Item = &otlp_bytes::AnyValue>| -> Vec<String> {
+            values.filter_map(|v| v.string_value()).map(str::to_string).collect()
+        };
+        for values in [
+            collect(&mut bytes_resource.get_all("env")),
+            collect(&mut bytes_scope.get_all("env")),
+            collect(&mut bytes_record.get_all("env")),
+        ] {
+            assert_eq!(values, ["staging", "canary"]);
+        }
+        assert_eq!(bytes_resource.get("env").and_then(|v| v.string_value()), Some("staging"));
+        assert_eq!(bytes_record.get_all("region").count(), 0);
+        assert_eq!(bytes_record.get_all("missing").count(), 0);
+    }
+
+    #[test]
+    fn test_sampling_key() {
+        let logs = create_test_logs();
+        let template = &logs.resource_logs[0].scope_logs[0].log_records[0];
+        let trace_id: Vec<u8> = (1..=16).collect();
+
+        let first = LogRecord { trace_id: trace_id.clone(), ..template.clone() };
+        let second = LogRecord {
+            trace_id: trace_id.clone(),
+            severity_number: 17,
+            body: None,
+            ..template.clone()
+        };
+        assert_eq!(first.sampling_key(), second.sampling_key());
+
+        let other_trace = LogRecord { trace_id: (17..=32).collect(), ..template.clone() };
+        assert_ne!(first.sampling_key(), other_trace.sampling_key());
+
+        // Without a (valid) trace id the key follows severity and body
+        let traceless = template.clone();
+        let zero_trace = LogRecord { trace_id: vec![0; 16], ..template.clone() };
+        let same_content = LogRecord { attributes: vec![], ..template.clone() };
+        let other_body = LogRecord {
+            body: Some(AnyValue { value: Some(any_value::Value::StringValue("other".to_string())) }),
+            ..template.clone()
+        };
+        assert_eq!(traceless.sampling_key(), zero_trace.sampling_key());
+        assert_eq!(traceless.sampling_key(), same_content.sampling_key());
+        assert_ne!(traceless.sampling_key(), other_body.sampling_key());
+        assert_ne!(traceless.sampling_key(), first.sampling_key());
+
+        // Backends agree
+        for record in [&first, &traceless] {
+            let encoded = record.encode_to_vec();
+            let mut bytes_record = otlp_bytes::LogRecord::new();
+            assert!(bytes_record.parse(&encoded));
+            assert_eq!(bytes_record.sampling_key(), record.sampling_key());
+        }
+    }
+
+    #[test]
+    fn test_lazy_body_value_kvlist() {
+        let record = LogRecord {
+            body: Some(AnyValue {
+                value: Some(any_value::Value::KvlistValue(KeyValueList {
+                    values: vec![
+                        KeyValue {
+                            key: "user".to_string(),
+                            value: Some(AnyValue { value: Some(any_value::Value::StringValue("alice".to_string())) }),
+                        },
+                        KeyValue {
+                            key: "attempts".to_string(),
+                            value: Some(AnyValue { value: Some(any_value::Value::IntValue(3)) }),
+                        },
+                    ],
+                })),
+            }),
+            ..Default::default()
+        };
+        let encoded = record.encode_to_vec();
+        let lazy = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+
+        let body = lazy.body_value().unwrap();
+        assert!(matches!(body.value_type(), otlp_bytes_lazy::AnyValueType::KvList));
+        let entries: Vec<_> = body
+            .kvlist_value()
+            .unwrap()
+            .map(|kv| (kv.key().unwrap(), kv.value().unwrap()))
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].0, "user");
+        assert_eq!(entries[0].1.string_value(), Some("alice"));
+        assert_eq!(entries[1].0, "attempts");
+        assert_eq!(entries[1].1.int_value(), Some(3));
+
+        let empty = LogRecord::default().encode_to_vec();
+        assert!(otlp_bytes_lazy::LogRecordParser::new(&empty).body_value().is_none());
+    }
+
+    #[test]
+    fn test_repeated_singular_fields_last_wins() {
+        let mut record = Vec::new();
+        prost::encoding::int32::encode(2, &9, &mut record);
+        prost::encoding::string::encode(3, &"INFO".to_string(), &mut record);
+        prost::encoding::fixed64::encode(1, &100, &mut record);
+        prost::encoding::int32::encode(2, &17, &mut record);
+        prost::encoding::string::encode(3, &"ERROR".to_string(), &mut record);
+        prost::encoding::fixed64::encode(1, &200, &mut record);
+
+        let decoded = LogRecord::decode(&record[..]).unwrap();
+        assert_eq!(decoded.severity_number, 17);
+
+        let mut eager = otlp_bytes::LogRecord::new();
+        assert!(eager.parse(&record));
+        assert_eq!(eager.severity_number, decoded.severity_number);
+        assert_eq!(eager.severity_text, Some(decoded.severity_text.as_str()));
+        assert_eq!(eager.time_unix_nano, Some(decoded.time_unix_nano));
+
+        let lazy = otlp_bytes_lazy::LogRecordParser::new(&record);
+        assert_eq!(lazy.severity_number(), decoded.severity_number);
+        assert_eq!(lazy.time_unix_nano(), decoded.time_unix_nano);
+
+        let mut scope = Vec::new();
+        prost::encoding::string::encode(3, &"https://first".to_string(), &mut scope);
+        prost::encoding::string::encode(3, &"https://second".to_string(), &mut scope);
+        let mut bytes_scope = otlp_bytes::ScopeLogs::new();
+        assert!(bytes_scope.parse(&scope));
+        assert_eq!(bytes_scope.schema_url, Some(ScopeLogs::decode(&scope[..]).unwrap().schema_url.as_str()));
+        assert_eq!(otlp_bytes_lazy::ScopeLogsParser::new(&scope).schema_url(), Some("https://second"));
+
+        // ResourceLogs carries schema_url under the same tag
+        let mut resource = Vec::new();
+        prost::encoding::string::encode(3, &"https://first".to_string(), &mut resource);
+        prost::encoding::string::encode(3, &"https://second".to_string(), &mut resource);
+        let decoded = ResourceLogs::decode(&resource[..]).unwrap();
+        assert_eq!(decoded.schema_url, "https://second");
+        let mut bytes_resource = otlp_bytes::ResourceLogs::new();
+        assert!(bytes_resource.parse(&resource));
+        assert_eq!(bytes_resource.schema_url, Some(decoded.schema_url.as_str()));
+        assert_eq!(otlp_bytes_lazy::ResourceLogsParser::new(&resource).schema_url(), Some("https://second"));
+    }
+
+
+    #[test]
+    fn test_duplicated_scope_and_resource_fields_last_wins() {
+        fn fields<'a, L: LogsView<'a>>(logs: &'a L) -> (String, Option<String>, u32, u32, Option<String>) {
+            let resource = logs.resources().next().unwrap();
+            let scope = resource.scopes().next().unwrap();
+            (
+                scope.scope().to_string(),
+                scope.version().map(str::to_string),
+                scope.dropped_attributes_count(),
+                resource.dropped_attributes_count(),
+                resource.schema_url().map(str::to_string),
+            )
+        }
+
+        // Two encodings back to back inside one field: every singular field repeats
+        let mut scope = InstrumentationScope {
+            name: "old".to_string(),
+            version: "1".to_string(),
+            dropped_attributes_count: 1,
+            ..Default::default()
+        }
+        .encode_to_vec();
+        scope.extend(
+            InstrumentationScope {
+                name: "new".to_string(),
+                version: "2".to_string(),
+                dropped_attributes_count: 3,
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        );
+        let mut resource = Resource {
+            attributes: vec![KeyValue {
+                key: "service.name".to_string(),
+                value: Some(AnyValue { value: Some(any_value::Value::StringValue("checkout".to_string())) }),
+            }],
+            dropped_attributes_count: 1,
+            ..Default::default()
+        }
+        .encode_to_vec();
+        resource.extend(Resource { dropped_attributes_count: 2, ..Default::default() }.encode_to_vec());
+
+        let mut scope_logs = Vec::new();
+        prost::encoding::bytes::encode(1, &scope, &mut scope_logs);
+        prost::encoding::message::encode(2, &LogRecord::default(), &mut scope_logs);
+        let mut resource_logs = Vec::new();
+        prost::encoding::bytes::encode(1, &resource, &mut resource_logs);
+        prost::encoding::bytes::encode(2, &scope_logs, &mut resource_logs);
+        prost::encoding::string::encode(3, &"https://first".to_string(), &mut resource_logs);
+        prost::encoding::string::encode(3, &"https://second".to_string(), &mut resource_logs);
+        let mut encoded = Vec::new();
+        prost::encoding::bytes::encode(1, &resource_logs, &mut encoded);
+
+        let decoded = LogsData::decode(&encoded[..]).unwrap();
+        let expected = ("new".to_string(), Some("2".to_string()), 3, 2, Some("https://second".to_string()));
+        assert_eq!(fields(&decoded), expected);
+
+        let mut eager = otlp_bytes::LogsData::new();
+        assert!(eager.parse(&encoded));
+        assert_eq!(fields(&eager), expected);
+
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let lazy_resource = lazy.resource_logs().next().unwrap();
+        let lazy_scope = lazy_resource.scope_logs().next().unwrap();
+        assert_eq!(lazy_scope.scope_name(), "new");
+        assert_eq!(lazy_scope.scope_version(), Some("2"));
+        assert_eq!(lazy_resource.schema_url(), Some("https://second"));
+        assert_eq!(lazy_resource.service_name(), Some("checkout"));
+    }
+    #[test]
+    fn test_records_in_range() {
+        const FIRST: u64 = 1718380800000000000;
+        const SECOND: u64 = 1718380801000000000;
+
+        let logs = create_test_logs();
+        let scope = &logs.resource_logs[0].scope_logs[0];
+        let names = |records: Vec<&LogRecord>| records.iter().map(|r| r.name().to_string()).collect::<Vec<_>>();
+
+        assert_eq!(names(scope.records_in_range(FIRST, SECOND + 1).collect()), ["HTTP Request", "HTTP Error"]);
+        // The end bound is exclusive
+        assert_eq!(names(scope.records_in_range(FIRST, SECOND).collect()), ["HTTP Request"]);
+        assert_eq!(names(scope.records_in_range(SECOND, u64::MAX).collect()), ["HTTP Error"]);
+        assert_eq!(scope.records_in_range(0, FIRST).count(), 0);
+
+        let encoded = encode_logs_data(&logs);
+        let mut bytes_logs = otlp_bytes::LogsData::new();
+        bytes_logs.parse(&encoded);
+        let bytes_scope = &bytes_logs.resource_logs[0].scope_logs[0];
+        let selected: Vec<_> = bytes_scope.records_in_range(SECOND, u64::MAX).map(|r| r.name()).collect();
+        assert_eq!(selected, ["HTTP Error"]);
+
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let mut checked = 0;
+        for resource in lazy.resource_logs() {
+            for scope in resource.scope_logs() {
+                if scope.scope_name() != "http-handler" {
+                    continue;
+                }
+                let selected: Vec<_> = scope.records_in_range(SECOND, u64::MAX).map(|r| r.name()).collect();
+                assert_eq!(selected, ["HTTP Error"]);
+                assert_eq!(scope.records_in_range(FIRST, u64::MAX).count(), 2);
+                checked += 1;
+            }
+        }
+        assert_eq!(checked, 1);
+
+        // Records without time_unix_nano fall back to the observed time
+        let observed_only = LogRecord { observed_time_unix_nano: SECOND, ..Default::default() }.encode_to_vec();
+        let record = otlp_bytes_lazy::LogRecordParser::new(&observed_only);
+        assert_eq!(record.effective_timestamp(), Some(SECOND));
+    }
+
+    #[test]
+    fn test_attribute_overrides() {
+        use otlp_bytes::EffectiveAttribute;
+
+        let logs = create_test_logs();
+        let original = &logs.resource_logs[0].scope_logs[0].log_records[0];
+        let encoded = original.encode_to_vec();
+        let mut record = otlp_bytes::LogRecord::new();
+        assert!(record.parse(&encoded));
+
+        record.set_attribute_override("method", string("REDACTED"));
+        record.set_attribute_override("tenant", string("acme"));
+        record.set_attribute_override("method", string("[redacted]"));
+
+        let effective: Vec<_> = record.effective_attributes().collect();
+        let keys: Vec<_> = effective.iter().map(|attr| attr.key()).collect();
+        let original_keys: Vec<_> = original.attributes.iter().map(|attr| attr.key.as_str()).collect();
+        assert_eq!(keys[..original_keys.len()], original_keys[..]);
+        assert_eq!(keys.last(), Some(&"tenant"));
+
+        match effective[0] {
+            EffectiveAttribute::Override { key, value } => {
+                assert_eq!(key, "method");
+                assert_eq!(value.as_string(), Some("[redacted]"));
+            }
+            other => panic!("expected an override, got {:?}", other),
+        }
+        assert!(effective[1..original_keys.len()].iter().all(|attr| !attr.is_override()));
+
+        // The borrowed originals are untouched
+        assert_eq!(record.get_all("method").next().and_then(|v| v.string_value()), Some("GET"));
+        assert_eq!(record.attributes().count(), original_keys.len());
+
+        let prost = record.to_prost();
+        assert_eq!(prost.attributes[0].value, Some(string("[redacted]")));
+        assert_eq!(prost.attributes.len(), original_keys.len() + 1);
+
+        // Overrides do not survive a reparse
+        assert!(record.parse(&encoded));
+        assert!(record.effective_attributes().all(|attr| !attr.is_override()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_parse_logs() {
+        let document = r#"{
+            "resourceLogs": [{
+                "resource": {
+                    "attributes": [{"key": "service.name", "value": {"stringValue": "checkout"}}]
+                },
+                "scopeLogs": [{
+                    "scope": {"name": "payments", "version": "2.1.0"},
+                    "logRecords": [
+                        {
+                            "timeUnixNano": "1718380800000000000",
+                            "severityNumber": 17,
+                            "severityText": "ERROR",
+                            "traceId": "W47/95gDgQPSabYzgT/GDA==",
+                            "body": {"stringValue": "card declined"},
+                            "attributes": [
+                                {"key": "amount_cents", "value": {"intValue": "-1250"}},
+                                {"key": "retry", "value": {"boolValue": true}}
+                            ]
+                        },
+                        {
+                            "observedTimeUnixNano": 1718380801000000000,
+                            "eventName": "payment.settled",
+                            "traceId": "5b8efff798038103d269b633813fc60c"
+                        }
+                    ]
+                }]
+            }]
+        }"#;
+
+        let logs: json::OwnedLogsData = json::parse_logs(document).unwrap();
+        let mut records = Vec::new();
+        for resource in logs.resources() {
+            assert_eq!(resource.resource(), "checkout");
+            for scope in resource.scopes() {
+                assert_eq!(scope.instrumentation_library(), "payments@2.1.0");
+                records.extend(scope.log_records());
+            }
+        }
+        assert_eq!(records.len(), 2);
+
+        let first = records[0];
+        assert_eq!(first.name(), "ERROR");
+        assert_eq!(first.timestamp(), Some(1718380800000000000));
+        assert_eq!(first.body().and_then(|b| b.as_string()), Some("card declined"));
+        let amount = first.attributes().find(|a| a.key() == "amount_cents").unwrap();
+        assert_eq!(amount.value().and_then(|v| v.as_int64()), Some(-1250));
+
+        // Base64 and hex trace ids decode to the same bytes
+        assert_eq!(first.trace_id(), records[1].trace_id());
+        assert_eq!(records[1].name(), "payment.settled");
+        assert_eq!(records[1].timestamp(), Some(1718380801000000000));
+
+        assert!(matches!(json::parse_logs("{"), Err(json::JsonDecodeError::Syntax(_))));
+    }
+
+    #[test]
+    fn test_grpc_frames() {
+        fn frame(flags: u8, payload: &[u8]) -> Vec<u8> {
+            let mut out = vec![flags];
+            out.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+            out.extend_from_slice(payload);
+            out
+        }
+
+        let encoded = encode_logs_data(&create_test_logs());
+        let mut body = frame(0x00, &encoded);
+        body.extend(frame(0x80, b"grpc-status:0\r\n"));
+        assert_eq!(grpc::decode_grpc_frames(&body).unwrap(), encoded);
+
+        assert_eq!(grpc::decode_grpc_frames(&body[..3]), Err(grpc::GrpcDecodeError::Truncated { offset: 0 }));
+        // A declared length past the end of the body is truncation, not a panic
+        let mut oversized = frame(0x00, &encoded);
+        oversized.extend([0x00, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(
+            grpc::decode_grpc_frames(&oversized),
+            Err(grpc::GrpcDecodeError::Truncated { offset: encoded.len() + 5 })
+        );
+        assert_eq!(grpc::decode_grpc_frames(&frame(0x80, b"")), Err(grpc::GrpcDecodeError::NoMessage));
+        assert_eq!(grpc::decode_grpc_frames(&frame(0x01, &encoded)), Err(grpc::GrpcDecodeError::Compressed));
+
+        #[cfg(feature = "base64")]
+        {
+            use base64::Engine;
+            let b64 = base64::engine::general_purpose::STANDARD;
+
+            let text = b64.encode(&body);
+            let decoded = grpc::decode_grpc_web(text.as_bytes()).unwrap();
+            let mut logs = otlp_bytes::LogsData::new();
+            assert!(logs.parse(&decoded));
+            assert!(diff::logs_equal(&create_test_logs(), &logs));
+
+            // Streamed bodies may concatenate separately encoded frames
+            let chunked = b64.encode(frame(0x00, &encoded)) + &b64.encode(frame(0x80, b"grpc-status:0"));
+            assert_eq!(grpc::decode_grpc_web(chunked.as_bytes()).unwrap(), encoded);
+
+            assert_eq!(grpc::decode_grpc_web(b"not base64!"), Err(grpc::GrpcDecodeError::InvalidBase64));
+        }
+    }
+
+    #[test]
+    fn test_lazy_display_string_deep() {
+        let nested = kvlist(vec![
+            entry("outer", Some(kvlist(vec![
+                entry("inner", Some(kvlist(vec![entry("leaf", Some(int(7)))]))),
+                entry("flag", Some(AnyValue { value: Some(any_value::Value::BoolValue(true)) })),
+            ]))),
+            entry("name", Some(string("x"))),
+        ]);
+        let record = LogRecord { body: Some(nested), ..Default::default() };
+        let encoded = record.encode_to_vec();
+        let lazy_record = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+        let body = lazy_record.body_value().unwrap();
+
+        assert_eq!(
+            body.to_display_string_deep(4, 10),
+            "{outer: {inner: {leaf: 7}, flag: true}, name: \"x\"}"
+        );
+        assert_eq!(body.to_display_string_deep(2, 10), "{outer: {inner: {...}, flag: true}, name: \"x\"}");
+        assert_eq!(body.to_display_string_deep(0, 10), "{...}");
+        assert_eq!(body.to_display_string_deep(4, 1), "{outer: {inner: {leaf: 7}, ...}, ...}");
+
+        // Matches the eager rendering of the same value
+        let mut eager = otlp_bytes::LogRecord::new();
+        eager.parse(&encoded);
+        assert_eq!(eager.body.as_ref().unwrap().to_display_string_deep(4, 10), body.to_display_string_deep(4, 10));
+    }
+
+    #[test]
+    fn test_lazy_value_type_fast() {
+        let values = vec![
+            any_value::Value::StringValue("s".to_string()),
+            any_value::Value::BoolValue(true),
+            any_value::Value::IntValue(42),
+            any_value::Value::DoubleValue(1.5),
+            any_value::Value::ArrayValue(ArrayValue { values: vec![] }),
+            any_value::Value::KvlistValue(KeyValueList { values: vec![] }),
+            any_value::Value::BytesValue(vec![1, 2]),
+        ];
+
+        for value in values {
+            let encoded = AnyValue { value: Some(value) }.encode_to_vec();
+            let parser = otlp_bytes_lazy::AnyValueParser::new(&encoded);
+            assert_eq!(parser.value_type_fast(), parser.value_type());
+            assert_ne!(parser.value_type_fast(), otlp_bytes_lazy::AnyValueType::Unknown);
+        }
+
+        // Empty and unknown-field-only values are Unknown either way
+        let empty = otlp_bytes_lazy::AnyValueParser::new(&[]);
+        assert_eq!(empty.value_type_fast(), otlp_bytes_lazy::AnyValueType::Unknown);
+        let unknown_field = [0x40, 0x01, 0x18, 0x05]; // tag 8 varint, then int_value 5
+        let parser = otlp_bytes_lazy::AnyValueParser::new(&unknown_field);
+        assert_eq!(parser.value_type_fast(), otlp_bytes_lazy::AnyValueType::Int);
+        assert_eq!(parser.value_type_fast(), parser.value_type());
+    }
+
+    #[test]
+    fn test_schema_url_through_traits() {
+        fn urls<'a, L: LogsView<'a>>(logs: &'a L) -> Vec<(Option<String>, Vec<Option<String>>)> {
+            logs.resources()
+                .map(|resource| {
+                    let scopes = resource.scopes().map(|scope| scope.schema_url().map(str::to_string)).collect();
+                    (resource.schema_url().map(str::to_string), scopes)
+                })
+                .collect()
+        }
+
+        let mut logs = create_test_logs();
+        logs.resource_logs[0].schema_url = "https://opentelemetry.io/schemas/1.21.0".to_string();
+        logs.resource_logs[0].scope_logs[1].schema_url = "https://example.com/db/1.0".to_string();
+        let encoded = encode_logs_data(&logs);
+        let mut eager = otlp_bytes::LogsData::new();
+        assert!(eager.parse(&encoded));
+
+        let expected = vec![
+            (
+                Some("https://opentelemetry.io/schemas/1.21.0".to_string()),
+                vec![None, Some("https://example.com/db/1.0".to_string())],
+            ),
+            (None, vec![None]),
+        ];
+        assert_eq!(urls(&logs), expected);
+        assert_eq!(urls(&eager), expected);
+
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let lazy_resource = lazy.resource_logs().next().unwrap();
+        assert_eq!(lazy_resource.schema_url(), Some("https://opentelemetry.io/schemas/1.21.0"));
+        assert_eq!(lazy_resource.scope_logs().next().unwrap().schema_url(), None);
+    }
+
+    #[test]
+    fn test_lazy_to_compact() {
+        use util::ScalarValue;
+
+        let mut logs = create_test_logs();
+        let record = &mut logs.resource_logs[0].scope_logs[0].log_records[1];
+        record.attributes.push(KeyValue {
+            key: "http.headers".to_string(),
+            value: Some(AnyValue {
+                value: Some(any_value::Value::KvlistValue(KeyValueList {
+                    values: vec![KeyValue {
+                        key: "accept".to_string(),
+                        value: Some(AnyValue { value: Some(any_value::Value::StringValue("*/*".to_string())) }),
+                    }],
+                })),
+            }),
+        });
+        let encoded = encode_logs_data(&logs);
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let records = lazy.collect_records();
+        let error = &records[1].record;
+
+        let compact = error.to_compact();
+        assert_eq!(compact.time_unix_nano, error.time_unix_nano());
+        assert_eq!(compact.severity_number, 13);
+        assert_eq!(compact.body.as_deref(), Some("request_failed"));
+        assert!(compact.attributes.contains(&("method".to_string(), ScalarValue::String("POST".to_string()))));
+        assert!(compact.attributes.contains(&("status_code".to_string(), ScalarValue::Int(500))));
+        assert!(compact.attributes.iter().all(|(key, _)| !key.starts_with("http.headers")));
+
+        let flattened = error.to_compact_with(otlp_bytes_lazy::NestedAttributePolicy::Flatten);
+        assert_eq!(flattened.attributes.len(), compact.attributes.len() + 1);
+        assert!(flattened.attributes.contains(&("http.headers.accept".to_string(), ScalarValue::String("*/*".to_string()))));
+
+        // Owned and Send, so it can outlive the buffer on another thread
+        drop(records);
+        drop(encoded);
+        let handle = std::thread::spawn(move || compact.attributes.len());
+        assert_eq!(handle.join().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_lazy_severity_number_opt() {
+        let absent = LogRecord { time_unix_nano: 1, ..Default::default() }.encode_to_vec();
+        let record = otlp_bytes_lazy::LogRecordParser::new(&absent);
+        assert_eq!(record.severity_number_opt(), None);
+        assert_eq!(record.severity_number(), 0);
+
+        // prost never writes a default enum, so spell out tag 2 = 0 by hand
+        let explicit_zero = [0x10, 0x00];
+        let record = otlp_bytes_lazy::LogRecordParser::new(&explicit_zero);
+        assert_eq!(record.severity_number_opt(), Some(0));
+        assert_eq!(record.severity_number(), 0);
+
+        let info = LogRecord { severity_number: 9, ..Default::default() }.encode_to_vec();
+        assert_eq!(otlp_bytes_lazy::LogRecordParser::new(&info).severity_number_opt(), Some(9));
+    }
+
+    #[test]
+    fn test_parse_standalone_messages() {
+        let values = vec![
+            any_value::Value::StringValue("cached".to_string()),
+            any_value::Value::BoolValue(true),
+            any_value::Value::IntValue(-7),
+            any_value::Value::DoubleValue(2.5),
+            any_value::Value::BytesValue(vec![0xde, 0xad]),
+            any_value::Value::ArrayValue(ArrayValue {
+                values: vec![AnyValue { value: Some(any_value::Value::IntValue(1)) }],
+            }),
+            any_value::Value::KvlistValue(KeyValueList {
+                values: vec![KeyValue {
+                    key: "k".to_string(),
+                    value: Some(AnyValue { value: Some(any_value::Value::StringValue("v".to_string())) }),
+                }],
+            }),
+        ];
+
+        for value in values {
+            let original = AnyValue { value: Some(value) };
+            let encoded = original.encode_to_vec();
+            let parsed = otlp_bytes::parse_any_value(&encoded).unwrap();
+            assert_eq!(parsed.to_prost(), original);
+        }
+
+        assert!(otlp_bytes::parse_any_value(&[]).is_none());
+        // Length prefix runs past the end of the buffer
+        assert!(otlp_bytes::parse_any_value(&[0x0a, 0x05, b'a']).is_none());
+
+        let logs = create_test_logs();
+        let original = logs.resource_logs[0].resource.clone().unwrap();
+        let encoded = original.encode_to_vec();
+        let resource = otlp_bytes::parse_resource(&encoded).unwrap();
+        assert_eq!(resource.get_service_name(), Some("web-server"));
+        assert_eq!(resource.to_prost(), original);
+        assert!(otlp_bytes::parse_resource(&encoded[..encoded.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn test_attribute_order_matches_wire_order() {
+        // Deliberately unsorted, with a duplicate key
+        let keys = ["zeta", "alpha", "mid", "alpha", "beta"];
+        let record = LogRecord {
+            attributes: keys
+                .iter()
+                .enumerate()
+                .map(|(idx, key)| KeyValue {
+                    key: key.to_string(),
+                    value: Some(AnyValue { value: Some(any_value::Value::IntValue(idx as i64)) }),
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let encoded = record.encode_to_vec();
+
+        fn order<'a, R: LogRecordView<'a>>(record: &'a R) -> Vec<(String, i64)> {
+            record
+                .attributes()
+                .map(|attr| (attr.key().to_string(), attr.value().and_then(|v| v.as_int64()).unwrap()))
+                .collect()
+        }
+        let expected: Vec<(String, i64)> = keys.iter().enumerate().map(|(idx, key)| (key.to_string(), idx as i64)).collect();
+
+        assert_eq!(order(&record), expected);
+
+        let mut eager = otlp_bytes::LogRecord::new();
+        assert!(eager.parse(&encoded));
+        assert_eq!(order(&eager), expected);
+
+        let lazy = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+        let lazy_order: Vec<(String, i64)> = lazy
+            .attributes()
+            .map(|attr| (attr.key().unwrap().to_string(), attr.value().and_then(|v| v.int_value()).unwrap()))
+            .collect();
+        assert_eq!(lazy_order, expected);
+    }
+
+    #[test]
+    fn test_severity_text_or_inferred() {
+        let number_only = |severity_number| LogRecord { severity_number, ..Default::default() };
+
+        assert_eq!(number_only(9).severity_text_or_inferred(), "INFO");
+        assert_eq!(number_only(19).severity_text_or_inferred(), "ERROR3");
+        assert_eq!(number_only(24).severity_text_or_inferred(), "FATAL4");
+        assert_eq!(number_only(0).severity_text_or_inferred(), "UNSPECIFIED");
+        assert_eq!(number_only(99).severity_text_or_inferred(), "UNSPECIFIED");
+        assert_eq!(number_only(-1).severity_text_or_inferred(), "UNSPECIFIED");
+
+        let explicit = LogRecord { severity_number: 9, severity_text: "Information".to_string(), ..Default::default() };
+        assert_eq!(explicit.severity_text_or_inferred(), "Information");
+
+        let encoded = number_only(9).encode_to_vec();
+        let mut eager = otlp_bytes::LogRecord::new();
+        eager.parse(&encoded);
+        assert_eq!(eager.severity_text(), None);
+        assert_eq!(eager.severity_text_or_inferred(), "INFO");
+    }
+
+    #[test]
+    fn test_lazy_resource_cache_matches_scan() {
+        let mut logs = create_test_logs();
+        logs.resource_logs.push(ResourceLogs::default());
+        let encoded = encode_logs_data(&logs);
+        let mut eager = otlp_bytes::LogsData::new();
+        eager.parse(&encoded);
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        let eager_names: Vec<Option<&str>> = eager
+            .into_iter()
+            .map(|resource| resource.resource.as_ref().and_then(|r| r.get_service_name()))
+            .collect();
+        assert_eq!(eager_names, vec![Some("web-server"), Some("background-worker"), None]);
+
+        let mut lazy_names = Vec::new();
+        for resource in lazy.resource_logs() {
+            let scanned = resource
+                .attributes()
+                .and_then(|mut attrs| attrs.find(|attr| attr.key() == Some("service.name")))
+                .and_then(|attr| attr.value())
+                .and_then(|value| value.string_value());
+            assert_eq!(resource.service_name(), scanned);
+            // Served from the cache on the second call
+            assert_eq!(resource.service_name(), scanned);
+            assert_eq!(resource.resource_attribute("service.name").and_then(|v| v.string_value()), scanned);
+            assert_eq!(resource.resource_attribute_count(), resource.attributes().map_or(0, |attrs| attrs.count()));
+            lazy_names.push(scanned.map(str::to_string));
+        }
+        assert_eq!(lazy_names, eager_names.iter().map(|name| name.map(str::to_string)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_resource_info() {
+        type Info = (Option<String>, Option<String>, Option<String>, usize);
+        fn infos<'a, L: LogsView<'a>>(logs: &'a L) -> Vec<Info> {
+            logs.resources()
+                .map(|resource| {
+                    let info = resource.resource_info();
+                    (
+                        info.service_name().map(str::to_string),
+                        info.service_version().map(str::to_string),
+                        info.service_instance_id().map(str::to_string),
+                        info.attributes().count(),
+                    )
+                })
+                .collect()
+        }
+
+        let mut logs = create_test_logs();
+        logs.resource_logs[1].resource.as_mut().unwrap().attributes.push(KeyValue {
+            key: "service.instance.id".to_string(),
+            value: Some(AnyValue { value: Some(any_value::Value::StringValue("worker-7".to_string())) }),
+        });
+        let encoded = encode_logs_data(&logs);
+        let mut eager = otlp_bytes::LogsData::new();
+        eager.parse(&encoded);
+
+        let info = logs.resource_logs[0].resource_info();
+        assert_eq!(info.service_name(), Some("web-server"));
+        assert_eq!(info.service_version(), Some("1.2.3"));
+        assert_eq!(info.service_namespace(), None);
+        assert_eq!(info.get_str("deployment.environment"), Some("production"));
+
+        let prost_infos = infos(&logs);
+        assert_eq!(prost_infos[1].0.as_deref(), Some("background-worker"));
+        assert_eq!(prost_infos[1].2.as_deref(), Some("worker-7"));
+        assert_eq!(infos(&eager), prost_infos);
+    }
+
+    #[cfg(feature = "legacy_compat")]
+    #[test]
+    fn test_legacy_instrumentation_library_logs() {
+        // Re-encode each ResourceLogs with its scopes under the deprecated
+        // instrumentation_library_logs field (1000) instead of scope_logs,
+        // except every second one, to check both tags merge in wire order
+        let logs = create_test_logs();
+        let mut legacy = Vec::new();
+        for resource_logs in &logs.resource_logs {
+            let mut resource_bytes = Vec::new();
+            if let Some(resource) = &resource_logs.resource {
+                prost::encoding::message::encode(1, resource, &mut resource_bytes);
+            }
+            for (index, scope_logs) in resource_logs.scope_logs.iter().enumerate() {
+                let tag = if index % 2 == 0 { 1000 } else { 2 };
+                prost::encoding::message::encode(tag, scope_logs, &mut resource_bytes);
+            }
+            prost::encoding::bytes::encode(1, &resource_bytes, &mut legacy);
+        }
+
+        let mut eager = otlp_bytes::LogsData::new();
+        assert!(eager.parse(&legacy));
+        assert!(diff::logs_equal(&logs, &eager));
+
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&legacy);
+        let mut scopes = Vec::new();
+        for resource in lazy.resource_logs() {
+            for scope in resource.scope_logs() {
+                scopes.push((scope.scope_name().to_string(), scope.log_records().count()));
+            }
+        }
+        assert_eq!(
+            scopes,
+            vec![
+                ("http-handler".to_string(), 2),
+                ("database-connector".to_string(), 1),
+                ("job-processor".to_string(), 1),
+            ]
+        );
+        assert_eq!(lazy.collect_records().len(), 4);
+    }
+
+    #[test]
+    fn test_attribute_cardinality() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut eager = otlp_bytes::LogsData::new();
+        eager.parse(&encoded);
+
+        assert_eq!(logs.distinct_attribute_keys(), 11);
+        assert_eq!(eager.distinct_attribute_keys(), 11);
+        assert!(logs.attribute_keys().contains("status_code"));
+        assert!(!logs.attribute_keys().contains("service.name"));
+
+        let cardinality = logs.attribute_value_cardinality(10);
+        assert_eq!(cardinality.len(), 11);
+        assert_eq!(cardinality["method"], ValueCardinality::Exact(2));
+        assert_eq!(cardinality["db.name"], ValueCardinality::Exact(1));
+        assert_eq!(eager.attribute_value_cardinality(10), cardinality);
+
+        let capped = logs.attribute_value_cardinality(1);
+        assert_eq!(capped["method"], ValueCardinality::AtLeast(1));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_frame_reader() {
+        use tokio::io::AsyncWriteExt;
+
+        let first = create_test_logs();
+        let mut second = create_test_logs();
+        second.resource_logs.truncate(1);
+        let mut stream = first.encode_length_delimited_to_vec();
+        stream.extend(second.encode_length_delimited_to_vec());
+
+        // A tiny pipe forces the reader through many partial reads
+        let (mut writer, reader) = tokio::io::duplex(16);
+        let write = tokio::spawn(async move {
+            writer.write_all(&stream).await.unwrap();
+        });
+
+        let mut frames = async_read::AsyncLogsFrameReader::new(reader);
+        let frame = frames.next_frame().await.unwrap().unwrap();
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(parsed.parse(&frame));
+        assert!(diff::logs_equal(&first, &parsed));
+
+        let frame = frames.next_frame().await.unwrap().unwrap();
+        assert!(parsed.parse(&frame));
+        assert!(diff::logs_equal(&second, &parsed));
+
+        write.await.unwrap();
+        assert!(frames.next_frame().await.is_none());
+
+        // Ending inside a frame, or a frame over the limit, is an error
+        let truncated = first.encode_length_delimited_to_vec();
+        let mut frames = async_read::AsyncLogsFrameReader::new(&truncated[..truncated.len() - 1]);
+        assert!(matches!(frames.next_frame().await, Some(Err(async_read::FrameReadError::Io(_)))));
+        let mut frames = async_read::AsyncLogsFrameReader::new(&truncated[..]).with_max_frame_len(8);
+        assert!(matches!(frames.next_frame().await, Some(Err(async_read::FrameReadError::TooLarge { max: 8, .. }))));
+    }
+
+    #[test]
+    fn test_has_attributes() {
+        let logs = create_test_logs();
+        let worker_record = &logs.resource_logs[1].scope_logs[0].log_records[0];
+        let bare = LogRecord { severity_number: 9, body: worker_record.body.clone(), ..Default::default() };
+        assert!(worker_record.has_attributes());
+        assert!(!bare.has_attributes());
+
+        for (record, expected) in [(worker_record, true), (&bare, false)] {
+            let encoded = record.encode_to_vec();
+            let mut eager = otlp_bytes::LogRecord::new();
+            eager.parse(&encoded);
+            assert_eq!(eager.has_attributes(), expected);
+
+            let lazy = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+            assert_eq!(lazy.has_attributes(), expected);
+            // Same answer once the field cache exists
+            assert_eq!(lazy.attributes().count() > 0, expected);
+            assert_eq!(lazy.has_attributes(), expected);
+
+            let projected = otlp_bytes_lazy::LogRecordParser::with_projection(
+                &encoded,
+                otlp_bytes_lazy::ProjectionMask::SEVERITY_NUMBER,
+            );
+            assert!(!projected.has_attributes());
+        }
+    }
+
+    #[test]
+    fn test_key_interner() {
+        use intern::KeyInterner;
+
+        let mut interner = KeyInterner::new();
+        let method = interner.intern("method").unwrap();
+        assert_eq!(interner.intern_bytes(b"method"), Some(method));
+        assert_eq!(interner.get("method"), Some(method));
+        assert_ne!(interner.intern("status_code"), Some(method));
+        assert_eq!(interner.resolve(method), "method");
+        assert_eq!(interner.intern_bytes(&[0xff, 0xfe]), None);
+        assert_eq!(interner.len(), 2);
+
+        // Equal key bytes in different records intern to the same handle
+        let encoded = encode_logs_data(&create_test_logs());
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let records = lazy.collect_records();
+        let mut interned = Vec::new();
+        for flat in &records {
+            interned.extend(flat.record.attributes_interned(&mut interner).map(|(id, attr)| (id.unwrap(), attr)));
+        }
+        let mut method_ids = Vec::new();
+        for (id, attr) in &interned {
+            assert_eq!(interner.resolve(*id), attr.key().unwrap());
+            if attr.key() == Some("method") {
+                method_ids.push(*id);
+            }
+        }
+        assert_eq!(method_ids, vec![method, method]);
+        assert_eq!(interner.len(), 11);
+
+        let mut capped = KeyInterner::new().with_max_keys(1);
+        let first = capped.intern("a").unwrap();
+        assert_eq!(capped.intern("b"), None);
+        assert_eq!(capped.intern("a"), Some(first));
+    }
+
+    #[test]
+    fn test_encode_rejects_malformed_ids() {
+        let mut logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut eager = otlp_bytes::LogsData::new();
+        eager.parse(&encoded);
+        assert_eq!(eager.encode().unwrap(), encoded);
+
+        // A producer sent a 20-byte trace id; it parses, but is not re-emitted
+        logs.resource_logs[0].scope_logs[0].log_records[1].trace_id = vec![0xab; 20];
+        let encoded = encode_logs_data(&logs);
+        eager.parse(&encoded);
+        assert_eq!(eager.encode(), Err(otlp_bytes::EncodeError::InvalidTraceIdLength(20)));
+
+        let resource = eager.into_iter().next().unwrap();
+        let scope = resource.into_iter().next().unwrap();
+        let record = scope.into_iter().nth(1).unwrap();
+        assert_eq!(record.trace_id(), Some(&[0xab; 20][..]));
+        assert!(record.encode().is_err());
+        assert_eq!(scope.into_iter().next().unwrap().encode().unwrap(), logs.resource_logs[0].scope_logs[0].log_records[0].encode_to_vec());
+        // The untouched resource still encodes
+        assert!(eager.into_iter().nth(1).unwrap().encode().is_ok());
+
+        let mut short_span = otlp_bytes::LogRecord::new();
+        let bytes = LogRecord { span_id: vec![1; 4], ..Default::default() }.encode_to_vec();
+        short_span.parse(&bytes);
+        assert_eq!(short_span.encode(), Err(otlp_bytes::EncodeError::InvalidSpanIdLength(4)));
+    }
+
+    #[test]
+    fn test_nth_random_access() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut eager = otlp_bytes::LogsData::new();
+        eager.parse(&encoded);
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        assert_eq!(logs.nth_resource(1).unwrap().resource(), "background-worker");
+        assert_eq!(eager.nth_resource(1).unwrap().resource(), "background-worker");
+        assert!(logs.nth_resource(2).is_none());
+        assert!(eager.nth_resource(2).is_none());
+
+        let scope = eager.nth_resource(0).unwrap().nth_scope(1).unwrap();
+        assert_eq!(scope.scope(), "database-connector");
+        assert_eq!(scope.nth_record(0).unwrap().name(), "DB Connection");
+        assert!(scope.nth_record(1).is_none());
+        assert_eq!(logs.nth_resource(0).unwrap().nth_scope(0).unwrap().nth_record(1).unwrap().name(), "HTTP Error");
+
+        let resource = lazy.nth_resource(1).unwrap();
+        assert_eq!(resource.service_name(), Some("background-worker"));
+        assert!(lazy.nth_resource(2).is_none());
+        let scope = lazy.nth_resource(0).unwrap().nth_scope(0).unwrap();
+        assert_eq!(scope.scope_name(), "http-handler");
+        assert_eq!(scope.nth_record(1).unwrap().event_name(), Some("HTTP Error"));
+        assert!(scope.nth_record(2).is_none());
+    }
+
+    #[test]
+    fn test_packed_fixed_arrays() {
+        let mut doubles = Vec::new();
+        prost::encoding::double::encode_packed(1, &[1.5, -2.0, 3.25], &mut doubles);
+        // One tag byte, then the length prefix
+        let parser = otlp_bytes::ProtobufParser::new(&doubles);
+        let values: Vec<f64> = parser.parse_packed_fixed64(1).unwrap().into_iter().map(f64::from_bits).collect();
+        assert_eq!(values, vec![1.5, -2.0, 3.25]);
+
+        let mut counts = Vec::new();
+        prost::encoding::fixed32::encode_packed(4, &[7, 0, u32::MAX], &mut counts);
+        let parser = otlp_bytes::ProtobufParser::new(&counts);
+        assert_eq!(parser.parse_packed_fixed32(1), Some(vec![7, 0, u32::MAX]));
+
+        // Empty, ragged and truncated payloads
+        assert_eq!(otlp_bytes::ProtobufParser::new(&[0x0a, 0x00]).parse_packed_fixed64(1), Some(vec![]));
+        assert_eq!(otlp_bytes::ProtobufParser::new(&[0x0a, 0x03, 1, 2, 3]).parse_packed_fixed32(1), None);
+        assert_eq!(otlp_bytes::ProtobufParser::new(&doubles[..doubles.len() - 1]).parse_packed_fixed64(1), None);
+    }
+
+    #[test]
+    fn test_take_records() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut eager = otlp_bytes::LogsData::new();
+        eager.parse(&encoded);
+
+        fn names<'a, L: LogsView<'a>>(logs: &'a L, n: usize) -> Vec<String> {
+            logs.take_records(n).map(|record| record.name().to_string()).collect()
+        }
+
+        let first_three = vec!["HTTP Request", "HTTP Error", "DB Connection"];
+        assert_eq!(names(&logs, 3), first_three);
+        assert_eq!(names(&eager, 3), first_three);
+        // Stops partway through the first resource's first scope
+        assert_eq!(names(&eager, 1), vec!["HTTP Request"]);
+        assert_eq!(names(&logs, 0), Vec::<String>::new());
+        assert_eq!(names(&logs, 10).len(), 4);
+    }
+
+    #[test]
+    fn test_logs_hash() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let mut first = otlp_bytes::LogsData::new();
+        first.parse(&encoded);
+        let mut second = otlp_bytes::LogsData::new();
+        second.parse(&encoded);
+
+        let hash = first.logs_hash();
+        assert_eq!(second.logs_hash(), hash);
+        assert_eq!(logs.logs_hash(), hash);
+
+        let mut swapped = create_test_logs();
+        swapped.resource_logs[0].scope_logs[0].log_records.swap(0, 1);
+        assert_ne!(swapped.logs_hash(), hash);
+
+        // Only a timestamp differs, which content_hash alone would miss
+        let mut retimed = create_test_logs();
+        retimed.resource_logs[1].scope_logs[0].log_records[0].time_unix_nano += 1;
+        assert_ne!(retimed.logs_hash(), hash);
+
+        // Moving a record across a scope boundary changes it too
+        let mut moved = create_test_logs();
+        let record = moved.resource_logs[0].scope_logs[0].log_records.pop().unwrap();
+        moved.resource_logs[0].scope_logs[1].log_records.insert(0, record);
+        assert_ne!(moved.logs_hash(), hash);
+    }
+
+    #[test]
+    fn test_lazy_raw_timestamps() {
+        let encoded = encode_logs_data(&create_test_logs());
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+
+        for flat in lazy.collect_records() {
+            let record = &flat.record;
+            let raw = record.time_unix_nano_raw().unwrap();
+            assert_eq!(raw.len(), 8);
+            assert_eq!(u64::from_le_bytes(raw.try_into().unwrap()), record.time_unix_nano());
+            let observed = record.observed_time_unix_nano_raw().unwrap();
+            assert_eq!(u64::from_le_bytes(observed.try_into().unwrap()), record.observed_time_unix_nano());
+            // The slice points into the original buffer
+            let offset = raw.as_ptr() as usize - encoded.as_ptr() as usize;
+            assert_eq!(&encoded[offset..offset + 8], raw);
+        }
+
+        let untimed = LogRecord { severity_number: 9, ..Default::default() }.encode_to_vec();
+        let record = otlp_bytes_lazy::LogRecordParser::new(&untimed);
+        assert_eq!(record.time_unix_nano_raw(), None);
+        assert_eq!(record.observed_time_unix_nano_raw(), None);
+    }
+
+    #[test]
+    fn test_logs_stream_writer() {
+        use stream_writer::{LogsStreamWriter, StreamWriteError};
+
+        let logs = create_test_logs();
+        let resource = &logs.resource_logs[0];
+        let scope = &resource.scope_logs[0];
+
+        let mut writer = LogsStreamWriter::new(Vec::new());
+        writer.begin_resource(resource.resource.as_ref(), "https://resource").unwrap();
+        writer.begin_scope(scope.scope.as_ref(), "").unwrap();
+        writer.write_record(&scope.log_records[0]).unwrap();
+        writer.write_record_bytes(&scope.log_records[1].encode_to_vec()).unwrap();
+        writer.end_scope().unwrap();
+        writer.end_resource().unwrap();
+        let encoded = writer.finish().unwrap();
+
+        let expected = LogsData {
+            resource_logs: vec![ResourceLogs {
+                resource: resource.resource.clone(),
+                scope_logs: vec![ScopeLogs {
+                    scope: scope.scope.clone(),
+                    log_records: scope.log_records[..2].to_vec(),
+                    schema_url: String::new(),
+                }],
+                schema_url: "https://resource".to_string(),
+            }],
+        };
+        assert_eq!(LogsData::decode(encoded.as_slice()).unwrap(), expected);
+
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let names: Vec<_> = lazy.collect_records().iter().map(|flat| flat.record.event_name().map(str::to_string)).collect();
+        assert_eq!(names, [Some("HTTP Request".to_string()), Some("HTTP Error".to_string())]);
+
+        // Calls out of order are rejected rather than producing a bad frame
+        let mut writer = LogsStreamWriter::new(Vec::new());
+        assert!(matches!(writer.write_record(&scope.log_records[0]), Err(StreamWriteError::OutOfOrder(_))));
+        writer.begin_resource(None, "").unwrap();
+        assert!(matches!(writer.end_scope(), Err(StreamWriteError::OutOfOrder(_))));
+        assert!(matches!(writer.finish(), Err(StreamWriteError::OutOfOrder("finish"))));
+    }
+
+    #[test]
+    fn test_detect_signal() {
+        use signal::{detect_signal, Confidence, Signal};
+
+        let guess = detect_signal(&encode_logs_data(&create_test_logs()));
+        assert_eq!(guess.signal, Signal::Logs);
+        assert_eq!(guess.confidence, Confidence::High);
+
+        // TracesData with one Span: trace_id, span_id, name, kind, start/end
+        let mut span = Vec::new();
+        prost::encoding::bytes::encode(1, &vec![0xAB; 16], &mut span);
+        prost::encoding::bytes::encode(2, &vec![0xCD; 8], &mut span);
+        prost::encoding::string::encode(5, &"GET /users".to_string(), &mut span);
+        prost::encoding::int32::encode(6, &2, &mut span);
+        prost::encoding::fixed64::encode(7, &1_700_000_000, &mut span);
+        prost::encoding::fixed64::encode(8, &1_700_000_500, &mut span);
+        let mut scope_spans = Vec::new();
+        prost::encoding::bytes::encode(2, &span, &mut scope_spans);
+        let mut resource_spans = Vec::new();
+        prost::encoding::bytes::encode(2, &scope_spans, &mut resource_spans);
+        let mut traces = Vec::new();
+        prost::encoding::bytes::encode(1, &resource_spans, &mut traces);
+
+        let guess = detect_signal(&traces);
+        assert_eq!(guess.signal, Signal::Traces);
+        assert_eq!(guess.confidence, Confidence::High);
+
+        // A leaf with only a string at tag 5 is a record body, a span name or
+        // a gauge as far as the wire shows; the guess falls back to logs
+        let mut ambiguous_leaf = Vec::new();
+        prost::encoding::bytes::encode(5, &vec![0x0A, 0x00], &mut ambiguous_leaf);
+        let mut scope = Vec::new();
+        prost::encoding::bytes::encode(2, &ambiguous_leaf, &mut scope);
+        let mut resource = Vec::new();
+        prost::encoding::bytes::encode(2, &scope, &mut resource);
+        let mut ambiguous = Vec::new();
+        prost::encoding::bytes::encode(1, &resource, &mut ambiguous);
+        assert_eq!(detect_signal(&ambiguous).confidence, Confidence::Low);
+
+        assert_eq!(detect_signal(&[]).signal, Signal::Unknown);
+        assert_eq!(detect_signal(b"not protobuf").signal, Signal::Unknown);
+        assert_eq!(detect_signal(&encode_logs_data(&LogsData::default())).signal, Signal::Unknown);
+    }
+
+    #[cfg(feature = "parse_stats")]
+    #[test]
+    fn test_parse_stats() {
+        let encoded = encode_logs_data(&create_test_logs());
+        let mut logs = otlp_bytes::LogsData::new();
+        let (parsed, stats) = logs.parse_with_stats(&encoded);
+        assert!(parsed);
+        assert!(stats.varints_parsed > 0);
+        assert!(stats.length_delimited_skipped > 0);
+        assert_eq!(stats.bytes_visited, encoded.len());
+        assert!(stats.bytes_visited <= encoded.len());
+        // Every message is rescanned per field looked up
+        assert!(stats.bytes_scanned > stats.bytes_visited);
+        // Each scanned field costs at least its tag varint
+        assert!(stats.varints_parsed >= stats.length_delimited_skipped);
+
+        // Counters start from zero on every call
+        let (_, again) = logs.parse_with_stats(&encoded);
+        assert_eq!(again, stats);
+
+        // A truncated buffer stops the top-level walk early
+        let (parsed, truncated) = logs.parse_with_stats(&encoded[..encoded.len() - 1]);
+        assert!(!parsed);
+        assert!(truncated.bytes_visited < encoded.len() - 1);
+    }
+
+    #[test]
+    fn test_typed_attributes() {
+        let logs = create_test_logs();
+        let record = &logs.resource_logs[0].scope_logs[0].log_records[0];
+
+        let typed: Vec<_> = record.typed_attributes().collect();
+        assert_eq!(typed.len(), record.attributes.len());
+        assert_eq!(typed[0], ("method", TypedValue::String("GET")));
+        assert_eq!(typed[1], ("status_code", TypedValue::Int(200)));
+        assert_eq!(typed[2], ("response_time_ms", TypedValue::Double(45.7)));
+
+        // Same values through the eager parser
+        let encoded = encode_logs_data(&logs);
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(parsed.parse(&encoded));
+        let parsed_record = parsed.resources().next().unwrap().scopes().next().unwrap().log_records().next().unwrap();
+        let status = parsed_record.typed_attributes().find(|(key, _)| *key == "status_code").unwrap();
+        assert_eq!(status.1, TypedValue::Int(200));
+
+        // Containers carry the value itself; unset values are skipped
+        let nested = LogRecord {
+            attributes: vec![
+                KeyValue {
+                    key: "list".to_string(),
+                    value: Some(AnyValue {
+                        value: Some(any_value::Value::ArrayValue(ArrayValue { values: vec![AnyValue::default()] })),
+                    }),
+                },
+                KeyValue { key: "unset".to_string(), value: Some(AnyValue::default()) },
+                KeyValue { key: "missing".to_string(), value: None },
+            ],
+            ..Default::default()
+        };
+        let typed: Vec<_> = nested.typed_attributes().collect();
+        assert_eq!(typed.len(), 1);
+        match typed[0] {
+            ("list", TypedValue::Array(value)) => assert_eq!(value.array_iter().unwrap().count(), 1),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_utf8_error_offsets() {
+        // KeyValue { key: "ab\xFFc", value: { string_value: "ok\xC3" } }
+        let mut value = Vec::new();
+        prost::encoding::bytes::encode(1, &b"ok\xC3".to_vec(), &mut value);
+        let mut kv = Vec::new();
+        prost::encoding::bytes::encode(1, &b"ab\xFFc".to_vec(), &mut kv);
+        prost::encoding::bytes::encode(2, &value, &mut kv);
+
+        let mut eager = otlp_bytes::KeyValue::new();
+        assert!(eager.parse(&kv));
+        assert_eq!(eager.key, "");
+        assert_eq!(eager.key_utf8_error(), Some(2));
+        assert_eq!(eager.value.as_ref().unwrap().string_utf8_error(), Some(2));
+
+        let lazy = otlp_bytes_lazy::KeyValueParser::new(&kv);
+        assert_eq!(lazy.key(), None);
+        assert_eq!(lazy.key_utf8_error(), Some(2));
+        assert_eq!(lazy.value().unwrap().string_utf8_error(), Some(2));
+
+        // Valid keys and non-string values report nothing
+        let mut valid = Vec::new();
+        prost::encoding::string::encode(1, &"status".to_string(), &mut valid);
+        assert!(eager.parse(&valid));
+        assert_eq!(eager.key_utf8_error(), None);
+        assert_eq!(otlp_bytes_lazy::KeyValueParser::new(&valid).key_utf8_error(), None);
+        let int_value = AnyValue { value: Some(any_value::Value::IntValue(7)) }.encode_to_vec();
+        assert_eq!(otlp_bytes_lazy::AnyValueParser::new(&int_value).string_utf8_error(), None);
+    }
+
+    #[test]
+    fn test_parse_columnar() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let columns = columnar::parse_columnar(&encoded);
+
+        let records: Vec<&LogRecord> = logs
+            .resource_logs
+            .iter()
+            .flat_map(|r| &r.scope_logs)
+            .flat_map(|s| &s.log_records)
+            .collect();
+        assert_eq!(columns.len(), 4);
+        assert_eq!(columns.severity_numbers, records.iter().map(|r| r.severity_number).collect::<Vec<_>>());
+        assert_eq!(columns.time_unix_nanos, records.iter().map(|r| r.time_unix_nano).collect::<Vec<_>>());
+        assert_eq!(columns.resource_indices, [0, 0, 0, 1]);
+        assert_eq!(columns.scope_indices, [0, 0, 1, 0]);
+
+        // Bodies and attribute values decode from their ranges on demand
+        assert_eq!(columns.body(1).unwrap().string_value(), Some("request_failed"));
+        assert_eq!(columns.attributes.offsets.len(), columns.len() + 1);
+        assert_eq!(columns.attributes.keys.len(), records.iter().map(|r| r.attributes.len()).sum::<usize>());
+        let status = columns.attributes(0).find(|(key, _)| *key == "status_code").unwrap();
+        assert_eq!(status.1.unwrap().int_value(), Some(200));
+        for (i, record) in records.iter().enumerate() {
+            let keys: Vec<&str> = columns.attributes(i).map(|(key, _)| key).collect();
+            assert_eq!(keys, record.attributes.iter().map(|kv| kv.key.as_str()).collect::<Vec<_>>());
+        }
+        assert_eq!(columns.attributes(4).count(), 0);
+
+        assert!(columnar::parse_columnar(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_int_value_any_accepts_fixed64() {
+        // AnyValue { int_value: -5 } with the int written as sfixed64
+        let mut fixed = Vec::new();
+        prost::encoding::sfixed64::encode(3, &-5, &mut fixed);
+        let value = otlp_bytes_lazy::AnyValueParser::new(&fixed);
+        assert_eq!(value.int_value(), None);
+        assert_eq!(value.int_value_any(), Some(-5));
+
+        // Conforming varint ints read the same either way
+        let varint = AnyValue { value: Some(any_value::Value::IntValue(200)) }.encode_to_vec();
+        let value = otlp_bytes_lazy::AnyValueParser::new(&varint);
+        assert_eq!(value.int_value_any(), Some(200));
+        assert_eq!(value.int_value_any(), value.int_value());
+
+        let string = AnyValue { value: Some(any_value::Value::StringValue("x".to_string())) }.encode_to_vec();
+        assert_eq!(otlp_bytes_lazy::AnyValueParser::new(&string).int_value_any(), None);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_resources() {
+        use rayon::iter::ParallelIterator;
+
+        let encoded = encode_logs_data(&create_test_logs());
+        let mut logs = otlp_bytes::LogsData::new();
+        assert!(logs.parse(&encoded));
+
+        let sequential: usize = logs.resources().flat_map(|r| r.scopes()).map(|s| s.log_records().count()).sum();
+        let parallel: usize = logs
+            .par_resources()
+            .map(|resource| resource.scopes().map(|s| s.log_records().count()).sum::<usize>())
+            .sum();
+        assert_eq!(sequential, 4);
+        assert_eq!(parallel, sequential);
+
+        // Slots kept from an earlier, larger parse are not visited
+        let single = encode_logs_data(&LogsData { resource_logs: create_test_logs().resource_logs[1..].to_vec() });
+        assert!(logs.parse(&single));
+        assert_eq!(logs.par_resources().count(), 1);
+    }
+
+    #[test]
+    fn test_varint_overflow_rejected() {
+        // Unknown top-level field 2 holding a 10-byte varint. A final byte of
+        // 0x01 sets only bit 63 (u64::MAX); 0x02 would set bit 64.
+        let varint_field = |last: u8| {
+            let mut field = vec![0x10];
+            field.extend([0xFF; 9]);
+            field.push(last);
+            field
+        };
+        let max = varint_field(0x01);
+        let overflow = varint_field(0x02);
+        let mut decoded = &max[1..];
+        assert_eq!(prost::encoding::decode_varint(&mut decoded).unwrap(), u64::MAX);
+        assert!(prost::encoding::decode_varint(&mut &overflow[1..]).is_err());
+
+        let mut eager = otlp_bytes::LogsData::new();
+        assert!(eager.parse(&max));
+        assert!(!eager.parse(&overflow));
+        assert_eq!(eager.parse_error_offset(), Some(0));
+
+        assert_eq!(otlp_bytes_lazy::ProtobufParser::debug_fields(&max), [(2, 0, 10)]);
+        assert!(otlp_bytes_lazy::ProtobufParser::debug_fields(&overflow).is_empty());
+
+        // An 11-byte varint is over-long even when it fits
+        let mut over_long = vec![0x10];
+        over_long.extend([0x80; 10]);
+        over_long.push(0x00);
+        assert!(!eager.parse(&over_long));
+        assert!(otlp_bytes_lazy::ProtobufParser::debug_fields(&over_long).is_empty());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_frame_length_overflow() {
+        let mut prefix = vec![0xFF; 9];
+        prefix.push(0x02);
+        let mut frames = async_read::AsyncLogsFrameReader::new(&prefix[..]);
+        assert!(matches!(frames.next_frame().await, Some(Err(async_read::FrameReadError::InvalidLength))));
+    }
+
+    #[test]
+    fn test_trace_context() {
+        let trace_id: [u8; 16] = std::array::from_fn(|i| i as u8 + 1);
+        let span_id = [0xAA; 8];
+        let record = LogRecord {
+            trace_id: trace_id.to_vec(),
+            span_id: span_id.to_vec(),
+            flags: 0x0000_0301,
+            ..Default::default()
+        };
+        let encoded = record.encode_to_vec();
+        let expected = TraceContext { trace_id: Some(trace_id), span_id: Some(span_id), flags: 0x01 };
+
+        let mut eager = otlp_bytes::LogRecord::new();
+        assert!(eager.parse(&encoded));
+        assert_eq!(eager.trace_context(), expected);
+        let lazy = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+        assert_eq!(lazy.trace_context(), expected);
+        assert!(expected.is_sampled());
+
+        // Wrong-length and all-zero ids are dropped; absent flags read as 0
+        let bad = LogRecord { trace_id: vec![1; 15], span_id: vec![0; 8], ..Default::default() }.encode_to_vec();
+        assert!(eager.parse(&bad));
+        assert_eq!(eager.trace_context(), TraceContext::default());
+        assert_eq!(otlp_bytes_lazy::LogRecordParser::new(&bad).trace_context(), TraceContext::default());
+    }
+
+    #[test]
+    fn test_dedup_records() {
+        let logs = create_test_logs();
+        let request = logs.resource_logs[0].scope_logs[0].log_records[0].clone();
+        let error = logs.resource_logs[0].scope_logs[0].log_records[1].clone();
+        let later = LogRecord { time_unix_nano: request.time_unix_nano + 1_000, ..request.clone() };
+        let scope = ScopeLogs {
+            log_records: vec![request.clone(), later, request.clone(), error, request],
+            ..Default::default()
+        };
+
+        let runs: Vec<(String, usize)> = scope.dedup_records().map(|(record, count)| (record.name().to_string(), count)).collect();
+        assert_eq!(runs, [("HTTP Request".to_string(), 3), ("HTTP Error".to_string(), 1), ("HTTP Request".to_string(), 1)]);
+        // The run is represented by its first record
+        let (first, _) = scope.dedup_records().next().unwrap();
+        assert!(std::ptr::eq(first, &scope.log_records[0]));
+
+        let encoded = scope.encode_to_vec();
+        let mut eager = otlp_bytes::ScopeLogs::new();
+        assert!(eager.parse(&encoded));
+        let counts: Vec<usize> = eager.dedup_records().map(|(_, count)| count).collect();
+        assert_eq!(counts, [3, 1, 1]);
+
+        assert_eq!(ScopeLogs::default().dedup_records().count(), 0);
+    }
+
+    #[test]
+    fn test_profiles_parser() {
+        use crate::proto::opentelemetry::proto::profiles::v1development as pprof;
+
+        let strings = ["", "samples", "count", "cpu", "nanoseconds", "main"];
+        let profile = pprof::Profile {
+            sample_type: vec![
+                pprof::ValueType { type_strindex: 1, unit_strindex: 2, ..Default::default() },
+                pprof::ValueType { type_strindex: 3, unit_strindex: 4, ..Default::default() },
+            ],
+            sample: vec![
+                pprof::Sample { value: vec![1, 10_000], timestamps_unix_nano: vec![1_700_000_000], ..Default::default() },
+                pprof::Sample { value: vec![2, 25_000], locations_length: 1, ..Default::default() },
+            ],
+            string_table: strings.iter().map(|s| s.to_string()).collect(),
+            time_nanos: 1_700_000_000,
+            duration_nanos: 10_000_000,
+            profile_id: vec![7; 16],
+            ..Default::default()
+        };
+        let data = pprof::ProfilesData {
+            resource_profiles: vec![pprof::ResourceProfiles {
+                resource: create_test_logs().resource_logs[0].resource.clone(),
+                scope_profiles: vec![pprof::ScopeProfiles {
+                    scope: Some(InstrumentationScope { name: "profiler".to_string(), ..Default::default() }),
+                    profiles: vec![profile],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        };
+        let encoded = data.encode_to_vec();
+
+        let parser = profiles::ProfilesDataParser::new(&encoded);
+        let resource = parser.resource_profiles().next().unwrap();
+        assert_eq!(resource.resource_attributes().next().unwrap().key(), Some("service.name"));
+        let scope = resource.scope_profiles().next().unwrap();
+        assert_eq!(scope.scope_name(), Some("profiler"));
+        assert_eq!(scope.schema_url(), None);
+
+        let profile = parser.profiles().next().unwrap();
+        assert_eq!(profile.string_table_len(), strings.len());
+        assert_eq!(profile.string_table().collect::<Vec<_>>(), strings);
+        assert_eq!(profile.string(5), Some("main"));
+        assert_eq!(profile.string(6), None);
+        assert_eq!(profile.string(-1), None);
+        let sample_types: Vec<_> = profile
+            .sample_types()
+            .map(|(ty, unit)| (profile.string(ty).unwrap(), profile.string(unit).unwrap()))
+            .collect();
+        assert_eq!(sample_types, [("samples", "count"), ("cpu", "nanoseconds")]);
+
+        assert_eq!(profile.sample_count(), 2);
+        let samples: Vec<_> = profile.samples().collect();
+        assert_eq!(samples[0].values(), [1, 10_000]);
+        assert_eq!(samples[0].timestamps_unix_nano(), [1_700_000_000]);
+        assert_eq!(samples[1].values(), [2, 25_000]);
+        assert_eq!(samples[1].locations_length(), 1);
+        assert_eq!(profile.time_nanos(), 1_700_000_000);
+        assert_eq!(profile.duration_nanos(), 10_000_000);
+        assert_eq!(profile.profile_id(), Some(&[7; 16][..]));
+
+        // Unpacked repeated values decode the same as packed ones
+        let mut unpacked = Vec::new();
+        prost::encoding::int64::encode(3, &4, &mut unpacked);
+        prost::encoding::int64::encode(3, &-1, &mut unpacked);
+        assert_eq!(profiles::SampleParser::new(&unpacked).values(), [4, -1]);
+    }
+
+    #[test]
+    fn test_fold_attributes() {
+        let logs = create_test_logs();
+        let sum_ints = |total: i64, attr: &KeyValue| total + attr.value().and_then(|v| v.as_int64()).unwrap_or(0);
+
+        for record in logs.resource_logs.iter().flat_map(|r| &r.scope_logs).flat_map(|s| &s.log_records) {
+            let expected: i64 = record.attributes.iter().filter_map(|kv| kv.value.as_ref()?.as_int64()).sum();
+            assert_eq!(record.fold_attributes(0, sum_ints), expected);
+        }
+        let request = &logs.resource_logs[0].scope_logs[0].log_records[0];
+        assert_eq!(request.fold_attributes(0, sum_ints), 200);
+
+        // Same fold through the eager parser, collecting keys instead
+        let encoded = encode_logs_data(&logs);
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(parsed.parse(&encoded));
+        let record = parsed.resources().next().unwrap().scopes().next().unwrap().log_records().next().unwrap();
+        let keys = record.fold_attributes(Vec::new(), |mut keys, attr| {
+            keys.push(attr.key());
+            keys
+        });
+        assert_eq!(keys, ["method", "status_code", "response_time_ms", "success"]);
+    }
+
+    #[test]
+    fn test_strict_structure_validation() {
+        use otlp_bytes_lazy::{validate_structure, LogsDataParser, StructureError, MAX_STRUCTURE_DEPTH};
+
+        let encoded = encode_logs_data(&create_test_logs());
+        assert_eq!(validate_structure(&encoded), Ok(()));
+        assert!(LogsDataParser::new_strict(&encoded).is_ok());
+
+        // Resource A holds one ScopeLogs whose declared length claims 5 more
+        // bytes than A has, reaching into sibling resource B
+        let scope = ScopeLogs {
+            scope: Some(InstrumentationScope { name: "a".to_string(), ..Default::default() }),
+            ..Default::default()
+        }
+        .encode_to_vec();
+        let mut resource_a = vec![0x12, (scope.len() + 5) as u8];
+        resource_a.extend_from_slice(&scope);
+        let resource_b = ResourceLogs { schema_url: "https://b".to_string(), ..Default::default() }.encode_to_vec();
+        let mut crafted = vec![0x0A, resource_a.len() as u8];
+        crafted.extend_from_slice(&resource_a);
+        crafted.push(0x0A);
+        crafted.push(resource_b.len() as u8);
+        crafted.extend_from_slice(&resource_b);
+
+        // The lenient parser stops at the bad field without complaint
+        let lenient = LogsDataParser::new(&crafted);
+        let resources: Vec<_> = lenient.resource_logs().collect();
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[0].scope_logs().count(), 0);
+
+        let err = LogsDataParser::new_strict(&crafted).err().unwrap();
+        assert_eq!(err, StructureError::Overlap { offset: 2, end: 4 + scope.len() + 5, parent_end: 2 + resource_a.len() });
+        assert!(err.to_string().contains("past its parent's end"));
+
+        assert_eq!(validate_structure(&[0x0F]), Err(StructureError::Malformed { offset: 0 }));
+        // A bad field after good ones is reported at its own offset
+        let mut bad_tail = encoded.clone();
+        bad_tail.push(0x0F);
+        assert_eq!(validate_structure(&bad_tail), Err(StructureError::Malformed { offset: encoded.len() }));
+
+        let mut deep = AnyValue::default();
+        for _ in 0..MAX_STRUCTURE_DEPTH {
+            deep = AnyValue { value: Some(any_value::Value::ArrayValue(ArrayValue { values: vec![deep] })) };
+        }
+        let mut deep_logs = create_test_logs();
+        deep_logs.resource_logs[0].scope_logs[0].log_records[0].body = Some(deep);
+        assert!(matches!(validate_structure(&encode_logs_data(&deep_logs)), Err(StructureError::TooDeep { .. })));
+    }
+
+    #[test]
+    fn test_all_scopes() {
+        let logs = create_test_logs();
+        let pairs: Vec<(Option<String>, String)> = logs
+            .all_scopes()
+            .map(|(resource, scope)| (resource.resource_info().service_name().map(str::to_string), scope.scope().to_string()))
+            .collect();
+        let expected = [
+            (Some("web-server".to_string()), "http-handler".to_string()),
+            (Some("web-server".to_string()), "database-connector".to_string()),
+            (Some("background-worker".to_string()), "job-processor".to_string()),
+        ];
+        assert_eq!(pairs, expected);
+
+        let encoded = encode_logs_data(&logs);
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(parsed.parse(&encoded));
+        let scope_names: Vec<&str> = parsed.all_scopes().map(|(_, scope)| scope.scope()).collect();
+        assert_eq!(scope_names, ["http-handler", "database-connector", "job-processor"]);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_parse_from_mmap() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let path = std::env::temp_dir().join(format!("otlp-mmap-test-{}.bin", std::process::id()));
+        std::fs::write(&path, &encoded).unwrap();
+
+        // SAFETY: the file is private to this test and not modified while mapped
+        let mapped = unsafe { otlp_bytes_lazy::LogsDataParser::from_mmap(&path) }.unwrap();
+        assert_eq!(mapped.as_bytes(), &encoded[..]);
+        let parser = mapped.parser();
+        let resources: Vec<_> = parser.resource_logs().collect();
+        assert_eq!(resources.len(), 2);
+        let records: usize = resources.iter().map(|r| r.scope_logs().map(|s| s.log_records().count()).sum::<usize>()).sum();
+        assert_eq!(records, 4);
+
+        drop(mapped);
+        std::fs::remove_file(&path).unwrap();
+        // SAFETY: nothing is mapped; opening the removed file fails first
+        assert!(unsafe { otlp_bytes_lazy::LogsDataParser::from_mmap(&path) }.is_err());
+    }
+
+    #[test]
+    fn test_dropped_attributes_count() {
+        let mut logs = create_test_logs();
+        logs.resource_logs[0].resource.as_mut().unwrap().dropped_attributes_count = 3;
+        logs.resource_logs[0].scope_logs[0].scope.as_mut().unwrap().dropped_attributes_count = 2;
+        logs.resource_logs[0].scope_logs[0].log_records[0].dropped_attributes_count = 5;
+
+        fn counts<'a, L: LogsView<'a>>(logs: &'a L) -> (u32, u32, u32, u32) {
+            let resource = logs.resources().next().unwrap();
+            let scope = resource.scopes().next().unwrap();
+            let mut records = scope.log_records();
+            let first = records.next().unwrap().dropped_attributes_count();
+            let second = records.next().unwrap().dropped_attributes_count();
+            (resource.dropped_attributes_count(), scope.dropped_attributes_count(), first, second)
+        }
+
+        assert_eq!(counts(&logs), (3, 2, 5, 0));
+
+        let encoded = encode_logs_data(&logs);
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(parsed.parse(&encoded));
+        assert_eq!(counts(&parsed), (3, 2, 5, 0));
+
+        // Unset counts are omitted on the wire and read back as 0
+        let encoded = encode_logs_data(&create_test_logs());
+        assert!(parsed.parse(&encoded));
+        assert_eq!(counts(&parsed), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_lazy_iterator_count_matches_manual_count() {
+        fn manual<I: Iterator>(iter: I) -> usize {
+            let mut n = 0;
+            for _ in iter {
+                n += 1;
+            }
+            n
+        }
+
+        let encoded = encode_logs_data(&create_test_logs());
+        let parser = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let mut checked = 0;
+        for resource in parser.resource_logs() {
+            assert_eq!(resource.scope_logs().count(), manual(resource.scope_logs()));
+            let attributes = resource.attributes().unwrap();
+            assert_eq!(attributes.size_hint().1, Some(manual(resource.attributes().unwrap())));
+            assert_eq!(attributes.count(), manual(resource.attributes().unwrap()));
+            for scope in resource.scope_logs() {
+                assert_eq!(scope.log_records().count(), manual(scope.log_records()));
+                for record in scope.log_records() {
+                    assert_eq!(record.attributes().count(), manual(record.attributes()));
+                    checked += 1;
+                }
+            }
+        }
+        assert_eq!(checked, 4);
+
+        // Partially consumed iterators count only what is left
+        let resource = parser.resource_logs().next().unwrap();
+        let scope = resource.scope_logs().next().unwrap();
+        let mut records = scope.log_records();
+        assert_eq!(records.size_hint(), (2, Some(2)));
+        records.next();
+        assert_eq!(records.size_hint(), (1, Some(1)));
+        assert_eq!(records.count(), 1);
+        let record = scope.log_records().next().unwrap();
+        let mut attributes = record.attributes();
+        assert_eq!(attributes.size_hint(), (4, Some(4)));
+        attributes.next();
+        assert_eq!(attributes.count(), 3);
+    }
+
+    #[test]
+    fn test_redact_attributes() {
+        let encoded = encode_logs_data(&create_test_logs());
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(parsed.parse(&encoded));
+
+        let deny: HashSet<&str> = ["error_message", "user.email"].into_iter().collect();
+        parsed.redact_attributes(&deny);
+
+        let reencoded = parsed.encode().unwrap();
+        let logs = LogsData::decode(&reencoded[..]).unwrap();
+        let error = &logs.resource_logs[0].scope_logs[0].log_records[1];
+        let keys: Vec<&str> = error.attributes.iter().map(|attr| attr.key.as_str()).collect();
+        assert_eq!(keys, ["method", "status_code"]);
+        assert!(logs
+            .resource_logs
+            .iter()
+            .flat_map(|r| &r.scope_logs)
+            .flat_map(|s| &s.log_records)
+            .all(|record| record.attributes.iter().all(|attr| attr.key != "error_message")));
+        assert_eq!(logs.resource_logs[0].scope_logs[0].log_records[0].attributes.len(), 4);
+
+        // A single record redacts duplicates too, and the borrowed input is kept
+        let mut duplicated = create_test_logs().resource_logs[0].scope_logs[0].log_records[1].clone();
+        duplicated.attributes.push(duplicated.attributes[2].clone());
+        let bytes = duplicated.encode_to_vec();
+        let mut record = otlp_bytes::LogRecord::new();
+        assert!(record.parse(&bytes));
+        otlp_bytes::redact_attributes(&mut record, &deny);
+        assert_eq!(record.to_prost().attributes.len(), 2);
+        assert_eq!(record.attribute_count(), 2);
+        assert!(LogRecordView::attributes(&record).all(|attr| !deny.contains(attr.key())));
+        assert!(util::flatten_attributes(&record).iter().all(|(key, _)| !deny.contains(key.as_str())));
+
+        // Overrides for denied keys are dropped as well
+        assert!(record.parse(&bytes));
+        record.set_attribute_override("user.email", AnyValue { value: Some(any_value::Value::StringValue("a@b.c".into())) });
+        otlp_bytes::redact_attributes(&mut record, &deny);
+        assert!(record.effective_attributes().all(|attr| !deny.contains(attr.key())));
+    }
+
+    #[test]
+    fn test_resource_ranges() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+        let parser = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let ranges = parser.resource_ranges();
+        assert_eq!(ranges.len(), logs.resource_logs.len());
+
+        for (range, expected) in ranges.iter().zip(&logs.resource_logs) {
+            // Each range is exactly the encoded ResourceLogs, framing excluded
+            assert_eq!(&encoded[range.clone()], &expected.encode_to_vec()[..]);
+        }
+        // Ranges are in order and separated only by each field's framing
+        assert!(ranges.windows(2).all(|pair| pair[0].end < pair[1].start));
+        assert_eq!(ranges.last().unwrap().end, encoded.len());
+
+        let services: Vec<_> = ranges
+            .iter()
+            .map(|range| {
+                let resource = otlp_bytes_lazy::ResourceLogsParser::new(&encoded[range.clone()]);
+                let records: usize = resource.scope_logs().map(|scope| scope.log_records().count()).sum();
+                (resource.service_name().map(str::to_string), records)
+            })
+            .collect();
+        assert_eq!(
+            services,
+            [(Some("web-server".to_string()), 3), (Some("background-worker".to_string()), 1)]
+        );
+    }
+
+    #[test]
+    fn test_validated_string_cache() {
+        let logs = create_test_logs();
+        let encoded = logs.resource_logs[0].scope_logs[0].log_records[1].encode_to_vec();
+        let plain = otlp_bytes_lazy::LogRecordParser::new(&encoded);
+        let validated = otlp_bytes_lazy::LogRecordParser::new(&encoded).with_validated_strings();
+        for _ in 0..3 {
+            assert_eq!(validated.severity_text(), plain.severity_text());
+            assert_eq!(validated.event_name(), Some("HTTP Error"));
+        }
+        assert_eq!(validated.severity_text(), Some("ERROR"));
+
+        // severity_text (tag 3) is not UTF-8; event_name (tag 12) is
+        let invalid = [0x1a, 0x02, 0xff, 0xfe, 0x62, 0x03, b'e', b'v', b't'];
+        let record = otlp_bytes_lazy::LogRecordParser::new(&invalid).with_validated_strings();
+        assert_eq!(record.severity_text(), None);
+        assert_eq!(record.severity_text(), None);
+        assert_eq!(record.event_name(), Some("evt"));
+        assert_eq!(record.field_wire_type(3), Some(2));
+
+        // Projected-out fields stay absent
+        let projected = otlp_bytes_lazy::LogRecordParser::with_projection(&encoded, otlp_bytes_lazy::ProjectionMask::TIME_UNIX_NANO)
+            .with_validated_strings();
+        assert_eq!(projected.severity_text(), None);
+
+        // The validated strings survive a later mode change, which still
+        // takes effect for the rest of the cache
+        let mut mistyped = Vec::new();
+        prost::encoding::uint64::encode(1, &1_700_000_000, &mut mistyped);
+        prost::encoding::string::encode(3, &"WARN".to_string(), &mut mistyped);
+        let strict = otlp_bytes_lazy::LogRecordParser::new(&mistyped)
+            .with_validated_strings()
+            .with_mode(otlp_bytes_lazy::ParseMode::Strict);
+        assert_eq!(strict.severity_text(), Some("WARN"));
+        assert_eq!(strict.errors().len(), 1);
+    }
+
+    #[test]
+    fn test_erased_logs_view() {
+        use erased::{parse_logs, Backend, BoxedLogs, ErasedLogsView};
+        use util::ScalarValue;
+
+        fn summarize(logs: &dyn ErasedLogsView) -> Vec<(String, String, String, i32, Option<String>, usize)> {
+            let mut rows = Vec::new();
+            for resource in logs.resources() {
+                for scope in resource.scopes() {
+                    for record in scope.log_records() {
+                        rows.push((
+                            resource.resource().to_string(),
+                            scope.scope().to_string(),
+                            record.name().to_string(),
+                            record.severity_number(),
+                            record.body_str().map(str::to_string),
+                            record.attributes().len(),
+                        ));
+                    }
+                }
+            }
+            rows
+        }
+
+        let encoded = encode_logs_data(&create_test_logs());
+        let prost = parse_logs(Backend::Prost, &encoded).unwrap();
+        let eager = parse_logs(Backend::Eager, &encoded).unwrap();
+        let lazy = parse_logs(Backend::Lazy, &encoded).unwrap();
+        assert!(matches!(prost, BoxedLogs::Prost(_)));
+        assert!(matches!(eager, BoxedLogs::Eager(_)));
+
+        let expected = summarize(prost.as_view());
+        assert_eq!(expected.len(), 4);
+        assert_eq!(expected[0].0, "web-server");
+        assert_eq!(expected[0].1, "http-handler");
+        assert_eq!(expected[0].2, "HTTP Request");
+        assert_eq!(expected[3].0, "background-worker");
+        assert_eq!(summarize(eager.as_view()), expected);
+        assert_eq!(summarize(lazy.as_view()), expected);
+
+        // Picked at runtime, all backends agree on attribute values
+        for backend in [Backend::Prost, Backend::Eager, Backend::Lazy] {
+            let logs = parse_logs(backend, &encoded).unwrap();
+            let resource = logs.as_view().resources().next().unwrap();
+            let scope = resource.scopes().next().unwrap();
+            let record = scope.log_records().next().unwrap();
+            assert!(record
+                .attributes()
+                .contains(&("status_code".to_string(), ScalarValue::Int(200))));
+        }
+
+        assert!(parse_logs(Backend::Eager, &[0x0a, 0x05, 0x01]).is_err());
+        assert!(parse_logs(Backend::Prost, &[0x0a, 0x05, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_summarize() {
+        let logs = create_test_logs();
+        let summary = logs.summarize();
+        assert_eq!(summary.resource_count(), 2);
+        assert_eq!(summary.scope_count(), 3);
+        assert_eq!(summary.record_count, 4);
+        assert_eq!(summary.records_per_resource, [3, 1]);
+        assert_eq!(summary.records_per_scope, [2, 1, 1]);
+        assert_eq!(summary.min_timestamp, Some(1718380800000000000));
+        assert_eq!(summary.max_timestamp, Some(1718380803000000000));
+        // INFO (9) twice, ERROR (13) and DEBUG (5) once
+        let severities: Vec<_> = summary.severity_counts.iter().map(|(&sev, &count)| (sev, count)).collect();
+        assert_eq!(severities, [(5, 1), (9, 2), (13, 1)]);
+
+        let encoded = encode_logs_data(&logs);
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(parsed.parse(&encoded));
+        assert_eq!(parsed.summarize(), summary);
+
+        let empty = LogsData { resource_logs: vec![] }.summarize();
+        assert_eq!(empty, LogsStats::default());
+        assert_eq!(empty.min_timestamp, None);
+    }
+
+    #[test]
+    fn test_kvlist_get() {
+        let kvlist = kvlist(vec![
+            entry("user", Some(string("alice"))),
+            entry("retries", Some(int(3))),
+            entry("user", Some(string("shadowed"))),
+            entry("unset", None),
+        ]);
+
+        assert_eq!(kvlist.kvlist_get("user").and_then(|v| v.as_string()), Some("alice"));
+        assert_eq!(kvlist.kvlist_get("retries").and_then(|v| v.as_int64()), Some(3));
+        assert!(kvlist.kvlist_get("missing").is_none());
+        assert!(kvlist.kvlist_get("unset").is_none());
+        assert!(string("flat").kvlist_get("user").is_none());
+
+        let encoded = kvlist.encode_to_vec();
+        let lazy = otlp_bytes_lazy::AnyValueParser::new(&encoded);
+        assert_eq!(lazy.kvlist_get("user").and_then(|v| v.string_value()), Some("alice"));
+        assert_eq!(lazy.kvlist_get("retries").and_then(|v| v.int_value()), Some(3));
+        assert!(lazy.kvlist_get("missing").is_none());
+        assert!(lazy.kvlist_get("unset").is_none());
+
+        let mut eager = otlp_bytes::AnyValue::new();
+        assert!(eager.parse(&encoded));
+        assert_eq!(eager.kvlist_get("user").and_then(|v| v.as_string()), Some("alice"));
+        assert!(eager.kvlist_get("missing").is_none());
+    }
+
+    #[test]
+    fn test_time_unix_nano_checked() {
+        use otlp_bytes_lazy::{LogRecordParser, ParseError};
+
+        // Field 1 declared fixed64, but only 4 bytes follow the tag
+        let truncated = [0x09, 0x01, 0x02, 0x03, 0x04];
+        let record = LogRecordParser::new(&truncated);
+        assert_eq!(
+            record.time_unix_nano_checked(),
+            Err(ParseError::Truncated { tag: 1, offset: 1, needed: 8, available: 4 })
+        );
+        // The unchecked accessor still reads the corruption as unset
+        assert_eq!(record.time_unix_nano(), 0);
+
+        let logs = create_test_logs();
+        let encoded = logs.resource_logs[0].scope_logs[0].log_records[0].encode_to_vec();
+        let record = LogRecordParser::new(&encoded);
+        assert_eq!(record.time_unix_nano_checked(), Ok(Some(1718380800000000000)));
+        assert_eq!(record.observed_time_unix_nano_checked(), Ok(Some(1718380800000000000)));
+
+        assert_eq!(LogRecordParser::new(&[]).time_unix_nano_checked(), Ok(None));
+        // time_unix_nano sent as a varint
+        let varint = [0x08, 0x05];
+        assert_eq!(
+            LogRecordParser::new(&varint).time_unix_nano_checked(),
+            Err(ParseError::WrongWireType { tag: 1, expected: 1, actual: 0 })
+        );
+    }
+
+    #[test]
+    fn test_rebuild_filtered() {
+        let logs = create_test_logs();
+        let is_error = |text: Option<&str>| text == Some("ERROR");
+
+        let rebuilt = logs.rebuild_filtered(|record| is_error(record.severity_text()));
+        assert_eq!(rebuilt.resource_logs.len(), 1);
+        let resource = &rebuilt.resource_logs[0];
+        assert_eq!(resource.resource, logs.resource_logs[0].resource);
+        assert_eq!(resource.scope_logs.len(), 1);
+        let scope = &resource.scope_logs[0];
+        assert_eq!(scope.scope, logs.resource_logs[0].scope_logs[0].scope);
+        assert_eq!(scope.log_records, [logs.resource_logs[0].scope_logs[0].log_records[1].clone()]);
+
+        let encoded = encode_logs_data(&logs);
+        let mut parsed = otlp_bytes::LogsData::new();
+        assert!(parsed.parse(&encoded));
+        assert_eq!(parsed.rebuild_filtered(|record| is_error(record.severity_text)), rebuilt);
+
+        // Keeping everything round-trips; keeping nothing leaves no empty groups
+        assert_eq!(parsed.rebuild_filtered(|_| true), logs);
+        assert!(logs.rebuild_filtered(|_| false).resource_logs.is_empty());
+    }
+
+    #[test]
+    fn test_record_count_agrees_across_backends() {
+        let logs = create_test_logs();
+        let encoded = encode_logs_data(&logs);
+
+        let prost_count = logs.summarize().record_count;
+        let mut eager = otlp_bytes::LogsData::new();
+        assert!(eager.parse(&encoded));
+        let eager_count = eager.summarize().record_count;
+        let lazy = otlp_bytes_lazy::LogsDataParser::new(&encoded);
+        let lazy_count: usize = lazy
+            .resource_logs()
+            .map(|resource| resource.scope_logs().map(|scope| scope.log_records().count()).sum::<usize>())
+            .sum();
+
+        assert_eq!(prost_count, 4);
+        assert_eq!(eager_count, prost_count);
+        assert_eq!(lazy_count, prost_count);
+        assert_eq!(lazy.collect_records().len(), prost_count);
+    }
+}
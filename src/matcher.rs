@@ -0,0 +1,89 @@
+use crate::{LogRecordView, AttributeView, AnyValueView};
+
+/// A typed value an attribute is compared against
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpectedValue {
+    String(String),
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+}
+
+impl From<&str> for ExpectedValue {
+    fn from(value: &str) -> Self {
+        ExpectedValue::String(value.to_string())
+    }
+}
+
+impl From<String> for ExpectedValue {
+    fn from(value: String) -> Self {
+        ExpectedValue::String(value)
+    }
+}
+
+impl From<bool> for ExpectedValue {
+    fn from(value: bool) -> Self {
+        ExpectedValue::Bool(value)
+    }
+}
+
+impl From<i64> for ExpectedValue {
+    fn from(value: i64) -> Self {
+        ExpectedValue::Int(value)
+    }
+}
+
+impl From<f64> for ExpectedValue {
+    fn from(value: f64) -> Self {
+        ExpectedValue::Double(value)
+    }
+}
+
+impl ExpectedValue {
+    /// Typed equality: a string never equals an int, even if it would parse as one
+    fn matches<V: AnyValueView>(&self, value: &V) -> bool {
+        match self {
+            ExpectedValue::String(expected) => value.as_string() == Some(expected.as_str()),
+            ExpectedValue::Bool(expected) => value.as_bool() == Some(*expected),
+            ExpectedValue::Int(expected) => value.as_int64() == Some(*expected),
+            ExpectedValue::Double(expected) => value.as_double() == Some(*expected),
+        }
+    }
+}
+
+/// Rule for routing records on attribute values
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeMatcher {
+    /// The attribute key is present, whatever its value
+    KeyExists(String),
+    /// The attribute key is present with a value equal to the expected one
+    Equals { key: String, value: ExpectedValue },
+}
+
+impl AttributeMatcher {
+    pub fn key_exists(key: impl Into<String>) -> Self {
+        AttributeMatcher::KeyExists(key.into())
+    }
+
+    pub fn equals(key: impl Into<String>, value: impl Into<ExpectedValue>) -> Self {
+        AttributeMatcher::Equals {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Check a single attribute against this rule
+    pub fn matches(&self, attr: &impl AttributeView) -> bool {
+        match self {
+            AttributeMatcher::KeyExists(key) => attr.key() == key,
+            AttributeMatcher::Equals { key, value } => {
+                attr.key() == key && attr.value().is_some_and(|v| value.matches(v))
+            }
+        }
+    }
+
+    /// Check whether any attribute of the record satisfies this rule
+    pub fn matches_record<'a, R: LogRecordView<'a>>(&self, record: &'a R) -> bool {
+        record.attributes().any(|attr| self.matches(attr))
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView, ValueType};
+use crate::proto::opentelemetry::proto as pb;
+use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView, EntityRefView, LogRecordFlags, TraceContext, ValueType};
+use std::borrow::{Borrow, Cow};
+use std::collections::HashSet;
+use std::fmt;
 
 /// Base protobuf parser with common functionality
 pub struct ProtobufParser<'a> {
@@ -15,6 +19,7 @@ impl<'a> ProtobufParser<'a> {
         if pos >= self.data.len() {
             return None;
         }
+        stats::record(|stats| stats.varints_parsed += 1);
         
         let byte = self.data[pos];
         pos += 1;
@@ -51,6 +56,11 @@ impl<'a> ProtobufParser<'a> {
             let byte = self.data[pos];
             pos += 1;
             
+            // The 10th byte holds only bit 63; anything more overflows u64
+            // or makes the varint longer than the 10-byte maximum
+            if shift == 63 && byte > 1 {
+                return None;
+            }
             result |= ((byte & 0x7F) as u64) << shift;
             
             if byte & 0x80 == 0 {
@@ -68,7 +78,7 @@ impl<'a> ProtobufParser<'a> {
         let (length, new_pos) = self.parse_varint(pos)?;
         pos = new_pos;
         
-        let end = pos + length as usize;
+        let end = usize::try_from(length).ok().and_then(|length| pos.checked_add(length))?;
         if end <= self.data.len() {
             Some((&self.data[pos..end], end))
         } else {
@@ -110,57 +120,287 @@ impl<'a> ProtobufParser<'a> {
         }
     }
 
-    /// Parse all occurrences of a field
-    fn parse_all_fields(&self, target_tag: u32) -> Vec<(u8, usize)> {
-        let mut results = Vec::new();
+    /// Parse a packed repeated fixed64 field (also used for packed doubles,
+    /// via `f64::from_bits`). `pos` points at the length prefix, as returned
+    /// by `find_field`. `None` if the payload is out of bounds or not a
+    /// whole number of 8-byte values.
+    pub fn parse_packed_fixed64(&self, pos: usize) -> Option<Vec<u64>> {
+        let (bytes, _) = self.parse_length_delimited(pos)?;
+        if bytes.len() % 8 != 0 {
+            return None;
+        }
+        Some(
+            bytes
+                .chunks_exact(8)
+                .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+                .collect(),
+        )
+    }
+
+    /// Parse a packed repeated fixed32 field (also used for packed floats);
+    /// see `parse_packed_fixed64`
+    pub fn parse_packed_fixed32(&self, pos: usize) -> Option<Vec<u32>> {
+        let (bytes, _) = self.parse_length_delimited(pos)?;
+        if bytes.len() % 4 != 0 {
+            return None;
+        }
+        Some(
+            bytes
+                .chunks_exact(4)
+                .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+                .collect(),
+        )
+    }
+
+    /// Walk the occurrences of a field lazily, so a caller that stops early
+    /// (e.g. at a limit) doesn't scan the rest of the message
+    fn fields(&self, target_tag: u32) -> FieldOccurrences<'_, 'a> {
+        FieldOccurrences {
+            parser: self,
+            target_tag,
+            scope_logs: false,
+            pos: 0,
+            done: false,
+        }
+    }
+
+    /// Walk the ScopeLogs fields of a ResourceLogs, under every tag
+    /// `is_scope_logs_tag` accepts, in wire order
+    fn scope_logs_fields(&self) -> FieldOccurrences<'_, 'a> {
+        FieldOccurrences {
+            scope_logs: true,
+            ..self.fields(2)
+        }
+    }
+
+    /// Find first occurrence of a field by tag number
+    fn find_field(&self, target_tag: u32) -> Option<(u8, usize)> {
+        self.fields(target_tag).next()
+    }
+
+    /// Find the last occurrence of a field by tag number. Protobuf (and
+    /// prost) let the last occurrence of a repeated singular field win, so
+    /// singular scalars are read with this rather than `find_field`.
+    fn find_last_field(&self, target_tag: u32) -> Option<(u8, usize)> {
+        self.fields(target_tag).last()
+    }
+
+    /// Check that the top-level fields tile the buffer exactly: every tag,
+    /// length and fixed-width value is in bounds and the wire types are known
+    fn is_well_formed(&self) -> bool {
         let mut pos = 0;
-        
         while pos < self.data.len() {
-            if let Some((tag_and_wire, new_pos)) = self.parse_varint(pos) {
-                pos = new_pos;
-                
-                let tag = (tag_and_wire >> 3) as u32;
-                let wire_type = (tag_and_wire & 0x7) as u8;
-                
-                if tag == target_tag {
-                    results.push((wire_type, pos));
-                }
-                
-                // Skip field based on wire type
-                pos = match wire_type {
-                    0 => {
-                        if let Some((_, new_pos)) = self.parse_varint(pos) {
-                            new_pos
-                        } else {
-                            break;
-                        }
-                    },
-                    1 => {
-                        if pos + 8 <= self.data.len() { pos + 8 } else { break; }
-                    },
-                    2 => {
-                        if let Some((_, new_pos)) = self.parse_length_delimited(pos) {
-                            new_pos
-                        } else {
-                            break;
-                        }
-                    },
-                    5 => {
-                        if pos + 4 <= self.data.len() { pos + 4 } else { break; }
-                    },
-                    _ => break,
-                };
-            } else {
+            let Some((tag_and_wire, new_pos)) = self.parse_varint(pos) else {
+                return false;
+            };
+            pos = new_pos;
+            let next = match (tag_and_wire & 0x7) as u8 {
+                0 => self.parse_varint(pos).map(|(_, p)| p),
+                1 => Some(pos + 8).filter(|&p| p <= self.data.len()),
+                2 => self.parse_length_delimited(pos).map(|(_, p)| p),
+                5 => Some(pos + 4).filter(|&p| p <= self.data.len()),
+                _ => None,
+            };
+            match next {
+                Some(next) => pos = next,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Iterator over the `(wire_type, value offset)` of each occurrence of a
+/// field, from `ProtobufParser::fields`. Stops at the first malformed field.
+struct FieldOccurrences<'p, 'a> {
+    parser: &'p ProtobufParser<'a>,
+    target_tag: u32,
+    /// Also match every tag `is_scope_logs_tag` does
+    scope_logs: bool,
+    pos: usize,
+    done: bool,
+}
+
+impl Iterator for FieldOccurrences<'_, '_> {
+    type Item = (u8, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.parser.data;
+        while !self.done && self.pos < data.len() {
+            let Some((tag_and_wire, value_pos)) = self.parser.parse_varint(self.pos) else {
+                self.done = true;
                 break;
+            };
+            self.pos = value_pos;
+
+            let tag = (tag_and_wire >> 3) as u32;
+            let wire_type = (tag_and_wire & 0x7) as u8;
+            let matched = tag == self.target_tag || (self.scope_logs && is_scope_logs_tag(tag));
+            if !matched && wire_type == 2 {
+                stats::record(|stats| stats.length_delimited_skipped += 1);
+            }
+
+            // Skip field based on wire type
+            let next = match wire_type {
+                0 => self.parser.parse_varint(value_pos).map(|(_, p)| p),
+                1 => Some(value_pos + 8).filter(|&p| p <= data.len()),
+                2 => self.parser.parse_length_delimited(value_pos).map(|(_, p)| p),
+                5 => Some(value_pos + 4).filter(|&p| p <= data.len()),
+                _ => None,
+            };
+            match next {
+                Some(next) => self.pos = next,
+                None => self.done = true,
+            }
+
+            if matched {
+                return Some((wire_type, value_pos));
             }
         }
-        
-        results
+        None
     }
+}
 
-    /// Find first occurrence of a field by tag number
-    fn find_field(&self, target_tag: u32) -> Option<(u8, usize)> {
-        self.parse_all_fields(target_tag).into_iter().next()
+impl Drop for FieldOccurrences<'_, '_> {
+    fn drop(&mut self) {
+        let scanned = self.pos.min(self.parser.data.len());
+        stats::record(|stats| stats.bytes_scanned += scanned);
+    }
+}
+
+/// Limits enforced by `LogsData::parse_with_limits` to bound memory and work
+/// on untrusted input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximum attributes kept per message; also bounds kvlist and array
+    /// entries and a resource's entity refs
+    pub max_attributes: usize,
+    /// Maximum nesting depth of array/kvlist values (a top-level value is depth 0)
+    pub max_depth: usize,
+    /// Maximum number of input bytes consumed. Checked per log record:
+    /// records ending past it are dropped, along with any resource or
+    /// scope starting past it.
+    pub max_total_bytes: usize,
+    /// What to do when a limit is hit
+    pub policy: LimitPolicy,
+}
+
+impl ParseLimits {
+    pub const UNLIMITED: ParseLimits = ParseLimits {
+        max_attributes: usize::MAX,
+        max_depth: usize::MAX,
+        max_total_bytes: usize::MAX,
+        policy: LimitPolicy::Truncate,
+    };
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+/// Behavior when a `ParseLimits` limit is hit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitPolicy {
+    /// Drop the excess data and keep parsing
+    Truncate,
+    /// Stop parsing and report the limit as an error
+    Error,
+}
+
+/// The limit that stopped or truncated a parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitExceeded {
+    Attributes,
+    Depth,
+    TotalBytes,
+}
+
+/// Tracks limit state while descending through a single parse
+struct LimitTracker<'l> {
+    limits: &'l ParseLimits,
+    depth: usize,
+    exceeded: Option<LimitExceeded>,
+    /// Address of the top-level input, so nested messages (which are
+    /// subslices of it) can be checked against `max_total_bytes`
+    input_start: usize,
+}
+
+impl<'l> LimitTracker<'l> {
+    fn new(limits: &'l ParseLimits) -> Self {
+        Self {
+            limits,
+            depth: 0,
+            exceeded: None,
+            input_start: 0,
+        }
+    }
+
+    /// Record a limit hit, keeping the first one seen
+    fn exceed(&mut self, limit: LimitExceeded) {
+        self.exceeded.get_or_insert(limit);
+    }
+
+    /// Whether a limit hit should stop the whole parse
+    fn aborted(&self) -> bool {
+        self.exceeded.is_some() && self.limits.policy == LimitPolicy::Error
+    }
+
+    /// Check whether `count` entries are already at the attribute limit
+    fn attributes_full(&mut self, count: usize) -> bool {
+        if count >= self.limits.max_attributes {
+            self.exceed(LimitExceeded::Attributes);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Check that `message`, a subslice of the top-level input, ends within
+    /// `max_total_bytes` of the input's start. Used for log records and
+    /// entity refs, which are kept whole or not at all.
+    fn within_total_bytes(&mut self, message: &[u8]) -> bool {
+        let (_, end) = self.input_range(message);
+        if end > self.limits.max_total_bytes {
+            self.exceed(LimitExceeded::TotalBytes);
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Whether to descend into `message`, a resource or scope: it must start
+    /// within `max_total_bytes`. One that ends past the budget records the
+    /// limit, but under `LimitPolicy::Truncate` is still entered so the
+    /// records that fit are kept.
+    fn enter_within_total_bytes(&mut self, message: &[u8]) -> bool {
+        let (start, end) = self.input_range(message);
+        if end > self.limits.max_total_bytes {
+            self.exceed(LimitExceeded::TotalBytes);
+        }
+        start < self.limits.max_total_bytes && !self.aborted()
+    }
+
+    /// Byte range of `message` within the top-level input
+    fn input_range(&self, message: &[u8]) -> (usize, usize) {
+        let start = (message.as_ptr() as usize).saturating_sub(self.input_start);
+        (start, start.saturating_add(message.len()))
+    }
+
+    /// Descend into an array/kvlist, returning false if it is too deep
+    fn enter_nested(&mut self) -> bool {
+        if self.depth >= self.limits.max_depth {
+            self.exceed(LimitExceeded::Depth);
+            false
+        } else {
+            self.depth += 1;
+            true
+        }
+    }
+
+    fn exit_nested(&mut self) {
+        self.depth -= 1;
     }
 }
 
@@ -168,6 +408,17 @@ impl<'a> ProtobufParser<'a> {
 pub struct LogsData<'a> {
     pub resource_logs: Vec<ResourceLogs<'a>>,
     pub used_count: usize,
+    /// Limit that truncated the last `parse_with_limits` call, if any
+    pub limit_exceeded: Option<LimitExceeded>,
+    /// Offset of the top-level field where the last parse stopped because
+    /// the message was malformed, if it did
+    pub error_offset: Option<usize>,
+}
+
+impl<'a> Default for LogsData<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> LogsData<'a> {
@@ -175,24 +426,73 @@ impl<'a> LogsData<'a> {
         Self {
             resource_logs: Vec::new(),
             used_count: 0,
+            limit_exceeded: None,
+            error_offset: None,
         }
     }
 
+    /// Number of ResourceLogs from the latest parse. `resource_logs.len()`
+    /// also counts slots kept from earlier, larger parses.
+    pub fn resource_logs_count(&self) -> usize {
+        self.used_count
+    }
+
     pub fn clear(&mut self) {
         // Clear nested structures while preserving their capacity
         // for resource_log in &mut self.resource_logs[..self.used_count] {
         //     resource_log.clear();
         // }
         self.used_count = 0;
+        self.limit_exceeded = None;
+        self.error_offset = None;
+    }
+
+    /// Byte offset of the field the last parse gave up on, for locating the
+    /// corruption in a truncated or damaged buffer. `None` after a clean
+    /// parse; resources parsed before that point are still available.
+    pub fn parse_error_offset(&self) -> Option<usize> {
+        self.error_offset
+    }
+
+    /// Fully clear every preallocated slot, not just the used prefix, so no
+    /// stale values survive into a later parse. Capacity is preserved.
+    pub fn reset(&mut self) {
+        for resource_log in &mut self.resource_logs {
+            resource_log.reset();
+        }
+        self.clear();
     }
 
+    /// Parse `data`, returning `false` only if the top-level message is
+    /// malformed (truncated field, bad length, unknown wire type). A valid
+    /// message with no resource logs parses successfully as empty.
     pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.parse_limited(data, &mut LimitTracker::new(&ParseLimits::UNLIMITED))
+    }
+
+    /// Parse while enforcing `limits`. Under `LimitPolicy::Error` the first
+    /// limit hit aborts the parse and is returned as the error (leaving a
+    /// partial parse behind); under `LimitPolicy::Truncate` the excess is
+    /// dropped and the hit is recorded in `limit_exceeded`.
+    pub fn parse_with_limits(&mut self, data: &'a [u8], limits: &ParseLimits) -> Result<bool, LimitExceeded> {
+        let mut tracker = LimitTracker::new(limits);
+        let parsed = self.parse_limited(data, &mut tracker);
+        self.limit_exceeded = tracker.exceeded;
+        match tracker.exceeded {
+            Some(limit) if limits.policy == LimitPolicy::Error => Err(limit),
+            _ => Ok(parsed),
+        }
+    }
+
+    fn parse_limited(&mut self, data: &'a [u8], tracker: &mut LimitTracker) -> bool {
         self.clear();
+        tracker.input_start = data.as_ptr() as usize;
         
         let parser = ProtobufParser::new(data);
         let mut pos = 0;
         
         while pos < data.len() {
+            let field_start = pos;
             if let Some((tag_and_wire, new_pos)) = parser.parse_varint(pos) {
                 pos = new_pos;
                 
@@ -201,6 +501,10 @@ impl<'a> LogsData<'a> {
                 
                 if tag == 1 && wire_type == 2 {
                     if let Some((bytes, end_pos)) = parser.parse_length_delimited(pos) {
+                        if !tracker.enter_within_total_bytes(bytes) {
+                            break;
+                        }
+
                         // Reuse existing ResourceLogs if available
                         let resource_log = if self.used_count < self.resource_logs.len() {
                             &mut self.resource_logs[self.used_count]
@@ -209,29 +513,116 @@ impl<'a> LogsData<'a> {
                             self.resource_logs.last_mut().unwrap()
                         };
                         
-                        if resource_log.parse(bytes) {
+                        if resource_log.parse_limited(bytes, tracker) {
                             self.used_count += 1;
                         }
+                        if tracker.aborted() {
+                            break;
+                        }
                         pos = end_pos;
                     } else {
+                        self.error_offset = Some(field_start);
                         break;
                     }
                 } else {
                     // Skip unknown fields
-                    pos = match wire_type {
-                        0 => parser.parse_varint(pos).map(|(_, p)| p).unwrap_or(data.len()),
-                        1 => pos + 8,
-                        2 => parser.parse_length_delimited(pos).map(|(_, p)| p).unwrap_or(data.len()),
-                        5 => pos + 4,
-                        _ => break,
+                    let next = match wire_type {
+                        0 => parser.parse_varint(pos).map(|(_, p)| p),
+                        1 => Some(pos + 8).filter(|&p| p <= data.len()),
+                        2 => parser.parse_length_delimited(pos).map(|(_, p)| p),
+                        5 => Some(pos + 4).filter(|&p| p <= data.len()),
+                        _ => None,
                     };
+                    match next {
+                        Some(next) => pos = next,
+                        None => {
+                            self.error_offset = Some(field_start);
+                            break;
+                        }
+                    }
                 }
             } else {
+                self.error_offset = Some(field_start);
                 break;
             }
         }
 
-        self.used_count > 0
+        stats::record(|stats| {
+            stats.bytes_scanned += pos.min(data.len());
+            stats.bytes_visited = pos.min(data.len());
+        });
+        self.error_offset.is_none()
+    }
+
+    /// Iterate the used ResourceLogs on rayon's thread pool, for fanning
+    /// CPU-bound processing out across resources. Only the eager backend
+    /// offers this: its parsed resources are plain shared data, while the
+    /// lazy parser's per-record caches are not `Sync`.
+    #[cfg(feature = "rayon")]
+    pub fn par_resources(&self) -> impl rayon::iter::IndexedParallelIterator<Item = &ResourceLogs<'a>> {
+        use rayon::iter::IntoParallelRefIterator;
+        self.resource_logs[..self.used_count].par_iter()
+    }
+
+    /// Parse like `parse`, also returning counters describing the work the
+    /// parse did. Counting is compiled in only with the `parse_stats`
+    /// feature.
+    #[cfg(feature = "parse_stats")]
+    pub fn parse_with_stats(&mut self, data: &'a [u8]) -> (bool, ParseStats) {
+        stats::reset();
+        let parsed = self.parse(data);
+        (parsed, stats::reset())
+    }
+}
+
+/// Work counters from `LogsData::parse_with_stats`, available with the
+/// `parse_stats` feature
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseStats {
+    /// Varints decoded, including tags, lengths and values, and counting
+    /// each rescan of a message
+    pub varints_parsed: usize,
+    /// Length-delimited fields jumped over while scanning for another tag
+    pub length_delimited_skipped: usize,
+    /// Bytes walked by every field scan. Messages are rescanned once per
+    /// field looked up, so this exceeds `bytes_visited`; the ratio is the
+    /// rescan overhead.
+    pub bytes_scanned: usize,
+    /// Bytes of the input the top-level walk reached: the input length
+    /// after a clean parse, less if it stopped early
+    pub bytes_visited: usize,
+}
+
+/// Per-thread counters behind `parse_with_stats`. Without the feature
+/// `record` compiles to nothing, so the parse hot path pays no cost.
+mod stats {
+    use super::ParseStats;
+    #[cfg(feature = "parse_stats")]
+    use std::cell::Cell;
+
+    #[cfg(feature = "parse_stats")]
+    thread_local! {
+        static STATS: Cell<ParseStats> = Cell::new(ParseStats::default());
+    }
+
+    #[cfg(feature = "parse_stats")]
+    #[inline]
+    pub(super) fn record(update: impl FnOnce(&mut ParseStats)) {
+        STATS.with(|cell| {
+            let mut stats = cell.get();
+            update(&mut stats);
+            cell.set(stats);
+        });
+    }
+
+    #[cfg(not(feature = "parse_stats"))]
+    #[inline(always)]
+    pub(super) fn record(_update: impl FnOnce(&mut ParseStats)) {}
+
+    /// Zero the counters, returning their previous values
+    #[cfg(feature = "parse_stats")]
+    pub(super) fn reset() -> ParseStats {
+        STATS.with(|cell| cell.take())
     }
 }
 
@@ -243,6 +634,12 @@ pub struct ResourceLogs<'a> {
     pub schema_url: Option<&'a str>,
 }
 
+impl<'a> Default for ResourceLogs<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> ResourceLogs<'a> {
     pub fn new() -> Self {
         Self {
@@ -253,6 +650,11 @@ impl<'a> ResourceLogs<'a> {
         }
     }
 
+    /// Number of ScopeLogs from the latest parse, excluding reused slots
+    pub fn scope_logs_count(&self) -> usize {
+        self.scope_logs_used
+    }
+
     pub fn clear(&mut self) {
         self.resource = None;
         // Clear nested structures while preserving capacity
@@ -263,17 +665,30 @@ impl<'a> ResourceLogs<'a> {
         self.schema_url = None;
     }
 
+    /// Fully clear this and every preallocated ScopeLogs slot
+    pub fn reset(&mut self) {
+        for scope_log in &mut self.scope_logs {
+            scope_log.reset();
+        }
+        self.clear();
+    }
+
     pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.parse_limited(data, &mut LimitTracker::new(&ParseLimits::UNLIMITED))
+    }
+
+    fn parse_limited(&mut self, data: &'a [u8], tracker: &mut LimitTracker) -> bool {
+        // Reuse the previous Resource's attribute slots
+        let mut resource = self.resource.take().unwrap_or_default();
         self.clear();
         
         let parser = ProtobufParser::new(data);
         
-        self.resource = parser.find_field(1).and_then(|(wire_type, pos)| {
+        self.resource = parser.find_last_field(1).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| {
-                        let mut resource = Resource::new();
-                        if resource.parse(bytes) {
+                        if resource.parse_limited(bytes, tracker) {
                             Some(resource)
                         } else {
                             None
@@ -284,9 +699,13 @@ impl<'a> ResourceLogs<'a> {
             }
         });
 
-        for (wire_type, pos) in parser.parse_all_fields(2) {
+        // Legacy scopes are merged in wire order
+        for (wire_type, pos) in parser.scope_logs_fields() {
             if wire_type == 2 {
                 if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
+                    if !tracker.enter_within_total_bytes(bytes) {
+                        break;
+                    }
                     // Reuse existing ScopeLogs if available
                     let scope_log = if self.scope_logs_used < self.scope_logs.len() {
                         &mut self.scope_logs[self.scope_logs_used]
@@ -295,14 +714,17 @@ impl<'a> ResourceLogs<'a> {
                         self.scope_logs.last_mut().unwrap()
                     };
                     
-                    if scope_log.parse(bytes) {
+                    if scope_log.parse_limited(bytes, tracker) {
                         self.scope_logs_used += 1;
                     }
+                    if tracker.aborted() {
+                        break;
+                    }
                 }
             }
         }
 
-        self.schema_url = parser.find_field(3).and_then(|(wire_type, pos)| {
+        self.schema_url = parser.find_last_field(3).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
@@ -315,6 +737,23 @@ impl<'a> ResourceLogs<'a> {
     }
 }
 
+/// Tag of `ResourceLogs.instrumentation_library_logs`, the pre-1.0 name of
+/// `scope_logs`. Its `InstrumentationLibraryLogs` and `InstrumentationLibrary`
+/// messages use the same tags as ScopeLogs and InstrumentationScope, so
+/// they parse as such unchanged.
+#[cfg(feature = "legacy_compat")]
+pub(crate) const INSTRUMENTATION_LIBRARY_LOGS_TAG: u32 = 1000;
+
+/// Whether a ResourceLogs field with `tag` holds a ScopeLogs message
+#[inline]
+pub(crate) fn is_scope_logs_tag(tag: u32) -> bool {
+    #[cfg(feature = "legacy_compat")]
+    if tag == INSTRUMENTATION_LIBRARY_LOGS_TAG {
+        return true;
+    }
+    tag == 2
+}
+
 /// Reusable eagerly parsed ScopeLogs
 pub struct ScopeLogs<'a> {
     pub scope: Option<InstrumentationScope<'a>>,
@@ -323,6 +762,12 @@ pub struct ScopeLogs<'a> {
     pub schema_url: Option<&'a str>,
 }
 
+impl<'a> Default for ScopeLogs<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> ScopeLogs<'a> {
     pub fn new() -> Self {
         Self {
@@ -333,6 +778,11 @@ impl<'a> ScopeLogs<'a> {
         }
     }
 
+    /// Number of LogRecords from the latest parse, excluding reused slots
+    pub fn log_records_count(&self) -> usize {
+        self.log_records_used
+    }
+
     pub fn clear(&mut self) {
         self.scope = None;
         // Clear nested structures while preserving capacity
@@ -343,17 +793,30 @@ impl<'a> ScopeLogs<'a> {
         self.schema_url = None;
     }
 
+    /// Fully clear this and every preallocated LogRecord slot
+    pub fn reset(&mut self) {
+        for log_record in &mut self.log_records {
+            log_record.reset();
+        }
+        self.clear();
+    }
+
     pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.parse_limited(data, &mut LimitTracker::new(&ParseLimits::UNLIMITED))
+    }
+
+    fn parse_limited(&mut self, data: &'a [u8], tracker: &mut LimitTracker) -> bool {
+        // Reuse the previous scope's attribute slots
+        let mut scope = self.scope.take().unwrap_or_default();
         self.clear();
         
         let parser = ProtobufParser::new(data);
         
-        self.scope = parser.find_field(1).and_then(|(wire_type, pos)| {
+        self.scope = parser.find_last_field(1).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| {
-                        let mut scope = InstrumentationScope::new();
-                        if scope.parse(bytes) {
+                        if scope.parse_limited(bytes, tracker) {
                             Some(scope)
                         } else {
                             None
@@ -364,9 +827,12 @@ impl<'a> ScopeLogs<'a> {
             }
         });
 
-        for (wire_type, pos) in parser.parse_all_fields(2) {
+        for (wire_type, pos) in parser.fields(2) {
             if wire_type == 2 {
                 if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
+                    if !tracker.within_total_bytes(bytes) {
+                        break;
+                    }
                     // Reuse existing LogRecord if available
                     let log_record = if self.log_records_used < self.log_records.len() {
                         &mut self.log_records[self.log_records_used]
@@ -375,14 +841,17 @@ impl<'a> ScopeLogs<'a> {
                         self.log_records.last_mut().unwrap()
                     };
                     
-                    if log_record.parse(bytes) {
+                    if log_record.parse_limited(bytes, tracker) {
                         self.log_records_used += 1;
                     }
+                    if tracker.aborted() {
+                        break;
+                    }
                 }
             }
         }
 
-        self.schema_url = parser.find_field(3).and_then(|(wire_type, pos)| {
+        self.schema_url = parser.find_last_field(3).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
@@ -409,6 +878,15 @@ pub struct LogRecord<'a> {
     pub trace_id: Option<&'a [u8]>,
     pub span_id: Option<&'a [u8]>,
     pub event_name: Option<&'a str>,
+    /// Owned attribute values layered over the borrowed `attributes`, see
+    /// `set_attribute_override`; `None` marks a key as removed
+    attribute_overrides: Vec<(String, Option<OwnedAnyValue>)>,
+}
+
+impl<'a> Default for LogRecord<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> LogRecord<'a> {
@@ -426,9 +904,16 @@ impl<'a> LogRecord<'a> {
             trace_id: None,
             span_id: None,
             event_name: None,
+            attribute_overrides: Vec::new(),
         }
     }
 
+    /// Number of attributes from the latest parse, excluding reused slots.
+    /// `attributes()` yields exactly these.
+    pub fn attribute_count(&self) -> usize {
+        self.attributes_used
+    }
+
     pub fn clear(&mut self) {
         self.time_unix_nano = None;
         self.observed_time_unix_nano = 0;
@@ -445,14 +930,27 @@ impl<'a> LogRecord<'a> {
         self.trace_id = None;
         self.span_id = None;
         self.event_name = None;
+        self.attribute_overrides.clear();
+    }
+
+    /// Fully clear this and every preallocated attribute slot
+    pub fn reset(&mut self) {
+        for attr in &mut self.attributes {
+            attr.clear();
+        }
+        self.clear();
     }
 
     pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.parse_limited(data, &mut LimitTracker::new(&ParseLimits::UNLIMITED))
+    }
+
+    fn parse_limited(&mut self, data: &'a [u8], tracker: &mut LimitTracker) -> bool {
         self.clear();
         
         let parser = ProtobufParser::new(data);
 
-        self.time_unix_nano = parser.find_field(1).and_then(|(wire_type, pos)| {
+        self.time_unix_nano = parser.find_last_field(1).and_then(|(wire_type, pos)| {
             if wire_type == 1 {
                 parser.parse_fixed64(pos).map(|(value, _)| value)
             } else {
@@ -461,7 +959,7 @@ impl<'a> LogRecord<'a> {
         });
 
         // observed_time_unix_nano is required - parse with default 0
-        self.observed_time_unix_nano = parser.find_field(11).and_then(|(wire_type, pos)| {
+        self.observed_time_unix_nano = parser.find_last_field(11).and_then(|(wire_type, pos)| {
             if wire_type == 1 {
                 parser.parse_fixed64(pos).map(|(value, _)| value)
             } else {
@@ -470,7 +968,7 @@ impl<'a> LogRecord<'a> {
         }).unwrap_or(0);
 
         // severity_number is required enum - parse with default 0 (UNSPECIFIED)
-        self.severity_number = parser.find_field(2).and_then(|(wire_type, pos)| {
+        self.severity_number = parser.find_last_field(2).and_then(|(wire_type, pos)| {
             if wire_type == 0 {
                 parser.parse_varint(pos).map(|(value, _)| value as i32)
             } else {
@@ -478,7 +976,7 @@ impl<'a> LogRecord<'a> {
             }
         }).unwrap_or(0);
 
-        self.severity_text = parser.find_field(3).and_then(|(wire_type, pos)| {
+        self.severity_text = parser.find_last_field(3).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
@@ -487,12 +985,12 @@ impl<'a> LogRecord<'a> {
             }
         });
 
-        self.body = parser.find_field(5).and_then(|(wire_type, pos)| {
+        self.body = parser.find_last_field(5).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| {
                         let mut any_value = AnyValue::new();
-                        if any_value.parse(bytes) {
+                        if any_value.parse_limited(bytes, tracker) {
                             Some(any_value)
                         } else {
                             None
@@ -503,8 +1001,11 @@ impl<'a> LogRecord<'a> {
             }
         });
 
-        for (wire_type, pos) in parser.parse_all_fields(6) {
+        for (wire_type, pos) in parser.fields(6) {
             if wire_type == 2 {
+                if tracker.attributes_full(self.attributes_used) {
+                    break;
+                }
                 if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
                     // Reuse existing KeyValue if available
                     let kv = if self.attributes_used < self.attributes.len() {
@@ -514,14 +1015,17 @@ impl<'a> LogRecord<'a> {
                         self.attributes.last_mut().unwrap()
                     };
                     
-                    if kv.parse(bytes) {
+                    if kv.parse_limited(bytes, tracker) {
                         self.attributes_used += 1;
                     }
+                    if tracker.aborted() {
+                        break;
+                    }
                 }
             }
         }
 
-        self.dropped_attributes_count = parser.find_field(7).and_then(|(wire_type, pos)| {
+        self.dropped_attributes_count = parser.find_last_field(7).and_then(|(wire_type, pos)| {
             if wire_type == 0 {
                 parser.parse_varint(pos).map(|(value, _)| value as u32)
             } else {
@@ -529,7 +1033,7 @@ impl<'a> LogRecord<'a> {
             }
         });
 
-        self.flags = parser.find_field(8).and_then(|(wire_type, pos)| {
+        self.flags = parser.find_last_field(8).and_then(|(wire_type, pos)| {
             if wire_type == 5 {
                 parser.parse_fixed32(pos).map(|(value, _)| value)
             } else {
@@ -537,7 +1041,7 @@ impl<'a> LogRecord<'a> {
             }
         });
 
-        self.trace_id = parser.find_field(9).and_then(|(wire_type, pos)| {
+        self.trace_id = parser.find_last_field(9).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos).map(|(bytes, _)| bytes)
             } else {
@@ -545,7 +1049,7 @@ impl<'a> LogRecord<'a> {
             }
         });
 
-        self.span_id = parser.find_field(10).and_then(|(wire_type, pos)| {
+        self.span_id = parser.find_last_field(10).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos).map(|(bytes, _)| bytes)
             } else {
@@ -553,7 +1057,7 @@ impl<'a> LogRecord<'a> {
             }
         });
 
-        self.event_name = parser.find_field(12).and_then(|(wire_type, pos)| {
+        self.event_name = parser.find_last_field(12).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
@@ -587,52 +1091,199 @@ impl<'a> LogRecord<'a> {
     pub fn trace_flags(&self) -> Option<u8> {
         self.flags.map(|flags| (flags & 0xFF) as u8)
     }
+
+    /// The flags field decoded per OTLP's trace flag and `SpanFlags` bits
+    pub fn log_record_flags(&self) -> Option<LogRecordFlags> {
+        self.flags.map(LogRecordFlags)
+    }
+
+    /// Trace id, span id and trace flags together, ids as fixed arrays
+    pub fn trace_context(&self) -> TraceContext {
+        TraceContext::from_raw(self.trace_id, self.span_id, self.flags)
+    }
+
+    /// Get the values of every attribute with the given key, in wire order,
+    /// for producers that repeat a key to express multiple values
+    pub fn get_all<'s>(&'s self, key: &'s str) -> impl Iterator<Item = &'s AnyValue<'a>> + 's {
+        values_for_key(&self.attributes[..self.attributes_used], key)
+    }
+
+    /// Replace the value of attribute `key` (or add it, if the record has no
+    /// such attribute) without touching the borrowed input. Overrides are
+    /// seen through `effective_attributes` and `to_prost`, and are dropped by
+    /// the next `parse`.
+    pub fn set_attribute_override(&mut self, key: impl Into<String>, value: OwnedAnyValue) {
+        self.put_override(key.into(), Some(value));
+    }
+
+    /// Hide every attribute with `key`, duplicates included, the same way
+    /// `set_attribute_override` replaces one. Setting an override for the
+    /// key afterwards brings it back with the new value.
+    pub fn remove_attribute(&mut self, key: impl Into<String>) {
+        self.put_override(key.into(), None);
+    }
+
+    fn put_override(&mut self, key: String, value: Option<OwnedAnyValue>) {
+        match self.attribute_overrides.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.attribute_overrides.push((key, value)),
+        }
+    }
+
+    /// Drop every override set since the last parse
+    pub fn clear_attribute_overrides(&mut self) {
+        self.attribute_overrides.clear();
+    }
+
+    /// Iterate the attributes with overrides applied. An override takes the
+    /// place of the first original attribute with its key (later duplicates
+    /// are hidden); overrides for new keys follow the originals in the order
+    /// they were set. Removed keys are skipped entirely.
+    pub fn effective_attributes(&self) -> impl Iterator<Item = EffectiveAttribute<'_, 'a>> {
+        let originals = &self.attributes[..self.attributes_used];
+        let overrides = &self.attribute_overrides;
+        let find_override = move |key: &str| overrides.iter().find(|(k, _)| k == key);
+
+        let merged = originals.iter().enumerate().filter_map(move |(idx, attr)| {
+            match find_override(attr.key) {
+                None => Some(EffectiveAttribute::Original(attr)),
+                Some((_, None)) => None,
+                Some(_) if originals[..idx].iter().any(|earlier| earlier.key == attr.key) => None,
+                Some((key, Some(value))) => Some(EffectiveAttribute::Override { key, value }),
+            }
+        });
+        let added = overrides
+            .iter()
+            .filter(move |(key, _)| !originals.iter().any(|attr| attr.key == key))
+            .filter_map(|(key, value)| Some(EffectiveAttribute::Override { key, value: value.as_ref()? }));
+        merged.chain(added)
+    }
+}
+
+/// Remove every attribute whose key is in `deny` from `record`, e.g. to
+/// drop PII before export. Unlike `remove_attribute`, the attributes are
+/// dropped from the parsed record itself, so `attributes()` and everything
+/// built on the view traits no longer see them either. The borrowed input
+/// bytes are untouched.
+pub fn redact_attributes(record: &mut LogRecord<'_>, deny: &HashSet<&str>) {
+    // Move the kept attributes to the front; denied slots end up past
+    // `attributes_used`, where the next parse reuses them
+    let mut kept = 0;
+    for idx in 0..record.attributes_used {
+        if !deny.contains(record.attributes[idx].key) {
+            record.attributes.swap(kept, idx);
+            kept += 1;
+        }
+    }
+    record.attributes_used = kept;
+    record.attribute_overrides.retain(|(key, _)| !deny.contains(key.as_str()));
+}
+
+/// Owned attribute value used for overrides on the borrowed eager structs
+pub type OwnedAnyValue = pb::common::v1::AnyValue;
+
+/// An attribute as seen through `LogRecord::effective_attributes`
+#[derive(Debug, Clone, Copy)]
+pub enum EffectiveAttribute<'r, 'a> {
+    /// Borrowed from the parsed input, unmodified
+    Original(&'r KeyValue<'a>),
+    /// Set through `set_attribute_override`
+    Override { key: &'r str, value: &'r OwnedAnyValue },
+}
+
+impl<'r, 'a> EffectiveAttribute<'r, 'a> {
+    pub fn key(&self) -> &'r str {
+        match self {
+            EffectiveAttribute::Original(attr) => attr.key,
+            EffectiveAttribute::Override { key, .. } => key,
+        }
+    }
+
+    pub fn is_override(&self) -> bool {
+        matches!(self, EffectiveAttribute::Override { .. })
+    }
+
+    /// Copy into an owned prost `KeyValue`
+    pub fn to_prost(&self) -> pb::common::v1::KeyValue {
+        match self {
+            EffectiveAttribute::Original(attr) => attr.to_prost(),
+            EffectiveAttribute::Override { key, value } => pb::common::v1::KeyValue {
+                key: key.to_string(),
+                value: Some((*value).clone()),
+            },
+        }
+    }
 }
 
 /// Reusable eagerly parsed KeyValue
 #[derive(Debug, Clone)]
 pub struct KeyValue<'a> {
     pub key: &'a str,
+    /// The key as sent on the wire; differs from `key` (which is then
+    /// empty) only when the key is not valid UTF-8
+    pub key_bytes: &'a [u8],
     pub value: Option<AnyValue<'a>>,
 }
 
+impl<'a> Default for KeyValue<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> KeyValue<'a> {
     pub fn new() -> Self {
         Self {
             key: "",
+            key_bytes: &[],
             value: None,
         }
     }
 
     pub fn clear(&mut self) {
         self.key = "";
+        self.key_bytes = &[];
         self.value = None;
     }
 
+    /// Get the key, replacing invalid UTF-8 sequences with U+FFFD
+    pub fn key_cow(&self) -> Cow<'a, str> {
+        String::from_utf8_lossy(self.key_bytes)
+    }
+
+    /// Offset within the key of the first invalid UTF-8 byte, or `None` if
+    /// the key is valid. For tracing bad producers when `key` comes back empty.
+    pub fn key_utf8_error(&self) -> Option<usize> {
+        std::str::from_utf8(self.key_bytes).err().map(|err| err.valid_up_to())
+    }
+
     pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.parse_limited(data, &mut LimitTracker::new(&ParseLimits::UNLIMITED))
+    }
+
+    fn parse_limited(&mut self, data: &'a [u8], tracker: &mut LimitTracker) -> bool {
         self.clear();
         
         let parser = ProtobufParser::new(data);
 
-        self.key = parser.find_field(1).and_then(|(wire_type, pos)| {
+        self.key_bytes = parser.find_field(1).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
-                parser.parse_length_delimited(pos)
-                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
+                parser.parse_length_delimited(pos).map(|(bytes, _)| bytes)
             } else {
                 None
             }
-        }).unwrap_or("");
-
-        if self.key.is_empty() {
-            return false;
-        }
+        }).unwrap_or_default();
+        // A key that isn't valid UTF-8 is kept, readable through `key_cow`.
+        // An empty key is legal OTLP (and proto3 omits it from the wire), so
+        // the attribute is retained either way.
+        self.key = std::str::from_utf8(self.key_bytes).unwrap_or("");
 
         self.value = parser.find_field(2).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| {
                         let mut any_value = AnyValue::new();
-                        if any_value.parse(bytes) {
+                        if any_value.parse_limited(bytes, tracker) {
                             Some(any_value)
                         } else {
                             None
@@ -651,6 +1302,8 @@ impl<'a> KeyValue<'a> {
 #[derive(Debug, Clone)]
 pub struct AnyValue<'a> {
     pub value: AnyValueData<'a>,
+    /// Raw bytes of a string value, kept for lossy access to invalid UTF-8
+    string_bytes: Option<&'a [u8]>,
 }
 
 #[derive(Debug, Clone)]
@@ -664,18 +1317,30 @@ pub enum AnyValueData<'a> {
     Bytes(&'a [u8]),
 }
 
+impl<'a> Default for AnyValue<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> AnyValue<'a> {
     pub fn new() -> Self {
         Self {
             value: AnyValueData::String(""),
+            string_bytes: None,
         }
     }
 
     pub fn clear(&mut self) {
         self.value = AnyValueData::String("");
+        self.string_bytes = None;
     }
 
     pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.parse_limited(data, &mut LimitTracker::new(&ParseLimits::UNLIMITED))
+    }
+
+    fn parse_limited(&mut self, data: &'a [u8], tracker: &mut LimitTracker) -> bool {
         self.clear();
         
         let parser = ProtobufParser::new(data);
@@ -684,10 +1349,11 @@ impl<'a> AnyValue<'a> {
         if let Some((wire_type, pos)) = parser.find_field(1) {
             if wire_type == 2 {
                 if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
-                    if let Ok(s) = std::str::from_utf8(bytes) {
-                        self.value = AnyValueData::String(s);
-                        return true;
-                    }
+                    // Invalid UTF-8 reads as "" through string_value, but
+                    // stays a string and is recoverable via string_value_cow
+                    self.value = AnyValueData::String(std::str::from_utf8(bytes).unwrap_or(""));
+                    self.string_bytes = Some(bytes);
+                    return true;
                 }
             }
         }
@@ -719,38 +1385,66 @@ impl<'a> AnyValue<'a> {
             }
         }
 
-        if parser.find_field(5).is_some() {
-            // Array field - need to parse all array values
-            let mut array_values = Vec::new();
-            for (wire_type, pos) in parser.parse_all_fields(5) {
-                if wire_type == 2 {
-                    if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
-                        let mut any_value = AnyValue::new();
-                        if any_value.parse(bytes) {
-                            array_values.push(any_value);
+        if let Some((wire_type, pos)) = parser.find_field(5) {
+            if wire_type == 2 {
+                if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
+                    // ArrayValue message - parse all values (tag 1)
+                    let mut array_values = Vec::new();
+                    if tracker.enter_nested() {
+                        let array_parser = ProtobufParser::new(bytes);
+                        for (wire_type, pos) in array_parser.fields(1) {
+                            if wire_type == 2 {
+                                if tracker.attributes_full(array_values.len()) {
+                                    break;
+                                }
+                                if let Some((bytes, _)) = array_parser.parse_length_delimited(pos) {
+                                    let mut any_value = AnyValue::new();
+                                    if any_value.parse_limited(bytes, tracker) {
+                                        array_values.push(any_value);
+                                    }
+                                    if tracker.aborted() {
+                                        break;
+                                    }
+                                }
+                            }
                         }
+                        tracker.exit_nested();
                     }
+                    self.value = AnyValueData::Array(array_values);
+                    return true;
                 }
             }
-            self.value = AnyValueData::Array(array_values);
-            return true;
-        }
-
-        if parser.find_field(6).is_some() {
-            // KvList field - need to parse all key-value pairs
-            let mut kv_values = Vec::new();
-            for (wire_type, pos) in parser.parse_all_fields(6) {
-                if wire_type == 2 {
-                    if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
-                        let mut kv = KeyValue::new();
-                        if kv.parse(bytes) {
-                            kv_values.push(kv);
+        }
+
+        if let Some((wire_type, pos)) = parser.find_field(6) {
+            if wire_type == 2 {
+                if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
+                    // KeyValueList message - parse all key-value pairs (tag 1)
+                    let mut kv_values = Vec::new();
+                    if tracker.enter_nested() {
+                        let kvlist_parser = ProtobufParser::new(bytes);
+                        for (wire_type, pos) in kvlist_parser.fields(1) {
+                            if wire_type == 2 {
+                                if tracker.attributes_full(kv_values.len()) {
+                                    break;
+                                }
+                                if let Some((bytes, _)) = kvlist_parser.parse_length_delimited(pos) {
+                                    let mut kv = KeyValue::new();
+                                    if kv.parse_limited(bytes, tracker) {
+                                        kv_values.push(kv);
+                                    }
+                                    if tracker.aborted() {
+                                        break;
+                                    }
+                                }
+                            }
                         }
+                        tracker.exit_nested();
                     }
+                    self.value = AnyValueData::KvList(kv_values);
+                    return true;
                 }
             }
-            self.value = AnyValueData::KvList(kv_values);
-            return true;
         }
 
         if let Some((wire_type, pos)) = parser.find_field(7) {
@@ -784,6 +1478,23 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    /// Get the string value, replacing invalid UTF-8 sequences with U+FFFD
+    pub fn string_value_cow(&self) -> Option<Cow<'a, str>> {
+        match self.value {
+            AnyValueData::String(_) => self.string_bytes.map(String::from_utf8_lossy),
+            _ => None,
+        }
+    }
+
+    /// Offset within the string value of the first invalid UTF-8 byte;
+    /// `None` if the value is valid or not a string
+    pub fn string_utf8_error(&self) -> Option<usize> {
+        match self.value {
+            AnyValueData::String(_) => std::str::from_utf8(self.string_bytes?).err().map(|err| err.valid_up_to()),
+            _ => None,
+        }
+    }
+
     pub fn bool_value(&self) -> Option<bool> {
         match &self.value {
             AnyValueData::Bool(b) => Some(*b),
@@ -826,10 +1537,37 @@ impl<'a> AnyValue<'a> {
         }
     }
 
-    pub fn to_display_string(&self) -> String {
-        match &self.value {
-            AnyValueData::String(s) => format!("\"{}\"", s),
-            AnyValueData::Bool(b) => format!("{}", b),
+    /// Write the bytes value as lowercase hex without allocating.
+    /// Writes nothing if the value is not bytes.
+    pub fn write_bytes_hex<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        if let Some(bytes) = self.bytes_value() {
+            for byte in bytes {
+                write!(w, "{:02x}", byte)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the bytes value rendered as lowercase hex
+    pub fn bytes_as_hex(&self) -> Option<String> {
+        let bytes = self.bytes_value()?;
+        let mut hex = String::with_capacity(bytes.len() * 2);
+        self.write_bytes_hex(&mut hex).ok()?;
+        Some(hex)
+    }
+
+    /// Get the bytes value rendered as standard (padded) base64
+    pub fn bytes_as_base64(&self) -> Option<String> {
+        let bytes = self.bytes_value()?;
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        crate::util::write_base64(&mut encoded, bytes).ok()?;
+        Some(encoded)
+    }
+
+    pub fn to_display_string(&self) -> String {
+        match &self.value {
+            AnyValueData::String(s) => format!("\"{}\"", s),
+            AnyValueData::Bool(b) => format!("{}", b),
             AnyValueData::Int(i) => format!("{}", i),
             AnyValueData::Double(d) => format!("{}", d),
             AnyValueData::Bytes(b) => format!("bytes[{}]", b.len()),
@@ -837,6 +1575,13 @@ impl<'a> AnyValue<'a> {
             AnyValueData::KvList(kv) => format!("kvlist[{}]", kv.len()),
         }
     }
+
+    /// Render the value with array and kvlist contents expanded, e.g.
+    /// `[1, "a", true, ...]`, up to `max_depth` levels of nesting and
+    /// `max_elems` entries per container
+    pub fn to_display_string_deep(&self, max_depth: usize, max_elems: usize) -> String {
+        crate::util::display_value_deep(self, max_depth, max_elems)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -855,6 +1600,14 @@ pub struct Resource<'a> {
     pub attributes: Vec<KeyValue<'a>>,
     pub attributes_used: usize,
     pub dropped_attributes_count: Option<u32>,
+    pub entity_refs: Vec<EntityRef<'a>>,
+    pub entity_refs_used: usize,
+}
+
+impl<'a> Default for Resource<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> Resource<'a> {
@@ -863,9 +1616,21 @@ impl<'a> Resource<'a> {
             attributes: Vec::new(),
             attributes_used: 0,
             dropped_attributes_count: None,
+            entity_refs: Vec::new(),
+            entity_refs_used: 0,
         }
     }
 
+    /// Number of attributes from the latest parse, excluding reused slots
+    pub fn attribute_count(&self) -> usize {
+        self.attributes_used
+    }
+
+    /// Number of entity refs from the latest parse, excluding reused slots
+    pub fn entity_ref_count(&self) -> usize {
+        self.entity_refs_used
+    }
+
     pub fn clear(&mut self) {
         // Clear nested structures while preserving capacity
         // for attr in &mut self.attributes[..self.attributes_used] {
@@ -873,15 +1638,23 @@ impl<'a> Resource<'a> {
         // }
         self.attributes_used = 0;
         self.dropped_attributes_count = None;
+        self.entity_refs_used = 0;
     }
 
     pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.parse_limited(data, &mut LimitTracker::new(&ParseLimits::UNLIMITED))
+    }
+
+    fn parse_limited(&mut self, data: &'a [u8], tracker: &mut LimitTracker) -> bool {
         self.clear();
         
         let parser = ProtobufParser::new(data);
 
-        for (wire_type, pos) in parser.parse_all_fields(1) {
+        for (wire_type, pos) in parser.fields(1) {
             if wire_type == 2 {
+                if tracker.attributes_full(self.attributes_used) {
+                    break;
+                }
                 if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
                     // Reuse existing KeyValue if available
                     let kv = if self.attributes_used < self.attributes.len() {
@@ -891,14 +1664,17 @@ impl<'a> Resource<'a> {
                         self.attributes.last_mut().unwrap()
                     };
                     
-                    if kv.parse(bytes) {
+                    if kv.parse_limited(bytes, tracker) {
                         self.attributes_used += 1;
                     }
+                    if tracker.aborted() {
+                        break;
+                    }
                 }
             }
         }
 
-        self.dropped_attributes_count = parser.find_field(2).and_then(|(wire_type, pos)| {
+        self.dropped_attributes_count = parser.find_last_field(2).and_then(|(wire_type, pos)| {
             if wire_type == 0 {
                 parser.parse_varint(pos).map(|(value, _)| value as u32)
             } else {
@@ -906,15 +1682,138 @@ impl<'a> Resource<'a> {
             }
         });
 
+        for (wire_type, pos) in parser.fields(3) {
+            if wire_type == 2 {
+                if tracker.aborted() || tracker.attributes_full(self.entity_refs_used) {
+                    break;
+                }
+                if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
+                    if !tracker.within_total_bytes(bytes) {
+                        break;
+                    }
+                    // Reuse existing EntityRef if available
+                    let entity_ref = if self.entity_refs_used < self.entity_refs.len() {
+                        &mut self.entity_refs[self.entity_refs_used]
+                    } else {
+                        self.entity_refs.push(EntityRef::new());
+                        self.entity_refs.last_mut().unwrap()
+                    };
+
+                    if entity_ref.parse(bytes) {
+                        self.entity_refs_used += 1;
+                    }
+                }
+            }
+        }
+
         true
     }
 
-    pub fn get_service_name(&self) -> Option<&str> {
-        self.attributes[..self.attributes_used]
+    /// Get the entity references parsed by the last `parse`
+    pub fn entity_refs(&self) -> &[EntityRef<'a>] {
+        &self.entity_refs[..self.entity_refs_used]
+    }
+
+    /// Get the attributes parsed by the last `parse`
+    pub fn attributes(&self) -> &[KeyValue<'a>] {
+        &self.attributes[..self.attributes_used]
+    }
+
+    /// Get the value of the first attribute with the given key
+    pub fn get(&self, key: &str) -> Option<&AnyValue<'a>> {
+        self.attributes()
             .iter()
-            .find(|attr| attr.key == "service.name")
+            .find(|attr| attr.key == key)
             .and_then(|attr| attr.value.as_ref())
-            .and_then(|val| val.string_value())
+    }
+
+    /// Get the values of every attribute with the given key, in wire order,
+    /// for producers that repeat a key to express multiple values
+    pub fn get_all<'s>(&'s self, key: &'s str) -> impl Iterator<Item = &'s AnyValue<'a>> + 's {
+        values_for_key(&self.attributes[..self.attributes_used], key)
+    }
+
+    pub fn get_service_name(&self) -> Option<&str> {
+        self.get("service.name").and_then(|val| val.string_value())
+    }
+}
+
+/// Reusable eagerly parsed EntityRef
+#[derive(Debug, Clone)]
+pub struct EntityRef<'a> {
+    pub schema_url: Option<&'a str>,
+    pub r#type: Option<&'a str>,
+    pub id_keys: Vec<&'a str>,
+    pub description_keys: Vec<&'a str>,
+}
+
+impl<'a> Default for EntityRef<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> EntityRef<'a> {
+    pub fn new() -> Self {
+        Self {
+            schema_url: None,
+            r#type: None,
+            id_keys: Vec::new(),
+            description_keys: Vec::new(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.schema_url = None;
+        self.r#type = None;
+        self.id_keys.clear();
+        self.description_keys.clear();
+    }
+
+    pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.clear();
+
+        let parser = ProtobufParser::new(data);
+
+        self.schema_url = parser.find_last_field(1).and_then(|(wire_type, pos)| {
+            if wire_type == 2 {
+                parser.parse_length_delimited(pos)
+                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
+            } else {
+                None
+            }
+        });
+
+        self.r#type = parser.find_last_field(2).and_then(|(wire_type, pos)| {
+            if wire_type == 2 {
+                parser.parse_length_delimited(pos)
+                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
+            } else {
+                None
+            }
+        });
+
+        for (wire_type, pos) in parser.fields(3) {
+            if wire_type == 2 {
+                if let Some(key) = parser.parse_length_delimited(pos)
+                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
+                {
+                    self.id_keys.push(key);
+                }
+            }
+        }
+
+        for (wire_type, pos) in parser.fields(4) {
+            if wire_type == 2 {
+                if let Some(key) = parser.parse_length_delimited(pos)
+                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
+                {
+                    self.description_keys.push(key);
+                }
+            }
+        }
+
+        true
     }
 }
 
@@ -927,6 +1826,12 @@ pub struct InstrumentationScope<'a> {
     pub dropped_attributes_count: Option<u32>,
 }
 
+impl<'a> Default for InstrumentationScope<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> InstrumentationScope<'a> {
     pub fn new() -> Self {
         Self {
@@ -938,6 +1843,17 @@ impl<'a> InstrumentationScope<'a> {
         }
     }
 
+    /// Number of attributes from the latest parse, excluding reused slots
+    pub fn attribute_count(&self) -> usize {
+        self.attributes_used
+    }
+
+    /// Get the values of every attribute with the given key, in wire order,
+    /// for producers that repeat a key to express multiple values
+    pub fn get_all<'s>(&'s self, key: &'s str) -> impl Iterator<Item = &'s AnyValue<'a>> + 's {
+        values_for_key(&self.attributes[..self.attributes_used], key)
+    }
+
     pub fn clear(&mut self) {
         self.name = None;
         self.version = None;
@@ -950,11 +1866,15 @@ impl<'a> InstrumentationScope<'a> {
     }
 
     pub fn parse(&mut self, data: &'a [u8]) -> bool {
+        self.parse_limited(data, &mut LimitTracker::new(&ParseLimits::UNLIMITED))
+    }
+
+    fn parse_limited(&mut self, data: &'a [u8], tracker: &mut LimitTracker) -> bool {
         self.clear();
         
         let parser = ProtobufParser::new(data);
 
-        self.name = parser.find_field(1).and_then(|(wire_type, pos)| {
+        self.name = parser.find_last_field(1).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
@@ -963,7 +1883,7 @@ impl<'a> InstrumentationScope<'a> {
             }
         });
 
-        self.version = parser.find_field(2).and_then(|(wire_type, pos)| {
+        self.version = parser.find_last_field(2).and_then(|(wire_type, pos)| {
             if wire_type == 2 {
                 parser.parse_length_delimited(pos)
                     .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
@@ -972,8 +1892,11 @@ impl<'a> InstrumentationScope<'a> {
             }
         });
 
-        for (wire_type, pos) in parser.parse_all_fields(3) {
+        for (wire_type, pos) in parser.fields(3) {
             if wire_type == 2 {
+                if tracker.attributes_full(self.attributes_used) {
+                    break;
+                }
                 if let Some((bytes, _)) = parser.parse_length_delimited(pos) {
                     // Reuse existing KeyValue if available
                     let kv = if self.attributes_used < self.attributes.len() {
@@ -983,14 +1906,17 @@ impl<'a> InstrumentationScope<'a> {
                         self.attributes.last_mut().unwrap()
                     };
                     
-                    if kv.parse(bytes) {
+                    if kv.parse_limited(bytes, tracker) {
                         self.attributes_used += 1;
                     }
+                    if tracker.aborted() {
+                        break;
+                    }
                 }
             }
         }
 
-        self.dropped_attributes_count = parser.find_field(4).and_then(|(wire_type, pos)| {
+        self.dropped_attributes_count = parser.find_last_field(4).and_then(|(wire_type, pos)| {
             if wire_type == 0 {
                 parser.parse_varint(pos).map(|(value, _)| value as u32)
             } else {
@@ -1028,6 +1954,441 @@ impl<'a, T> Iterator for UsedSliceIter<'a, T> {
     }
 }
 
+// Allow `for resource in &logs` style iteration over the used elements
+impl<'a, 'b> IntoIterator for &'a LogsData<'b> {
+    type Item = &'a ResourceLogs<'b>;
+    type IntoIter = UsedSliceIter<'a, ResourceLogs<'b>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UsedSliceIter::new(&self.resource_logs[..self.used_count])
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'a ResourceLogs<'b> {
+    type Item = &'a ScopeLogs<'b>;
+    type IntoIter = UsedSliceIter<'a, ScopeLogs<'b>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UsedSliceIter::new(&self.scope_logs[..self.scope_logs_used])
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'a ScopeLogs<'b> {
+    type Item = &'a LogRecord<'b>;
+    type IntoIter = UsedSliceIter<'a, LogRecord<'b>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UsedSliceIter::new(&self.log_records[..self.log_records_used])
+    }
+}
+
+/// Parse a standalone serialized `Resource`, e.g. one kept in a cache,
+/// without wrapping it in a LogsData. Returns `None` if the message is
+/// malformed at the top level.
+pub fn parse_resource(data: &[u8]) -> Option<Resource<'_>> {
+    if !ProtobufParser::new(data).is_well_formed() {
+        return None;
+    }
+    let mut resource = Resource::new();
+    resource.parse(data).then_some(resource)
+}
+
+/// Parse a standalone serialized `AnyValue`. Returns `None` if the message
+/// is malformed or holds no recognized value.
+pub fn parse_any_value(data: &[u8]) -> Option<AnyValue<'_>> {
+    if !ProtobufParser::new(data).is_well_formed() {
+        return None;
+    }
+    let mut value = AnyValue::new();
+    value.parse(data).then_some(value)
+}
+
+/// Reusable storage for parsing many independent buffers. A `LogsData<'a>`
+/// can only be reused for buffers that share its lifetime; the arena instead
+/// keeps the preallocated resource/scope/record/attribute slots detached from
+/// any buffer and lends them out for one buffer at a time.
+#[derive(Default)]
+pub struct LogsArena {
+    storage: LogsData<'static>,
+}
+
+impl LogsArena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse `data` into the arena's slots. The slots go back to the arena
+    /// (with their capacity) when the returned view is dropped. Returns
+    /// `None`, with the slots already back in the arena, if the top-level
+    /// message is malformed.
+    pub fn parse_into<'arena, 'a>(&'arena mut self, data: &'a [u8]) -> Option<LogsDataView<'arena, 'a>> {
+        let mut logs: LogsData<'a> = std::mem::take(&mut self.storage);
+        if !logs.parse(data) {
+            self.storage = logs.recycle();
+            return None;
+        }
+        Some(LogsDataView { arena: self, logs })
+    }
+
+    /// Number of preallocated ResourceLogs slots
+    pub fn resource_capacity(&self) -> usize {
+        self.storage.resource_logs.len()
+    }
+}
+
+/// Parsed logs borrowed from a `LogsArena`; derefs to `LogsData`
+pub struct LogsDataView<'arena, 'a> {
+    arena: &'arena mut LogsArena,
+    logs: LogsData<'a>,
+}
+
+impl<'arena, 'a> std::ops::Deref for LogsDataView<'arena, 'a> {
+    type Target = LogsData<'a>;
+
+    fn deref(&self) -> &LogsData<'a> {
+        &self.logs
+    }
+}
+
+impl<'arena, 'a> Drop for LogsDataView<'arena, 'a> {
+    fn drop(&mut self) {
+        self.arena.storage = std::mem::take(&mut self.logs).recycle();
+    }
+}
+
+// Detaching slots from their buffer: each level is rebuilt empty around its
+// existing Vecs. Collecting a Vec's own IntoIter into an identically laid out
+// element type reuses the allocation, so no slot capacity is lost.
+impl<'a> LogsData<'a> {
+    fn recycle<'b>(self) -> LogsData<'b> {
+        LogsData {
+            resource_logs: self.resource_logs.into_iter().map(ResourceLogs::recycle).collect(),
+            ..LogsData::new()
+        }
+    }
+}
+
+impl<'a> ResourceLogs<'a> {
+    fn recycle<'b>(self) -> ResourceLogs<'b> {
+        ResourceLogs {
+            resource: self.resource.map(Resource::recycle),
+            scope_logs: self.scope_logs.into_iter().map(ScopeLogs::recycle).collect(),
+            ..ResourceLogs::new()
+        }
+    }
+}
+
+impl<'a> Resource<'a> {
+    fn recycle<'b>(self) -> Resource<'b> {
+        Resource {
+            attributes: self.attributes.into_iter().map(|_| KeyValue::new()).collect(),
+            entity_refs: self.entity_refs.into_iter().map(|_| EntityRef::new()).collect(),
+            ..Resource::new()
+        }
+    }
+}
+
+impl<'a> ScopeLogs<'a> {
+    fn recycle<'b>(self) -> ScopeLogs<'b> {
+        ScopeLogs {
+            scope: self.scope.map(InstrumentationScope::recycle),
+            log_records: self.log_records.into_iter().map(LogRecord::recycle).collect(),
+            ..ScopeLogs::new()
+        }
+    }
+}
+
+impl<'a> InstrumentationScope<'a> {
+    fn recycle<'b>(self) -> InstrumentationScope<'b> {
+        InstrumentationScope {
+            attributes: self.attributes.into_iter().map(|_| KeyValue::new()).collect(),
+            ..InstrumentationScope::new()
+        }
+    }
+}
+
+impl<'a> LogRecord<'a> {
+    fn recycle<'b>(self) -> LogRecord<'b> {
+        LogRecord {
+            attributes: self.attributes.into_iter().map(|_| KeyValue::new()).collect(),
+            ..LogRecord::new()
+        }
+    }
+}
+
+/// Largest message protobuf implementations accept (2 GiB - 1)
+const MAX_ENCODED_LEN: usize = i32::MAX as usize;
+
+/// Why parsed data could not be re-encoded with `encode`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// A trace id that is neither empty nor 16 bytes
+    InvalidTraceIdLength(usize),
+    /// A span id that is neither empty nor 8 bytes
+    InvalidSpanIdLength(usize),
+    /// The encoding would exceed protobuf's 2 GiB message limit
+    MessageTooLarge(usize),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::InvalidTraceIdLength(len) => write!(f, "trace id is {} bytes, expected 16", len),
+            EncodeError::InvalidSpanIdLength(len) => write!(f, "span id is {} bytes, expected 8", len),
+            EncodeError::MessageTooLarge(len) => {
+                write!(f, "encoded message of {} bytes exceeds the {} byte limit", len, MAX_ENCODED_LEN)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Encode `message`, refusing sizes other implementations would reject
+fn encode_checked<M: prost::Message>(message: &M) -> Result<Vec<u8>, EncodeError> {
+    let len = message.encoded_len();
+    if len > MAX_ENCODED_LEN {
+        return Err(EncodeError::MessageTooLarge(len));
+    }
+    Ok(message.encode_to_vec())
+}
+
+// Conversion into the owned prost types, the inverse of prost_structs.rs
+impl<'a> LogsData<'a> {
+    /// Copy the parsed data into an owned prost `LogsData`
+    pub fn to_prost(&self) -> pb::logs::v1::LogsData {
+        pb::logs::v1::LogsData {
+            resource_logs: self.into_iter().map(ResourceLogs::to_prost).collect(),
+        }
+    }
+
+    /// Copy into an owned prost `LogsData` keeping only the records `keep`
+    /// accepts. Records stay under their original resource and scope;
+    /// scopes and resources left without records are dropped.
+    pub fn rebuild_filtered(&self, keep: impl Fn(&LogRecord<'a>) -> bool) -> pb::logs::v1::LogsData {
+        let resource_logs = self
+            .into_iter()
+            .filter_map(|resource_logs| {
+                let scope_logs: Vec<_> = resource_logs
+                    .into_iter()
+                    .filter_map(|scope_logs| {
+                        let log_records: Vec<_> = scope_logs
+                            .into_iter()
+                            .filter(|&record| keep(record))
+                            .map(LogRecord::to_prost)
+                            .collect();
+                        (!log_records.is_empty()).then(|| pb::logs::v1::ScopeLogs {
+                            scope: scope_logs.scope.as_ref().map(InstrumentationScope::to_prost),
+                            log_records,
+                            schema_url: scope_logs.schema_url.unwrap_or_default().to_string(),
+                        })
+                    })
+                    .collect();
+                (!scope_logs.is_empty()).then(|| pb::logs::v1::ResourceLogs {
+                    resource: resource_logs.resource.as_ref().map(Resource::to_prost),
+                    scope_logs,
+                    schema_url: resource_logs.schema_url.unwrap_or_default().to_string(),
+                })
+            })
+            .collect();
+        pb::logs::v1::LogsData { resource_logs }
+    }
+
+    /// Size of the data once re-encoded; this is the canonical encoding, so
+    /// it can differ from the original buffer if that had unknown fields,
+    /// duplicates or non-minimal varints
+    pub fn encoded_len(&self) -> usize {
+        prost::Message::encoded_len(&self.to_prost())
+    }
+
+    /// Re-encode the data, failing on content that would not round-trip as
+    /// valid OTLP: malformed trace or span ids, or an oversized message
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        for resource_logs in self {
+            resource_logs.check_ids()?;
+        }
+        encode_checked(&self.to_prost())
+    }
+
+    /// Apply `redact_attributes` to every record, e.g. ahead of `encode`
+    pub fn redact_attributes(&mut self, deny: &HashSet<&str>) {
+        for resource_logs in &mut self.resource_logs[..self.used_count] {
+            for scope_logs in &mut resource_logs.scope_logs[..resource_logs.scope_logs_used] {
+                for record in &mut scope_logs.log_records[..scope_logs.log_records_used] {
+                    redact_attributes(record, deny);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> ResourceLogs<'a> {
+    pub fn to_prost(&self) -> pb::logs::v1::ResourceLogs {
+        pb::logs::v1::ResourceLogs {
+            resource: self.resource.as_ref().map(Resource::to_prost),
+            scope_logs: self.into_iter().map(ScopeLogs::to_prost).collect(),
+            schema_url: self.schema_url.unwrap_or_default().to_string(),
+        }
+    }
+
+    /// Size of this ResourceLogs once re-encoded, excluding its own framing
+    pub fn encoded_len(&self) -> usize {
+        prost::Message::encoded_len(&self.to_prost())
+    }
+
+    /// Re-encode this ResourceLogs; see `LogsData::encode`
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        self.check_ids()?;
+        encode_checked(&self.to_prost())
+    }
+
+    fn check_ids(&self) -> Result<(), EncodeError> {
+        self.into_iter().try_for_each(ScopeLogs::check_ids)
+    }
+}
+
+impl<'a> ScopeLogs<'a> {
+    pub fn to_prost(&self) -> pb::logs::v1::ScopeLogs {
+        pb::logs::v1::ScopeLogs {
+            scope: self.scope.as_ref().map(InstrumentationScope::to_prost),
+            log_records: self.into_iter().map(LogRecord::to_prost).collect(),
+            schema_url: self.schema_url.unwrap_or_default().to_string(),
+        }
+    }
+
+    /// Size of this ScopeLogs once re-encoded, excluding its own framing
+    pub fn encoded_len(&self) -> usize {
+        prost::Message::encoded_len(&self.to_prost())
+    }
+
+    /// Re-encode this ScopeLogs; see `LogsData::encode`
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        self.check_ids()?;
+        encode_checked(&self.to_prost())
+    }
+
+    fn check_ids(&self) -> Result<(), EncodeError> {
+        self.into_iter().try_for_each(LogRecord::check_ids)
+    }
+}
+
+impl<'a> LogRecord<'a> {
+    pub fn to_prost(&self) -> pb::logs::v1::LogRecord {
+        pb::logs::v1::LogRecord {
+            time_unix_nano: self.time_unix_nano.unwrap_or_default(),
+            observed_time_unix_nano: self.observed_time_unix_nano,
+            severity_number: self.severity_number,
+            severity_text: self.severity_text.unwrap_or_default().to_string(),
+            body: self.body.as_ref().map(AnyValue::to_prost),
+            attributes: self.effective_attributes().map(|attr| attr.to_prost()).collect(),
+            dropped_attributes_count: self.dropped_attributes_count.unwrap_or_default(),
+            flags: self.flags.unwrap_or_default(),
+            trace_id: self.trace_id.unwrap_or_default().to_vec(),
+            span_id: self.span_id.unwrap_or_default().to_vec(),
+            event_name: self.event_name.unwrap_or_default().to_string(),
+        }
+    }
+
+    /// Size of this LogRecord once re-encoded, excluding its own framing
+    pub fn encoded_len(&self) -> usize {
+        prost::Message::encoded_len(&self.to_prost())
+    }
+
+    /// Re-encode this LogRecord; see `LogsData::encode`
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        self.check_ids()?;
+        encode_checked(&self.to_prost())
+    }
+
+    /// Ids are opaque bytes to the parser, so a producer's wrong-length id
+    /// survives parsing; it is caught here rather than re-emitted
+    fn check_ids(&self) -> Result<(), EncodeError> {
+        match self.trace_id {
+            Some(id) if !id.is_empty() && id.len() != 16 => return Err(EncodeError::InvalidTraceIdLength(id.len())),
+            _ => {}
+        }
+        match self.span_id {
+            Some(id) if !id.is_empty() && id.len() != 8 => Err(EncodeError::InvalidSpanIdLength(id.len())),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> Resource<'a> {
+    pub fn to_prost(&self) -> pb::resource::v1::Resource {
+        pb::resource::v1::Resource {
+            attributes: key_values_to_prost(self.attributes()),
+            dropped_attributes_count: self.dropped_attributes_count.unwrap_or_default(),
+            entity_refs: self.entity_refs().iter().map(EntityRef::to_prost).collect(),
+        }
+    }
+}
+
+impl<'a> EntityRef<'a> {
+    pub fn to_prost(&self) -> pb::common::v1::EntityRef {
+        pb::common::v1::EntityRef {
+            schema_url: self.schema_url.unwrap_or_default().to_string(),
+            r#type: self.r#type.unwrap_or_default().to_string(),
+            id_keys: self.id_keys.iter().map(|key| key.to_string()).collect(),
+            description_keys: self.description_keys.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+}
+
+impl<'a> InstrumentationScope<'a> {
+    pub fn to_prost(&self) -> pb::common::v1::InstrumentationScope {
+        pb::common::v1::InstrumentationScope {
+            name: self.name.unwrap_or_default().to_string(),
+            version: self.version.unwrap_or_default().to_string(),
+            attributes: key_values_to_prost(&self.attributes[..self.attributes_used]),
+            dropped_attributes_count: self.dropped_attributes_count.unwrap_or_default(),
+        }
+    }
+}
+
+impl<'a> KeyValue<'a> {
+    pub fn to_prost(&self) -> pb::common::v1::KeyValue {
+        pb::common::v1::KeyValue {
+            key: self.key_cow().into_owned(),
+            value: self.value.as_ref().map(AnyValue::to_prost),
+        }
+    }
+}
+
+impl<'a> AnyValue<'a> {
+    pub fn to_prost(&self) -> pb::common::v1::AnyValue {
+        use pb::common::v1::any_value::Value;
+
+        let value = match &self.value {
+            AnyValueData::String(s) => Value::StringValue(
+                self.string_value_cow().map_or_else(|| s.to_string(), Cow::into_owned),
+            ),
+            AnyValueData::Bool(b) => Value::BoolValue(*b),
+            AnyValueData::Int(i) => Value::IntValue(*i),
+            AnyValueData::Double(d) => Value::DoubleValue(*d),
+            AnyValueData::Array(values) => Value::ArrayValue(pb::common::v1::ArrayValue {
+                values: values.iter().map(AnyValue::to_prost).collect(),
+            }),
+            AnyValueData::KvList(values) => Value::KvlistValue(pb::common::v1::KeyValueList {
+                values: key_values_to_prost(values),
+            }),
+            AnyValueData::Bytes(b) => Value::BytesValue(b.to_vec()),
+        };
+        pb::common::v1::AnyValue { value: Some(value) }
+    }
+}
+
+fn values_for_key<'s, 'a>(attributes: &'s [KeyValue<'a>], key: &'s str) -> impl Iterator<Item = &'s AnyValue<'a>> + 's {
+    attributes
+        .iter()
+        .filter(move |attr| attr.key == key)
+        .filter_map(|attr| attr.value.as_ref())
+}
+
+fn key_values_to_prost(attributes: &[KeyValue]) -> Vec<pb::common::v1::KeyValue> {
+    attributes.iter().map(KeyValue::to_prost).collect()
+}
+
 // Update trait implementations
 // Implement LogsView for LogsData
 impl<'a> LogsView<'a> for LogsData<'a> {
@@ -1037,12 +2398,20 @@ impl<'a> LogsView<'a> for LogsData<'a> {
     fn resources(&'a self) -> Self::ResourcesIter {
         UsedSliceIter::new(&self.resource_logs[..self.used_count])
     }
+
+    fn nth_resource(&'a self, n: usize) -> Option<&'a ResourceLogs<'a>> {
+        self.resource_logs[..self.used_count].get(n)
+    }
 }
 
 // Implement ResourceLogsView for ResourceLogs
 impl<'a> ResourceLogsView<'a> for ResourceLogs<'a> {
     type ScopeLogs = ScopeLogs<'a>;
     type ScopesIter = UsedSliceIter<'a, ScopeLogs<'a>>;
+    type ResourceAttribute = KeyValue<'a>;
+    type ResourceAttributesIter = std::slice::Iter<'a, KeyValue<'a>>;
+    type EntityRef = EntityRef<'a>;
+    type EntityRefsIter = std::slice::Iter<'a, EntityRef<'a>>;
     
     fn resource(&self) -> &str {
         self.resource
@@ -1054,6 +2423,37 @@ impl<'a> ResourceLogsView<'a> for ResourceLogs<'a> {
     fn scopes(&'a self) -> Self::ScopesIter {
         UsedSliceIter::new(&self.scope_logs[..self.scope_logs_used])
     }
+
+    fn nth_scope(&'a self, n: usize) -> Option<&'a ScopeLogs<'a>> {
+        self.scope_logs[..self.scope_logs_used].get(n)
+    }
+    
+    fn resource_attributes(&'a self) -> Self::ResourceAttributesIter {
+        self.resource
+            .as_ref()
+            .map(|r| r.attributes())
+            .unwrap_or(&[])
+            .iter()
+    }
+
+    fn entity_refs(&'a self) -> Self::EntityRefsIter {
+        self.resource
+            .as_ref()
+            .map(|r| r.entity_refs())
+            .unwrap_or(&[])
+            .iter()
+    }
+
+    fn schema_url(&self) -> Option<&str> {
+        self.schema_url.filter(|url| !url.is_empty())
+    }
+
+    fn dropped_attributes_count(&self) -> u32 {
+        self.resource
+            .as_ref()
+            .and_then(|r| r.dropped_attributes_count)
+            .unwrap_or(0)
+    }
 }
 
 // Implement ScopeLogsView for ScopeLogs
@@ -1077,6 +2477,21 @@ impl<'a> ScopeLogsView<'a> for ScopeLogs<'a> {
     fn log_records(&'a self) -> Self::LogRecordsIter {
         UsedSliceIter::new(&self.log_records[..self.log_records_used])
     }
+
+    fn nth_record(&'a self, n: usize) -> Option<&'a LogRecord<'a>> {
+        self.log_records[..self.log_records_used].get(n)
+    }
+
+    fn schema_url(&self) -> Option<&str> {
+        self.schema_url.filter(|url| !url.is_empty())
+    }
+
+    fn dropped_attributes_count(&self) -> u32 {
+        self.scope
+            .as_ref()
+            .and_then(|s| s.dropped_attributes_count)
+            .unwrap_or(0)
+    }
 }
 
 // Implement LogRecordView for LogRecord
@@ -1085,22 +2500,78 @@ impl<'a> LogRecordView<'a> for LogRecord<'a> {
     type AttributesIter = std::slice::Iter<'a, KeyValue<'a>>;
     
     fn name(&self) -> &str {
-        "log_record" // LogRecord doesn't have a name field in the protobuf, use constant
+        self.event_name
+            .filter(|s| !s.is_empty())
+            .or(self.severity_text.filter(|s| !s.is_empty()))
+            .unwrap_or("log_record")
     }
     
-    fn timestamp(&self) -> Option<u64> {
-        self.time_unix_nano.or_else(|| {
-            if self.observed_time_unix_nano != 0 {
-                Some(self.observed_time_unix_nano)
-            } else {
-                None
-            }
-        })
+    fn time_unix_nano(&self) -> Option<u64> {
+        self.time_unix_nano.filter(|&t| t != 0)
+    }
+    
+    fn observed_time_unix_nano(&self) -> Option<u64> {
+        Some(self.observed_time_unix_nano).filter(|&t| t != 0)
+    }
+    
+    fn severity_number(&self) -> i32 {
+        self.severity_number
+    }
+    
+    fn severity_text(&self) -> Option<&str> {
+        self.severity_text.filter(|s| !s.is_empty())
+    }
+
+    fn trace_id(&self) -> Option<&[u8]> {
+        self.trace_id.filter(|id| !id.is_empty())
+    }
+
+    fn span_id(&self) -> Option<&[u8]> {
+        self.span_id.filter(|id| !id.is_empty())
+    }
+
+    fn flags(&self) -> u32 {
+        self.flags.unwrap_or(0)
+    }
+
+    fn event_name(&self) -> Option<&str> {
+        self.event_name.filter(|s| !s.is_empty())
+    }
+    
+    fn body(&self) -> Option<&AnyValue<'a>> {
+        self.body.as_ref()
     }
     
     fn attributes(&'a self) -> Self::AttributesIter {
         self.attributes[..self.attributes_used].iter()
     }
+
+    fn has_attributes(&self) -> bool {
+        self.attributes_used > 0
+    }
+
+    fn dropped_attributes_count(&self) -> u32 {
+        self.dropped_attributes_count.unwrap_or(0)
+    }
+}
+
+// Implement EntityRefView for EntityRef
+impl<'a> EntityRefView for EntityRef<'a> {
+    fn schema_url(&self) -> Option<&str> {
+        self.schema_url.filter(|url| !url.is_empty())
+    }
+
+    fn entity_type(&self) -> Option<&str> {
+        self.r#type.filter(|ty| !ty.is_empty())
+    }
+
+    fn id_keys(&self) -> impl Iterator<Item = &str> {
+        self.id_keys.iter().copied()
+    }
+
+    fn description_keys(&self) -> impl Iterator<Item = &str> {
+        self.description_keys.iter().copied()
+    }
 }
 
 // Implement AttributeView for KeyValue
@@ -1119,6 +2590,7 @@ impl<'a> AttributeView for KeyValue<'a> {
 // Implement AnyValueView for AnyValue
 impl<'a> AnyValueView for AnyValue<'a> {
     type KeyValue = KeyValue<'a>;
+    type ArrayElement = AnyValue<'a>;
     
     fn value_type(&self) -> ValueType {
         match self.value_type() {
@@ -1159,4 +2631,12 @@ impl<'a> AnyValueView for AnyValue<'a> {
     fn as_kvlist(&self) -> Option<&[Self::KeyValue]> {
         self.kvlist_value()
     }
-}
\ No newline at end of file
+    
+    fn array_iter(&self) -> Option<impl Iterator<Item = impl Borrow<AnyValue<'a>>>> {
+        self.array_value().map(|values| values.iter())
+    }
+
+    fn kvlist_iter(&self) -> Option<impl Iterator<Item = impl Borrow<KeyValue<'a>>>> {
+        self.kvlist_value().map(|entries| entries.iter())
+    }
+}
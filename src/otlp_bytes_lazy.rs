@@ -1,6 +1,15 @@
 // use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView, ValueType};
+use crate::{AttributeView, AnyValueView, LogRecordFlags, TraceContext, ValueType};
+use crate::intern::{KeyId, KeyInterner};
+use crate::otlp_bytes::is_scope_logs_tag;
+use crate::util::{flatten_value, ScalarValue, MAX_FLATTEN_DEPTH};
+use std::borrow::{Borrow, Cow};
+use std::cell::OnceCell;
+use std::fmt;
+use std::ops::Range;
 
 /// Base protobuf parser with common functionality
+#[derive(Clone)]
 pub struct ProtobufParser<'a> {
     data: &'a [u8],
 }
@@ -10,6 +19,34 @@ impl<'a> ProtobufParser<'a> {
         Self { data }
     }
 
+    /// Walk the top level of a message and report each field's tag, wire
+    /// type and payload length without interpreting it. Stops at the first
+    /// malformed field; meant for dumping payloads that don't parse as
+    /// expected.
+    pub fn debug_fields(data: &[u8]) -> Vec<(u32, u8, usize)> {
+        ProtobufParser::new(data)
+            .top_level_fields()
+            .into_iter()
+            .map(|(tag, wire_type, payload)| (tag, wire_type, payload.len()))
+            .collect()
+    }
+
+    /// Each top-level field's tag, wire type and payload bytes (the varint
+    /// bytes themselves for wire type 0). Stops at the first malformed field.
+    pub(crate) fn top_level_fields(&self) -> Vec<(u32, u8, &'a [u8])> {
+        self.walk_fields().collect()
+    }
+
+    /// Non-allocating form of `top_level_fields`, for callers that only
+    /// need a single pass over the fields
+    pub(crate) fn walk_fields(&self) -> TopLevelFields<'a> {
+        TopLevelFields {
+            parser: ProtobufParser::new(self.data),
+            pos: 0,
+            done: false,
+        }
+    }
+
     /// Parse a varint from the current position
     #[inline]
     fn parse_varint(&self, mut pos: usize) -> Option<(u64, usize)> {
@@ -49,6 +86,11 @@ impl<'a> ProtobufParser<'a> {
             let byte = self.data[pos];
             pos += 1;
             
+            // The 10th byte holds only bit 63; anything more overflows u64
+            // or makes the varint longer than the 10-byte maximum
+            if shift == 63 && byte > 1 {
+                return None;
+            }
             result |= ((byte & 0x7F) as u64) << shift;
             
             if byte & 0x80 == 0 {
@@ -67,7 +109,7 @@ impl<'a> ProtobufParser<'a> {
         let (length, new_pos) = self.parse_varint(pos)?;
         pos = new_pos;
         
-        let end = pos + length as usize;
+        let end = usize::try_from(length).ok().and_then(|length| pos.checked_add(length))?;
         if end <= self.data.len() {
             Some((&self.data[pos..end], end))
         } else {
@@ -133,16 +175,12 @@ impl<'a> ProtobufParser<'a> {
                     let (_, new_pos) = self.parse_varint(pos)?;
                     new_pos
                 },
-                1 => {
-                    if pos + 8 <= self.data.len() { pos + 8 } else { return None; }
-                },
+                1 if pos + 8 <= self.data.len() => pos + 8,
                 2 => {
                     let (_, new_pos) = self.parse_length_delimited(pos)?;
                     new_pos
                 },
-                5 => {
-                    if pos + 4 <= self.data.len() { pos + 4 } else { return None; }
-                },
+                5 if pos + 4 <= self.data.len() => pos + 4,
                 _ => return None,
             };
         }
@@ -151,6 +189,158 @@ impl<'a> ProtobufParser<'a> {
     }
 }
 
+/// A memory-mapped OTLP capture from `LogsDataParser::from_mmap`
+#[cfg(feature = "mmap")]
+pub struct MappedLogs {
+    mmap: memmap2::Mmap,
+}
+
+#[cfg(feature = "mmap")]
+impl MappedLogs {
+    /// A parser over the mapped bytes
+    pub fn parser(&self) -> LogsDataParser<'_> {
+        LogsDataParser::new(&self.mmap)
+    }
+
+    /// The mapped bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+/// Why `validate_structure` rejected a buffer. Offsets are absolute
+/// positions in the validated buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureError {
+    /// The field at `offset` declares an extent ending at `end`, past its
+    /// enclosing message's end at `parent_end`, so its bytes would alias
+    /// the sibling fields that follow the parent
+    Overlap { offset: usize, end: usize, parent_end: usize },
+    /// The field at `offset` has a bad varint or an unknown wire type
+    Malformed { offset: usize },
+    /// Messages nest deeper than `MAX_STRUCTURE_DEPTH` at `offset`
+    TooDeep { offset: usize },
+}
+
+impl fmt::Display for StructureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructureError::Overlap { offset, end, parent_end } => write!(
+                f,
+                "field at offset {} ends at {}, past its parent's end at {}",
+                offset, end, parent_end
+            ),
+            StructureError::Malformed { offset } => write!(f, "malformed field at offset {}", offset),
+            StructureError::TooDeep { offset } => write!(f, "message nesting too deep at offset {}", offset),
+        }
+    }
+}
+
+impl std::error::Error for StructureError {}
+
+/// Deepest message nesting `validate_structure` descends through
+pub const MAX_STRUCTURE_DEPTH: usize = 64;
+
+/// Message types `validate_structure` knows the sub-message fields of
+#[derive(Clone, Copy)]
+enum MessageKind {
+    LogsData,
+    ResourceLogs,
+    Resource,
+    ScopeLogs,
+    Scope,
+    LogRecord,
+    KeyValue,
+    AnyValue,
+    ArrayValue,
+    KeyValueList,
+}
+
+impl MessageKind {
+    /// Type of the sub-message at `tag`, if that field is a message
+    fn child(self, tag: u32) -> Option<MessageKind> {
+        match (self, tag) {
+            (MessageKind::LogsData, 1) => Some(MessageKind::ResourceLogs),
+            (MessageKind::ResourceLogs, 1) => Some(MessageKind::Resource),
+            (MessageKind::ResourceLogs, tag) if is_scope_logs_tag(tag) => Some(MessageKind::ScopeLogs),
+            (MessageKind::Resource, 1) => Some(MessageKind::KeyValue),
+            (MessageKind::ScopeLogs, 1) => Some(MessageKind::Scope),
+            (MessageKind::ScopeLogs, 2) => Some(MessageKind::LogRecord),
+            (MessageKind::Scope, 3) => Some(MessageKind::KeyValue),
+            (MessageKind::LogRecord, 5) => Some(MessageKind::AnyValue),
+            (MessageKind::LogRecord, 6) => Some(MessageKind::KeyValue),
+            (MessageKind::KeyValue, 2) => Some(MessageKind::AnyValue),
+            (MessageKind::AnyValue, 5) => Some(MessageKind::ArrayValue),
+            (MessageKind::AnyValue, 6) => Some(MessageKind::KeyValueList),
+            (MessageKind::ArrayValue, 1) => Some(MessageKind::AnyValue),
+            (MessageKind::KeyValueList, 1) => Some(MessageKind::KeyValue),
+            _ => None,
+        }
+    }
+}
+
+/// Walk an encoded LogsData through every known sub-message and check
+/// that each field lies inside its parent. Fields are walked in order, so
+/// each starts where the previous ended; a declared length that runs past
+/// the parent is the one way sub-messages can overlap, and is reported as
+/// `StructureError::Overlap` rather than skipped.
+pub fn validate_structure(data: &[u8]) -> Result<(), StructureError> {
+    check_structure(data, 0, MessageKind::LogsData, 0)
+}
+
+fn check_structure(data: &[u8], base: usize, kind: MessageKind, depth: usize) -> Result<(), StructureError> {
+    let mut fields = ProtobufParser::new(data).walk_fields();
+
+    loop {
+        let offset = base + fields.offset();
+        let Some((tag, wire_type, payload)) = fields.next() else {
+            break;
+        };
+        if wire_type == 2 {
+            if let Some(child) = kind.child(tag) {
+                if depth == MAX_STRUCTURE_DEPTH {
+                    return Err(StructureError::TooDeep { offset });
+                }
+                let (start, _) = range_within(data, payload);
+                check_structure(payload, base + start, child, depth + 1)?;
+            }
+        }
+    }
+
+    match fields.offset() {
+        pos if pos < data.len() => Err(field_error(data, base, pos)),
+        _ => Ok(()),
+    }
+}
+
+/// Why the field at `pos` doesn't parse: a declared size running past the
+/// parent is an overlap, anything else is malformed
+fn field_error(data: &[u8], base: usize, pos: usize) -> StructureError {
+    let parser = ProtobufParser::new(data);
+    let end = parser.parse_varint(pos).and_then(|(tag_and_wire, value_pos)| match tag_and_wire & 0x7 {
+        1 => Some(value_pos.saturating_add(8)),
+        5 => Some(value_pos.saturating_add(4)),
+        2 => parser
+            .parse_varint(value_pos)
+            .map(|(len, start)| start.saturating_add(usize::try_from(len).unwrap_or(usize::MAX))),
+        _ => None,
+    });
+    match end {
+        Some(end) if end > data.len() => StructureError::Overlap {
+            offset: base + pos,
+            end: base.saturating_add(end),
+            parent_end: base + data.len(),
+        },
+        _ => StructureError::Malformed { offset: base + pos },
+    }
+}
+
+/// `(start, end)` of `bytes`, a subslice of `data`, as offsets into `data`
+fn range_within(data: &[u8], bytes: &[u8]) -> (usize, usize) {
+    let start = bytes.as_ptr() as usize - data.as_ptr() as usize;
+    (start, start + bytes.len())
+}
+
 /// Zero-allocation parser for LogsData
 pub struct LogsDataParser<'a> {
     parser: ProtobufParser<'a>,
@@ -163,23 +353,189 @@ impl<'a> LogsDataParser<'a> {
         }
     }
 
+    /// Memory-map the OTLP capture at `path` for zero-copy parsing of files
+    /// too large to read onto the heap. The parser borrows the mapping, so
+    /// it is handed back as a `MappedLogs` to take `parser()` from.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the mapping is
+    /// alive: the parser's `&[u8]` views would change underneath it.
+    #[cfg(feature = "mmap")]
+    pub unsafe fn from_mmap(path: impl AsRef<std::path::Path>) -> std::io::Result<MappedLogs> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: the caller guarantees the file is not modified while mapped
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(MappedLogs { mmap })
+    }
+
+    /// Strict mode: check the whole hierarchy with `validate_structure`
+    /// before handing out a parser. The lazy iterators stop quietly at a
+    /// field whose length runs past its parent; this reports it instead.
+    pub fn new_strict(data: &'a [u8]) -> Result<Self, StructureError> {
+        validate_structure(data)?;
+        Ok(Self::new(data))
+    }
+
+    /// The ResourceLogs at index `n`. Earlier ones are skipped over by
+    /// their length prefixes without being parsed.
+    pub fn nth_resource(&self, n: usize) -> Option<ResourceLogsParser<'a>> {
+        MessageFields::new(self.parser.data, 1).nth(n).map(ResourceLogsParser::new)
+    }
+
     /// Get iterator over ResourceLogs (tag 1, repeated message)
     pub fn resource_logs(&'a self) -> ResourceLogsIterator<'a> {
         ResourceLogsIterator {
-            parser: &self.parser,
-            pos: 0,
+            fields: MessageFields::new(self.parser.data, 1),
         }
     }
+
+    /// Like `resource_logs`, also yielding the byte offset at which each
+    /// ResourceLogs message starts in the buffer (just past its tag and
+    /// length prefix), for correlating errors with buffer positions
+    pub fn resource_logs_indexed(&'a self) -> impl Iterator<Item = (usize, ResourceLogsParser<'a>)> {
+        let base = self.parser.data.as_ptr() as usize;
+        self.resource_logs()
+            .map(move |resource| (resource.parser.data.as_ptr() as usize - base, resource))
+    }
+
+    /// Byte range of each ResourceLogs payload within the buffer (past its
+    /// tag and length prefix), for sharding a batch across workers: each
+    /// `ResourceLogsParser::new(&data[range])` parses one resource on its own
+    pub fn resource_ranges(&self) -> Vec<Range<usize>> {
+        let base = self.parser.data.as_ptr() as usize;
+        MessageFields::new(self.parser.data, 1)
+            .map(|bytes| {
+                let start = bytes.as_ptr() as usize - base;
+                start..start + bytes.len()
+            })
+            .collect()
+    }
+
+    /// Size of the whole encoded buffer, including the framing of every
+    /// ResourceLogs field
+    pub fn total_encoded_len(&self) -> usize {
+        self.parser.data.len()
+    }
+
+    /// Collect every log record in one linear pass into a flat Vec, for
+    /// analytics that touch every record and would otherwise re-descend the
+    /// nested iterators
+    pub fn collect_records(&self) -> Vec<FlatRecord<'a>> {
+        let base = self.parser.data.as_ptr() as usize;
+        let mut records = Vec::new();
+
+        for (resource_index, resource_bytes) in MessageFields::new(self.parser.data, 1).enumerate() {
+            for (scope_index, scope_bytes) in MessageFields::scope_logs(resource_bytes).enumerate() {
+                for record_bytes in MessageFields::new(scope_bytes, 2) {
+                    records.push(FlatRecord {
+                        resource_index,
+                        scope_index,
+                        offset: record_bytes.as_ptr() as usize - base,
+                        len: record_bytes.len(),
+                        record: LogRecordParser::new(record_bytes),
+                    });
+                }
+            }
+        }
+
+        records
+    }
 }
 
-/// Iterator over ResourceLogs messages
-pub struct ResourceLogsIterator<'a> {
-    parser: &'a ProtobufParser<'a>,
+/// A log record collected by `LogsDataParser::collect_records`
+pub struct FlatRecord<'a> {
+    pub resource_index: usize,
+    /// Index of the scope within its resource
+    pub scope_index: usize,
+    /// Byte offset of the record's payload within the LogsData buffer
+    pub offset: usize,
+    /// Length of the record's payload in bytes
+    pub len: usize,
+    pub record: LogRecordParser<'a>,
+}
+
+/// Iterator over a message's top-level fields, yielded as by
+/// `ProtobufParser::top_level_fields`
+pub(crate) struct TopLevelFields<'a> {
+    parser: ProtobufParser<'a>,
     pos: usize,
+    done: bool,
 }
 
-impl<'a> Iterator for ResourceLogsIterator<'a> {
-    type Item = ResourceLogsParser<'a>;
+impl TopLevelFields<'_> {
+    /// Offset of the next field's tag. Once the walk has ended, this is the
+    /// message length, or the start of the malformed field it stopped at.
+    pub(crate) fn offset(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> Iterator for TopLevelFields<'a> {
+    type Item = (u32, u8, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.pos >= self.parser.data.len() {
+            return None;
+        }
+        let data = self.parser.data;
+        let Some((tag_and_wire, new_pos)) = self.parser.parse_varint(self.pos) else {
+            self.done = true;
+            return None;
+        };
+        let tag = (tag_and_wire >> 3) as u32;
+        let wire_type = (tag_and_wire & 0x7) as u8;
+
+        let next = match wire_type {
+            0 => self.parser.parse_varint(new_pos).map(|(_, end)| (&data[new_pos..end], end)),
+            1 if new_pos + 8 <= data.len() => Some((&data[new_pos..new_pos + 8], new_pos + 8)),
+            2 => self.parser.parse_length_delimited(new_pos),
+            5 if new_pos + 4 <= data.len() => Some((&data[new_pos..new_pos + 4], new_pos + 4)),
+            _ => None,
+        };
+        let Some((payload, end)) = next else {
+            // Malformed: stop here and stay stopped
+            self.done = true;
+            return None;
+        };
+
+        self.pos = end;
+        Some((tag, wire_type, payload))
+    }
+}
+
+/// Iterator over the payloads of length-delimited fields with a given tag.
+/// The public message iterators wrap one, mapping payloads to parsers.
+#[derive(Clone)]
+struct MessageFields<'a> {
+    parser: ProtobufParser<'a>,
+    tag: u32,
+    /// Also accept every tag `is_scope_logs_tag` does
+    scope_logs: bool,
+    pos: usize,
+}
+
+impl<'a> MessageFields<'a> {
+    fn new(data: &'a [u8], tag: u32) -> Self {
+        Self {
+            parser: ProtobufParser::new(data),
+            tag,
+            scope_logs: false,
+            pos: 0,
+        }
+    }
+
+    /// The ScopeLogs messages of a ResourceLogs
+    fn scope_logs(data: &'a [u8]) -> Self {
+        Self {
+            scope_logs: true,
+            ..Self::new(data, 2)
+        }
+    }
+}
+
+impl<'a> Iterator for MessageFields<'a> {
+    type Item = &'a [u8];
 
     fn next(&mut self) -> Option<Self::Item> {
         while self.pos < self.parser.data.len() {
@@ -189,10 +545,10 @@ impl<'a> Iterator for ResourceLogsIterator<'a> {
             let tag = (tag_and_wire >> 3) as u32;
             let wire_type = (tag_and_wire & 0x7) as u8;
 
-            if tag == 1 && wire_type == 2 {
+            if (tag == self.tag || (self.scope_logs && is_scope_logs_tag(tag))) && wire_type == 2 {
                 let (bytes, end_pos) = self.parser.parse_length_delimited(self.pos)?;
                 self.pos = end_pos;
-                return Some(ResourceLogsParser::new(bytes));
+                return Some(bytes);
             } else {
                 // Skip field
                 self.pos = match wire_type {
@@ -208,263 +564,535 @@ impl<'a> Iterator for ResourceLogsIterator<'a> {
     }
 }
 
+/// Iterator over ResourceLogs messages
+pub struct ResourceLogsIterator<'a> {
+    fields: MessageFields<'a>,
+}
+
+impl<'a> Iterator for ResourceLogsIterator<'a> {
+    type Item = ResourceLogsParser<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fields.next().map(ResourceLogsParser::new)
+    }
+}
+
 /// Zero-allocation parser for ResourceLogs
 pub struct ResourceLogsParser<'a> {
     parser: ProtobufParser<'a>,
+    cache: OnceCell<ResourceCache>,
 }
 
 impl<'a> ResourceLogsParser<'a> {
     pub fn new(data: &'a [u8]) -> Self {
         Self {
             parser: ProtobufParser::new(data),
+            cache: OnceCell::new(),
         }
     }
 
-    /// Get the resource field (tag 1, optional message) - returns raw bytes
-    pub fn resource(&self) -> Option<&'a [u8]> {
-        self.parser.find_field(1).and_then(|(wire_type, pos)| {
-            if wire_type == 2 {
-                self.parser.parse_length_delimited(pos).map(|(bytes, _)| bytes)
-            } else {
-                None
+    /// Locate the resource's attributes once and cache their byte ranges,
+    /// so repeated lookups don't rescan the Resource message
+    fn get_cache(&self) -> &ResourceCache {
+        self.cache.get_or_init(|| {
+            let mut cache = ResourceCache::default();
+            let Some(resource) = self.resource() else {
+                return cache;
+            };
+
+            for (tag, wire_type, bytes) in ProtobufParser::new(resource).walk_fields() {
+                if tag != 1 || wire_type != 2 {
+                    continue;
+                }
+                if cache.service_name.is_none() && KeyValueParser::new(bytes).key() == Some("service.name") {
+                    cache.service_name = Some(cache.attributes.len());
+                }
+                cache.attributes.push(range_within(self.parser.data, bytes));
             }
+            cache
         })
     }
 
+    fn cached_attribute(&self, index: usize) -> KeyValueParser<'a> {
+        let (start, end) = self.get_cache().attributes[index];
+        KeyValueParser::new(&self.parser.data[start..end])
+    }
+
+    /// Number of resource attributes, from the cache
+    pub fn resource_attribute_count(&self) -> usize {
+        self.get_cache().attributes.len()
+    }
+
+    /// Value of the first resource attribute with `key`, from the cache
+    pub fn resource_attribute(&self, key: &str) -> Option<AnyValueParser<'a>> {
+        (0..self.resource_attribute_count())
+            .map(|index| self.cached_attribute(index))
+            .find(|attr| attr.key() == Some(key))
+            .and_then(|attr| attr.value())
+    }
+
+    /// The `service.name` resource attribute. Its position is cached, so
+    /// repeated calls cost a single field read rather than a scan.
+    pub fn service_name(&self) -> Option<&'a str> {
+        let index = self.get_cache().service_name?;
+        self.cached_attribute(index).value()?.string_value()
+    }
+
+    /// The exact encoded bytes this parser was constructed from, for
+    /// re-emitting the message untouched
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.parser.data
+    }
+
+    /// Size of this message's encoding, excluding the tag and length prefix
+    /// of the enclosing field
+    pub fn encoded_len(&self) -> usize {
+        self.parser.data.len()
+    }
+
+    /// Get the resource field (tag 1, optional message) - returns raw bytes
+    pub fn resource(&self) -> Option<&'a [u8]> {
+        // Like prost, the last occurrence of the field wins
+        MessageFields::new(self.parser.data, 1).last()
+    }
+
     /// Get iterator over resource attributes
     pub fn attributes(&'a self) -> Option<ResourceAttributeIterator<'a>> {
-        // First get the resource field bytes
-        if let Some(resource_bytes) = self.resource() {
-            Some(ResourceAttributeIterator {
-                parser: ProtobufParser::new(resource_bytes),
-                pos: 0,
-            })
-        } else {
-            None
-        }
+        // Resource attributes are at tag 1 in the Resource message
+        self.resource().map(|resource_bytes| ResourceAttributeIterator {
+            fields: MessageFields::new(resource_bytes, 1),
+        })
     }
     
     /// Get iterator over ScopeLogs (tag 2, repeated message)
     pub fn scope_logs(&'a self) -> ScopeLogsIterator<'a> {
         ScopeLogsIterator {
-            parser: &self.parser,
-            pos: 0,
+            fields: MessageFields::scope_logs(self.parser.data),
         }
     }
 
-    /// Get the schema_url field (tag 3, string)
+    /// The ScopeLogs at index `n`, skipping earlier ones unparsed
+    pub fn nth_scope(&self, n: usize) -> Option<ScopeLogsParser<'a>> {
+        MessageFields::scope_logs(self.parser.data).nth(n).map(ScopeLogsParser::new)
+    }
+
+    /// Get the schema_url field (tag 3, string); `None` when absent or empty
     pub fn schema_url(&self) -> Option<&'a str> {
-        self.parser.find_field(3).and_then(|(wire_type, pos)| {
-            if wire_type == 2 {
-                self.parser.parse_length_delimited(pos)
-                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
-            } else {
-                None
-            }
-        })
+        // Like prost, the last occurrence of the field wins
+        MessageFields::new(self.parser.data, 3)
+            .last()
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .filter(|url| !url.is_empty())
     }
 }
 
 /// Iterator over resource attribute KeyValue messages
 pub struct ResourceAttributeIterator<'a> {
-    parser: ProtobufParser<'a>,
-    pos: usize,
+    fields: MessageFields<'a>,
 }
 
 impl<'a> Iterator for ResourceAttributeIterator<'a> {
     type Item = KeyValueParser<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.pos < self.parser.data.len() {
-            let (tag_and_wire, new_pos) = self.parser.parse_varint(self.pos)?;
-            self.pos = new_pos;
+        self.fields.next().map(KeyValueParser::new)
+    }
 
-            let tag = (tag_and_wire >> 3) as u32;
-            let wire_type = (tag_and_wire & 0x7) as u8;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
 
-            // Resource attributes are at tag 1 in the Resource message
-            if tag == 1 && wire_type == 2 {
-                let (bytes, end_pos) = self.parser.parse_length_delimited(self.pos)?;
-                self.pos = end_pos;
-                return Some(KeyValueParser::new(bytes));
-            } else {
-                // Skip field
-                self.pos = match wire_type {
-                    0 => self.parser.parse_varint(self.pos)?.1,
-                    1 => self.pos + 8,
-                    2 => self.parser.parse_length_delimited(self.pos)?.1,
-                    5 => self.pos + 4,
-                    _ => return None,
-                };
-            }
-        }
-        None
+    fn count(self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<'a> ResourceAttributeIterator<'a> {
+    /// Attributes left, counted without building KeyValueParsers
+    fn remaining(&self) -> usize {
+        self.fields.clone().count()
     }
 }
 
 /// Iterator over ScopeLogs messages
 pub struct ScopeLogsIterator<'a> {
-    parser: &'a ProtobufParser<'a>,
-    pos: usize,
+    fields: MessageFields<'a>,
 }
 
 impl<'a> Iterator for ScopeLogsIterator<'a> {
     type Item = ScopeLogsParser<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.pos < self.parser.data.len() {
-            let (tag_and_wire, new_pos) = self.parser.parse_varint(self.pos)?;
-            self.pos = new_pos;
+        self.fields.next().map(ScopeLogsParser::new)
+    }
 
-            let tag = (tag_and_wire >> 3) as u32;
-            let wire_type = (tag_and_wire & 0x7) as u8;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
 
-            if tag == 2 && wire_type == 2 {
-                let (bytes, end_pos) = self.parser.parse_length_delimited(self.pos)?;
-                self.pos = end_pos;
-                return Some(ScopeLogsParser::new(bytes));
-            } else {
-                // Skip field
-                self.pos = match wire_type {
-                    0 => self.parser.parse_varint(self.pos)?.1,
-                    1 => self.pos + 8,
-                    2 => self.parser.parse_length_delimited(self.pos)?.1,
-                    5 => self.pos + 4,
-                    _ => return None,
-                };
-            }
-        }
-        None
+    fn count(self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<'a> ScopeLogsIterator<'a> {
+    /// ScopeLogs left, counted without building ScopeLogsParsers
+    fn remaining(&self) -> usize {
+        self.fields.clone().count()
     }
 }
 
 /// Zero-allocation parser for ScopeLogs
 pub struct ScopeLogsParser<'a> {
     parser: ProtobufParser<'a>,
+    scope_cache: OnceCell<ScopeMetadata>,
+}
+
+/// Locations of the InstrumentationScope name and version within the
+/// ScopeLogs bytes, found in a single pass. Stored as offsets rather than
+/// slices so the cache doesn't make the parser invariant over `'a`.
+#[derive(Default, Clone, Copy)]
+struct ScopeMetadata {
+    name: Option<(usize, usize)>,
+    version: Option<(usize, usize)>,
 }
 
 impl<'a> ScopeLogsParser<'a> {
     pub fn new(data: &'a [u8]) -> Self {
         Self {
             parser: ProtobufParser::new(data),
+            scope_cache: OnceCell::new(),
         }
     }
 
+    /// The exact encoded bytes this parser was constructed from, for
+    /// re-emitting the message untouched
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.parser.data
+    }
+
+    /// Size of this message's encoding, excluding the tag and length prefix
+    /// of the enclosing field
+    pub fn encoded_len(&self) -> usize {
+        self.parser.data.len()
+    }
+
     /// Get the scope field (tag 1, optional message) - returns raw bytes
     pub fn scope(&self) -> Option<&'a [u8]> {
-        self.parser.find_field(1).and_then(|(wire_type, pos)| {
-            if wire_type == 2 {
-                self.parser.parse_length_delimited(pos).map(|(bytes, _)| bytes)
-            } else {
-                None
-            }
-        })
+        // Like prost, the last occurrence of the field wins
+        MessageFields::new(self.parser.data, 1).last()
     }
 
     /// Get iterator over LogRecord (tag 2, repeated message)
     pub fn log_records(&'a self) -> LogRecordIterator<'a> {
         LogRecordIterator {
-            parser: &self.parser,
-            pos: 0,
+            fields: MessageFields::new(self.parser.data, 2),
         }
     }
 
-    /// Get the schema_url field (tag 3, string)
+    /// The LogRecord at index `n`, skipping earlier ones unparsed
+    pub fn nth_record(&self, n: usize) -> Option<LogRecordParser<'a>> {
+        MessageFields::new(self.parser.data, 2).nth(n).map(LogRecordParser::new)
+    }
+
+    /// Iterate records with parsers that only cache the fields in `projection`
+    pub fn log_records_with_projection(&'a self, projection: ProjectionMask) -> impl Iterator<Item = LogRecordParser<'a>> {
+        self.log_records()
+            .map(move |record| LogRecordParser::with_projection(record.raw_bytes(), projection))
+    }
+
+    /// Iterate only the records with `severity_number >= min`. Each record's
+    /// severity is probed with a bare field scan, so records that are
+    /// skipped never build their `FieldCache`.
+    pub fn log_records_min_severity(&'a self, min: i32) -> impl Iterator<Item = LogRecordParser<'a>> {
+        self.log_records()
+            .filter(move |record| record.probe_severity_number() >= min)
+    }
+
+    /// Iterate only the records whose resolved timestamp (time, else
+    /// observed time) lies in `start..end`. Like `log_records_min_severity`,
+    /// timestamps are probed without building each record's `FieldCache`.
+    pub fn records_in_range(&'a self, start: u64, end: u64) -> impl Iterator<Item = LogRecordParser<'a>> {
+        self.log_records()
+            .filter(move |record| record.probe_effective_timestamp().is_some_and(|ts| (start..end).contains(&ts)))
+    }
+
+    /// Get the schema_url field (tag 3, string); `None` when absent or empty
     pub fn schema_url(&self) -> Option<&'a str> {
-        self.parser.find_field(3).and_then(|(wire_type, pos)| {
-            if wire_type == 2 {
-                self.parser.parse_length_delimited(pos)
-                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok())
-            } else {
-                None
+        // Like prost, the last occurrence of the field wins
+        MessageFields::new(self.parser.data, 3)
+            .last()
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .filter(|url| !url.is_empty())
+    }
+
+    /// Find the scope message once and locate name and version in a single
+    /// scan, instead of re-scanning for each accessor. Like prost, the last
+    /// occurrence of each field wins.
+    fn scope_metadata(&self) -> ScopeMetadata {
+        *self.scope_cache.get_or_init(|| {
+            let mut metadata = ScopeMetadata::default();
+            let Some(scope_bytes) = self.scope() else {
+                return metadata;
+            };
+
+            for (tag, wire_type, bytes) in ProtobufParser::new(scope_bytes).walk_fields() {
+                // Fields 1 and 2 in InstrumentationScope are name and version
+                match (tag, wire_type) {
+                    (1, 2) => metadata.name = Some(range_within(self.parser.data, bytes)),
+                    (2, 2) => metadata.version = Some(range_within(self.parser.data, bytes)),
+                    _ => {}
+                }
             }
+            metadata
         })
     }
 
+    fn scope_str(&self, range: Option<(usize, usize)>) -> Option<&'a str> {
+        let (start, end) = range?;
+        std::str::from_utf8(&self.parser.data[start..end]).ok()
+    }
+
     /// Get the scope name as a readable string
     pub fn scope_name(&self) -> &'a str {
-        if let Some(scope_bytes) = self.scope() {
-            let scope_parser = ProtobufParser::new(scope_bytes);
-            // Field 1 in InstrumentationScope is the name (string)
-            if let Some((wire_type, pos)) = scope_parser.find_field(1) {
-                if wire_type == 2 {
-                    if let Some((bytes, _)) = scope_parser.parse_length_delimited(pos) {
-                        return std::str::from_utf8(bytes).unwrap_or("");
-                    }
-                }
-            }
-        }
-        ""
+        self.scope_str(self.scope_metadata().name).unwrap_or("")
     }
 
     /// Get the scope version as a readable string
     pub fn scope_version(&self) -> Option<&'a str> {
-        if let Some(scope_bytes) = self.scope() {
-            let scope_parser = ProtobufParser::new(scope_bytes);
-            // Field 2 in InstrumentationScope is the version (string)
-            if let Some((wire_type, pos)) = scope_parser.find_field(2) {
-                if wire_type == 2 {
-                    if let Some((bytes, _)) = scope_parser.parse_length_delimited(pos) {
-                        let version = std::str::from_utf8(bytes).unwrap_or("");
-                        return if version.is_empty() { None } else { Some(version) };
-                    }
-                }
-            }
-        }
-        None
+        self.scope_str(self.scope_metadata().version).filter(|v| !v.is_empty())
     }
 }
 
 /// Iterator over LogRecord messages
 pub struct LogRecordIterator<'a> {
-    parser: &'a ProtobufParser<'a>,
-    pos: usize,
+    fields: MessageFields<'a>,
 }
 
 impl<'a> Iterator for LogRecordIterator<'a> {
     type Item = LogRecordParser<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.pos < self.parser.data.len() {
-            let (tag_and_wire, new_pos) = self.parser.parse_varint(self.pos)?;
-            self.pos = new_pos;
+        self.fields.next().map(LogRecordParser::new)
+    }
 
-            let tag = (tag_and_wire >> 3) as u32;
-            let wire_type = (tag_and_wire & 0x7) as u8;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
 
-            if tag == 2 && wire_type == 2 {
-                let (bytes, end_pos) = self.parser.parse_length_delimited(self.pos)?;
-                self.pos = end_pos;
-                return Some(LogRecordParser::new(bytes));
-            } else {
-                // Skip field
-                self.pos = match wire_type {
-                    0 => self.parser.parse_varint(self.pos)?.1,
-                    1 => self.pos + 8,
-                    2 => self.parser.parse_length_delimited(self.pos)?.1,
-                    5 => self.pos + 4,
-                    _ => return None,
-                };
+    fn count(self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<'a> LogRecordIterator<'a> {
+    /// Records left, counted without building LogRecordParsers
+    fn remaining(&self) -> usize {
+        self.fields.clone().count()
+    }
+}
+
+/// Set of LogRecord fields to populate when building the field cache.
+/// Each constant is the bit for the field's protobuf tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProjectionMask(u16);
+
+impl ProjectionMask {
+    pub const TIME_UNIX_NANO: Self = Self(1 << 1);
+    pub const SEVERITY_NUMBER: Self = Self(1 << 2);
+    pub const SEVERITY_TEXT: Self = Self(1 << 3);
+    pub const BODY: Self = Self(1 << 5);
+    pub const ATTRIBUTES: Self = Self(1 << 6);
+    pub const DROPPED_ATTRIBUTES_COUNT: Self = Self(1 << 7);
+    pub const FLAGS: Self = Self(1 << 8);
+    pub const TRACE_ID: Self = Self(1 << 9);
+    pub const SPAN_ID: Self = Self(1 << 10);
+    pub const OBSERVED_TIME_UNIX_NANO: Self = Self(1 << 11);
+    pub const EVENT_NAME: Self = Self(1 << 12);
+
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self(u16::MAX);
+
+    /// Whether every field in `other` is also in `self`
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether the field with protobuf tag `tag` is selected
+    fn includes_tag(self, tag: u32) -> bool {
+        tag < 16 && self.0 & (1 << tag) != 0
+    }
+}
+
+impl std::ops::BitOr for ProjectionMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ProjectionMask {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// How a LogRecordParser treats fields sent with an unexpected wire type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Accessors read mismatched fields as absent
+    #[default]
+    Lenient,
+    /// Accessors still read mismatched fields as absent, but each mismatch
+    /// is also recorded and retrievable via `LogRecordParser::errors`
+    Strict,
+}
+
+/// A known LogRecord field that arrived with the wrong wire type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WireTypeMismatch {
+    pub tag: u32,
+    pub expected: u8,
+    pub actual: u8,
+    /// Offset of the field's tag within the record
+    pub offset: usize,
+}
+
+impl fmt::Display for WireTypeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "field {} at offset {} has wire type {}, expected {}",
+            self.tag, self.offset, self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for WireTypeMismatch {}
+
+/// A LogRecord field that is present but can't be read, from the
+/// `*_checked` accessors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The record ends before the field's value does. `offset` is where
+    /// the value starts within the record.
+    Truncated { tag: u32, offset: usize, needed: usize, available: usize },
+    /// The field was sent with a wire type its schema doesn't allow
+    WrongWireType { tag: u32, expected: u8, actual: u8 },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Truncated { tag, offset, needed, available } => write!(
+                f,
+                "field {} at offset {} needs {} bytes, only {} remain",
+                tag, offset, needed, available
+            ),
+            ParseError::WrongWireType { tag, expected, actual } => {
+                write!(f, "field {} has wire type {}, expected {}", tag, actual, expected)
             }
         }
-        None
     }
 }
 
+impl std::error::Error for ParseError {}
+
+/// How `LogRecordParser::to_compact_with` treats array and kvlist attributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NestedAttributePolicy {
+    /// Leave non-scalar attributes out
+    #[default]
+    Drop,
+    /// Flatten them into dotted keys as `util::flatten_attributes` does
+    Flatten,
+}
+
+/// Owned, `'static` copy of a record's commonly used fields, for sending
+/// across channels without keeping the source buffer alive. Lighter than a
+/// full owned tree: attributes are kept as flat scalars only.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompactRecord {
+    pub time_unix_nano: u64,
+    pub severity_number: i32,
+    /// String bodies verbatim; other bodies in their expanded display form
+    pub body: Option<String>,
+    pub attributes: Vec<(String, ScalarValue)>,
+}
+
 /// Zero-allocation parser for LogRecord
 pub struct LogRecordParser<'a> {
     parser: ProtobufParser<'a>,
     cache: std::cell::OnceCell<FieldCache>, // Add this field
+    projection: ProjectionMask,
+    mode: ParseMode,
+    /// Set by `with_validated_strings`. Kept outside the `OnceCell` so the
+    /// parser stays covariant over `'a`.
+    validated: Option<ValidatedStrings<'a>>,
 }
 
 impl<'a> LogRecordParser<'a> {
     pub fn new(data: &'a [u8]) -> Self {
+        Self::with_projection(data, ProjectionMask::ALL)
+    }
+
+    /// Create a parser that only caches the fields in `projection`. Fields
+    /// outside it are skipped while building the cache, so their accessors
+    /// read as absent (`attributes()` is empty, `body()` is `None`, ...).
+    pub fn with_projection(data: &'a [u8], projection: ProjectionMask) -> Self {
         Self {
             parser: ProtobufParser::new(data),
             cache: std::cell::OnceCell::new(), // Initialize the cache
+            projection,
+            mode: ParseMode::Lenient,
+            validated: None,
         }
     }
 
+    /// Set how fields with an unexpected wire type are reported
+    pub fn with_mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        // `with_validated_strings` may have built the cache under the old mode
+        self.cache = std::cell::OnceCell::new();
+        self
+    }
+
+    /// Build the field cache now, validate `severity_text` and `event_name`
+    /// once and keep the resulting `&str`, instead of re-checking UTF-8 on
+    /// every accessor call. Worth it when those fields are read more than
+    /// once per record; otherwise it only moves the work earlier.
+    pub fn with_validated_strings(mut self) -> Self {
+        let cache = self.get_cache();
+        let validated = ValidatedStrings {
+            severity_text: self.string_at(cache.severity_text),
+            event_name: self.string_at(cache.event_name),
+        };
+        self.validated = Some(validated);
+        self
+    }
+
+    /// Wire-type mismatches found while building the field cache. Always
+    /// empty in lenient mode; projected-out fields are not checked.
+    pub fn errors(&self) -> &[WireTypeMismatch] {
+        &self.get_cache().errors
+    }
+
+    /// The exact encoded bytes this parser was constructed from, for
+    /// re-emitting the message untouched
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.parser.data
+    }
+
+    /// Size of this message's encoding, excluding the tag and length prefix
+    /// of the enclosing field
+    pub fn encoded_len(&self) -> usize {
+        self.parser.data.len()
+    }
+
     /// Parse all fields once and cache their positions
     fn get_cache(&self) -> &FieldCache {
         self.cache.get_or_init(|| {
@@ -473,12 +1101,22 @@ impl<'a> LogRecordParser<'a> {
             
             while pos < self.parser.data.len() {
                 if let Some((tag_and_wire, new_pos)) = self.parser.parse_varint(pos) {
+                    let offset = pos;
                     pos = new_pos;
                     let tag = (tag_and_wire >> 3) as u32;
                     let wire_type = (tag_and_wire & 0x7) as u8;
+
+                    if self.mode == ParseMode::Strict && self.projection.includes_tag(tag) {
+                        if let Some(expected) = expected_wire_type(tag) {
+                            if expected != wire_type {
+                                cache.errors.push(WireTypeMismatch { tag, expected, actual: wire_type, offset });
+                            }
+                        }
+                    }
                     
-                    // Cache field positions based on tag
+                    // Cache field positions based on tag, skipping unprojected fields
                     match tag {
+                        _ if !self.projection.includes_tag(tag) => {}
                         1 => cache.time_unix_nano = Some((wire_type, pos)),
                         2 => cache.severity_number = Some((wire_type, pos)),
                         3 => cache.severity_text = Some((wire_type, pos)),
@@ -529,46 +1167,179 @@ impl<'a> LogRecordParser<'a> {
                     break;
                 }
             }
+
             cache
         })
     }
 
-    pub fn time_unix_nano(&self) -> u64 {
-        if let Some((wire_type, pos)) = self.get_cache().time_unix_nano {
-            if wire_type == 1 {
-                return self.parser.parse_fixed64(pos).map(|(value, _)| value).unwrap_or(0);
-            }
+    /// Decode a cached string field position, `None` if the field is
+    /// absent, has the wrong wire type or is not valid UTF-8
+    fn string_at(&self, field: Option<(u8, usize)>) -> Option<&'a str> {
+        match field? {
+            (2, pos) => self.parser.parse_length_delimited(pos)
+                .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok()),
+            _ => None,
+        }
+    }
+
+    /// Get the wire type actually present for a field tag, for reporting
+    /// malformed payloads (e.g. severity_number sent as a string). Known
+    /// tags report the occurrence the accessors read (none if projected
+    /// out); unknown tags are scanned for their first occurrence.
+    pub fn field_wire_type(&self, tag: u32) -> Option<u8> {
+        let cache = self.get_cache();
+        let field = match tag {
+            1 => cache.time_unix_nano,
+            2 => cache.severity_number,
+            3 => cache.severity_text,
+            5 => cache.body,
+            6 => cache.attributes.first().copied(),
+            7 => cache.dropped_attributes_count,
+            8 => cache.flags,
+            9 => cache.trace_id,
+            10 => cache.span_id,
+            11 => cache.observed_time_unix_nano,
+            12 => cache.event_name,
+            _ => self.parser.find_field(tag),
+        };
+        field.map(|(wire_type, _)| wire_type)
+    }
+
+    pub fn time_unix_nano(&self) -> u64 {
+        if let Some((wire_type, pos)) = self.get_cache().time_unix_nano {
+            if wire_type == 1 {
+                return self.parser.parse_fixed64(pos).map(|(value, _)| value).unwrap_or(0);
+            }
+        }
+        0
+    }
+
+    pub fn observed_time_unix_nano(&self) -> u64 {
+        if let Some((wire_type, pos)) = self.get_cache().observed_time_unix_nano {
+            if wire_type == 1 {
+                return self.parser.parse_fixed64(pos).map(|(value, _)| value).unwrap_or(0);
+            }
+        }
+        0
+    }
+
+    /// Like `time_unix_nano`, but a field that is present yet unreadable
+    /// is an error instead of 0: a truncated fixed64 (the record ends
+    /// before its 8 bytes) or a non-fixed64 wire type. `Ok(None)` when the
+    /// field is absent or projected out; an explicit 0 is `Ok(Some(0))`.
+    pub fn time_unix_nano_checked(&self) -> Result<Option<u64>, ParseError> {
+        self.fixed64_checked(1, self.get_cache().time_unix_nano)
+    }
+
+    /// `observed_time_unix_nano` with the checks of `time_unix_nano_checked`
+    pub fn observed_time_unix_nano_checked(&self) -> Result<Option<u64>, ParseError> {
+        self.fixed64_checked(11, self.get_cache().observed_time_unix_nano)
+    }
+
+    fn fixed64_checked(&self, tag: u32, field: Option<(u8, usize)>) -> Result<Option<u64>, ParseError> {
+        match field {
+            None => Ok(None),
+            Some((1, offset)) => match self.parser.parse_fixed64(offset) {
+                Some((value, _)) => Ok(Some(value)),
+                None => Err(ParseError::Truncated {
+                    tag,
+                    offset,
+                    needed: 8,
+                    available: self.parser.data.len().saturating_sub(offset),
+                }),
+            },
+            Some((actual, _)) => Err(ParseError::WrongWireType { tag, expected: 1, actual }),
+        }
+    }
+
+    /// The 8 little-endian bytes of time_unix_nano exactly as on the wire,
+    /// for copying verbatim when passing a record through. `None` when the
+    /// field is absent or not a fixed64.
+    pub fn time_unix_nano_raw(&self) -> Option<&'a [u8]> {
+        self.raw_fixed64(self.get_cache().time_unix_nano)
+    }
+
+    /// The raw bytes of observed_time_unix_nano; see `time_unix_nano_raw`
+    pub fn observed_time_unix_nano_raw(&self) -> Option<&'a [u8]> {
+        self.raw_fixed64(self.get_cache().observed_time_unix_nano)
+    }
+
+    fn raw_fixed64(&self, field: Option<(u8, usize)>) -> Option<&'a [u8]> {
+        match field {
+            Some((1, pos)) => self.parser.data.get(pos..pos + 8),
+            _ => None,
+        }
+    }
+
+    /// Resolve the record's timestamp with the same precedence as
+    /// `LogRecordView::effective_timestamp`: time, then observed time,
+    /// treating zero as unset
+    pub fn effective_timestamp(&self) -> Option<u64> {
+        Some(self.time_unix_nano())
+            .filter(|&t| t != 0)
+            .or_else(|| Some(self.observed_time_unix_nano()).filter(|&t| t != 0))
+    }
+
+    /// Get severity_number, reading an absent field as 0 (UNSPECIFIED)
+    pub fn severity_number(&self) -> i32 {
+        self.severity_number_opt().unwrap_or(0)
+    }
+
+    /// Get severity_number (tag 2, enum), or `None` when the field is not
+    /// on the wire. Encoders omit default values, so an explicit 0 is rare
+    /// but distinguishable here from no severity at all.
+    pub fn severity_number_opt(&self) -> Option<i32> {
+        let (wire_type, pos) = self.get_cache().severity_number?;
+        if wire_type == 0 {
+            self.parser.parse_varint(pos).map(|(value, _)| value as i32)
+        } else {
+            None
         }
-        0
     }
 
-    pub fn observed_time_unix_nano(&self) -> u64 {
-        if let Some((wire_type, pos)) = self.get_cache().observed_time_unix_nano {
-            if wire_type == 1 {
-                return self.parser.parse_fixed64(pos).map(|(value, _)| value).unwrap_or(0);
-            }
+    /// Read severity_number without populating the field cache. Like the
+    /// cached accessor, the last occurrence of the field wins.
+    fn probe_severity_number(&self) -> i32 {
+        if self.cache.get().is_some() {
+            return self.severity_number();
         }
-        0
+
+        self.parser
+            .walk_fields()
+            .filter(|&(tag, wire_type, _)| tag == 2 && wire_type == 0)
+            .last()
+            .and_then(|(_, _, varint)| ProtobufParser::new(varint).parse_varint(0))
+            .map_or(0, |(value, _)| value as i32)
     }
 
-    pub fn severity_number(&self) -> i32 {
-        if let Some((wire_type, pos)) = self.get_cache().severity_number {
-            if wire_type == 0 {
-                return self.parser.parse_varint(pos).map(|(value, _)| value as i32).unwrap_or(0);
+    /// Resolve `effective_timestamp` without populating the field cache.
+    /// Like the cached accessors, the last occurrence of each field wins.
+    fn probe_effective_timestamp(&self) -> Option<u64> {
+        if self.cache.get().is_some() {
+            return self.effective_timestamp();
+        }
+
+        let (mut time, mut observed) = (0, 0);
+        for (tag, wire_type, bytes) in self.parser.walk_fields() {
+            if wire_type != 1 {
+                continue;
+            }
+            let value = u64::from_le_bytes(bytes.try_into().unwrap());
+            match tag {
+                1 => time = value,
+                11 => observed = value,
+                _ => {}
             }
         }
-        0
+        Some(time).filter(|&t| t != 0).or(Some(observed).filter(|&t| t != 0))
     }
 
     /// Get the severity_text field (tag 3, string)
     pub fn severity_text(&self) -> Option<&'a str> {
-        if let Some((wire_type, pos)) = self.get_cache().severity_text {
-            if wire_type == 2 {
-                return self.parser.parse_length_delimited(pos)
-                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok());
-            }
+        match &self.validated {
+            Some(validated) => validated.severity_text,
+            None => self.string_at(self.get_cache().severity_text),
         }
-        None
     }
 
     /// Get the body field (tag 5, message) - returns raw bytes
@@ -581,7 +1352,43 @@ impl<'a> LogRecordParser<'a> {
         None
     }
 
-    /// Get iterator over attributes (tag 6, repeated message)
+    /// Get the body as a lazily navigable value, so structured bodies
+    /// (kvlists, arrays) can be walked without copying
+    pub fn body_value(&self) -> Option<AnyValueParser<'a>> {
+        self.body().map(AnyValueParser::new)
+    }
+
+    /// Get the lines of a multi-line body stored as an array of strings.
+    /// Returns `None` if the body is absent or not an array; non-string
+    /// elements are skipped.
+    pub fn body_lines(&'a self) -> Option<impl Iterator<Item = &'a str>> {
+        self.body_value()?
+            .array_value()
+            .map(|values| values.filter_map(|value| value.string_value()))
+    }
+
+    /// Whether the record has any attributes. Before the field cache is
+    /// built this stops at the first attribute field instead of building it.
+    pub fn has_attributes(&self) -> bool {
+        if let Some(cache) = self.cache.get() {
+            return cache.attributes.iter().any(|&(wire_type, _)| wire_type == 2);
+        }
+        self.projection.includes_tag(6) && matches!(self.parser.find_field(6), Some((2, _)))
+    }
+
+    /// Iterate attributes like `attributes()`, pairing each with its key
+    /// interned in `interner` (`None` for keys it rejects)
+    pub fn attributes_interned<'i>(
+        &'a self,
+        interner: &'i mut KeyInterner,
+    ) -> impl Iterator<Item = (Option<KeyId>, KeyValueParser<'a>)> + 'i
+    where
+        'a: 'i,
+    {
+        self.attributes().map(move |attr| (attr.key_id(interner), attr))
+    }
+
+    /// Get iterator over attributes (tag 6, repeated message), in wire order
     pub fn attributes(&'a self) -> CachedAttributeIterator<'a> {
         CachedAttributeIterator {
             parser: &self.parser,
@@ -590,6 +1397,23 @@ impl<'a> LogRecordParser<'a> {
         }
     }
 
+    /// Get the cached `(wire_type, pos)` of every attribute field, for
+    /// building external indexes. `pos` is relative to `raw_bytes()` and
+    /// points just past the field tag, at the KeyValue's length prefix.
+    pub fn attribute_ranges(&self) -> &[(u8, usize)] {
+        &self.get_cache().attributes
+    }
+
+    /// Offset of this record's bytes within `buffer`, or `None` if the record
+    /// was not parsed out of it. Adding a position from `attribute_ranges`
+    /// gives the attribute's absolute position in `buffer`.
+    pub fn base_offset(&self, buffer: &[u8]) -> Option<usize> {
+        let start = self.parser.data.as_ptr() as usize;
+        let buffer_start = buffer.as_ptr() as usize;
+        let offset = start.checked_sub(buffer_start)?;
+        (offset + self.parser.data.len() <= buffer.len()).then_some(offset)
+    }
+
     /// Get the dropped_attributes_count field (tag 7, uint32)
     pub fn dropped_attributes_count(&self) -> Option<u32> {
         if let Some((wire_type, pos)) = self.get_cache().dropped_attributes_count {
@@ -632,13 +1456,19 @@ impl<'a> LogRecordParser<'a> {
 
     /// Get the event_name field (tag 12, string)
     pub fn event_name(&self) -> Option<&'a str> {
-        if let Some((wire_type, pos)) = self.get_cache().event_name {
-            if wire_type == 2 {
-                return self.parser.parse_length_delimited(pos)
-                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).ok());
-            }
+        match &self.validated {
+            Some(validated) => validated.event_name,
+            None => self.string_at(self.get_cache().event_name),
         }
-        None
+    }
+
+    /// Display name with the same precedence as `LogRecordView::name`:
+    /// event_name, then severity_text, then `"log_record"`
+    pub fn name(&self) -> &'a str {
+        self.event_name()
+            .filter(|s| !s.is_empty())
+            .or_else(|| self.severity_text().filter(|s| !s.is_empty()))
+            .unwrap_or("log_record")
     }
 
     /// Check if trace_id is valid (16 bytes, not all zeros)
@@ -663,53 +1493,100 @@ impl<'a> LogRecordParser<'a> {
     pub fn trace_flags(&self) -> Option<u8> {
         self.flags().map(|flags| (flags & 0xFF) as u8)
     }
+
+    /// The flags field decoded per OTLP's trace flag and `SpanFlags` bits
+    pub fn log_record_flags(&self) -> Option<LogRecordFlags> {
+        self.flags().map(LogRecordFlags)
+    }
+
+    /// Trace id, span id and trace flags together, ids as fixed arrays
+    pub fn trace_context(&self) -> TraceContext {
+        TraceContext::from_raw(self.trace_id(), self.span_id(), self.flags())
+    }
+
+    /// Copy the record into a `CompactRecord`, dropping non-scalar attributes
+    pub fn to_compact(&self) -> CompactRecord {
+        self.to_compact_with(NestedAttributePolicy::Drop)
+    }
+
+    /// Copy the record into a `CompactRecord`, handling array and kvlist
+    /// attributes per `policy`
+    pub fn to_compact_with(&self, policy: NestedAttributePolicy) -> CompactRecord {
+        let body = self.body_value().map(|body| match body.string_value_cow() {
+            Some(text) => text.into_owned(),
+            None => body.to_display_string_deep(MAX_FLATTEN_DEPTH, usize::MAX),
+        });
+
+        // Starting at the depth limit keeps containers from being descended into
+        let start_depth = match policy {
+            NestedAttributePolicy::Drop => MAX_FLATTEN_DEPTH,
+            NestedAttributePolicy::Flatten => 0,
+        };
+        let mut attributes = Vec::with_capacity(self.attribute_ranges().len());
+        for &(wire_type, pos) in self.attribute_ranges() {
+            if wire_type != 2 {
+                continue;
+            }
+            let Some((bytes, _)) = self.parser.parse_length_delimited(pos) else {
+                continue;
+            };
+            let attr = KeyValueParser::new(bytes);
+            if let Some(value) = attr.value() {
+                let key = attr.key_cow().unwrap_or_default().into_owned();
+                flatten_value(key, &value, start_depth, &mut attributes);
+            }
+        }
+
+        CompactRecord {
+            time_unix_nano: self.time_unix_nano(),
+            severity_number: self.severity_number(),
+            body,
+            attributes,
+        }
+    }
 }
 
 /// Iterator over attribute KeyValue messages
 pub struct AttributeIterator<'a> {
-    parser: &'a ProtobufParser<'a>,
-    pos: usize,
+    fields: MessageFields<'a>,
 }
 
 impl<'a> Iterator for AttributeIterator<'a> {
     type Item = KeyValueParser<'a>; // Changed from &'a [u8] to KeyValueParser<'a>
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.pos < self.parser.data.len() {
-            let (tag_and_wire, new_pos) = self.parser.parse_varint(self.pos)?;
-            self.pos = new_pos;
+        self.fields.next().map(KeyValueParser::new)
+    }
 
-            let tag = (tag_and_wire >> 3) as u32;
-            let wire_type = (tag_and_wire & 0x7) as u8;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
 
-            if tag == 6 && wire_type == 2 {
-                let (bytes, end_pos) = self.parser.parse_length_delimited(self.pos)?;
-                self.pos = end_pos;
-                return Some(KeyValueParser::new(bytes)); // Return parsed KeyValueParser
-            } else {
-                // Skip field
-                self.pos = match wire_type {
-                    0 => self.parser.parse_varint(self.pos)?.1,
-                    1 => self.pos + 8,
-                    2 => self.parser.parse_length_delimited(self.pos)?.1,
-                    5 => self.pos + 4,
-                    _ => return None,
-                };
-            }
-        }
-        None
+    fn count(self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<'a> AttributeIterator<'a> {
+    /// Attributes left, counted without building KeyValueParsers
+    fn remaining(&self) -> usize {
+        self.fields.clone().count()
     }
 }
 
 /// Zero-allocation parser for KeyValue (attributes)
 pub struct KeyValueParser<'a> {
     parser: ProtobufParser<'a>,
+    /// Value parser handed out by reference through `AttributeView`
+    value_cache: OnceCell<Option<AnyValueParser<'a>>>,
 }
 
 impl<'a> KeyValueParser<'a> {
     pub fn new(data: &'a [u8]) -> Self {
         Self {
             parser: ProtobufParser::new(data),
+            value_cache: OnceCell::new(),
         }
     }
 
@@ -725,6 +1602,41 @@ impl<'a> KeyValueParser<'a> {
         })
     }
 
+    /// Get the key bytes as sent on the wire, without UTF-8 validation
+    pub fn key_bytes(&self) -> Option<&'a [u8]> {
+        self.parser.find_field(1).and_then(|(wire_type, pos)| {
+            if wire_type == 2 {
+                self.parser.parse_length_delimited(pos).map(|(bytes, _)| bytes)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Offset within the key of the first invalid UTF-8 byte, or `None` if
+    /// the key is valid or absent
+    pub fn key_utf8_error(&self) -> Option<usize> {
+        std::str::from_utf8(self.key_bytes()?).err().map(|err| err.valid_up_to())
+    }
+
+    /// Intern the key, validating it only if `interner` hasn't seen these
+    /// bytes before. An absent key interns as `""`.
+    pub fn key_id(&self, interner: &mut KeyInterner) -> Option<KeyId> {
+        interner.intern_bytes(self.key_bytes().unwrap_or_default())
+    }
+
+    /// Get the key, replacing invalid UTF-8 sequences with U+FFFD
+    pub fn key_cow(&self) -> Option<Cow<'a, str>> {
+        self.parser.find_field(1).and_then(|(wire_type, pos)| {
+            if wire_type == 2 {
+                self.parser.parse_length_delimited(pos)
+                    .map(|(bytes, _)| String::from_utf8_lossy(bytes))
+            } else {
+                None
+            }
+        })
+    }
+
     /// Get the value field (tag 2, message) - returns raw AnyValue bytes
     pub fn value(&self) -> Option<AnyValueParser<'a>> {
         self.parser.find_field(2).and_then(|(wire_type, pos)| {
@@ -738,6 +1650,18 @@ impl<'a> KeyValueParser<'a> {
     }
 }
 
+impl<'a> AttributeView for KeyValueParser<'a> {
+    type AnyValue = AnyValueParser<'a>;
+
+    fn key(&self) -> &str {
+        KeyValueParser::key(self).unwrap_or("")
+    }
+
+    fn value(&self) -> Option<&AnyValueParser<'a>> {
+        self.value_cache.get_or_init(|| KeyValueParser::value(self)).as_ref()
+    }
+}
+
 /// Zero-allocation parser for AnyValue
 pub struct AnyValueParser<'a> {
     parser: ProtobufParser<'a>,
@@ -750,6 +1674,12 @@ impl<'a> AnyValueParser<'a> {
         }
     }
 
+    /// The exact encoded bytes this parser was constructed from, for
+    /// re-emitting the message untouched
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.parser.data
+    }
+
     /// Get string value (tag 1, string)
     pub fn string_value(&self) -> Option<&'a str> {
         self.parser.find_field(1).and_then(|(wire_type, pos)| {
@@ -762,6 +1692,32 @@ impl<'a> AnyValueParser<'a> {
         })
     }
 
+    /// Get the string value, replacing invalid UTF-8 sequences with U+FFFD
+    pub fn string_value_cow(&self) -> Option<Cow<'a, str>> {
+        self.parser.find_field(1).and_then(|(wire_type, pos)| {
+            if wire_type == 2 {
+                self.parser.parse_length_delimited(pos)
+                    .map(|(bytes, _)| String::from_utf8_lossy(bytes))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Offset within the string value of the first invalid UTF-8 byte;
+    /// `None` if the value is valid or not a string
+    pub fn string_utf8_error(&self) -> Option<usize> {
+        self.parser.find_field(1).and_then(|(wire_type, pos)| {
+            if wire_type == 2 {
+                self.parser.parse_length_delimited(pos)
+                    .and_then(|(bytes, _)| std::str::from_utf8(bytes).err())
+                    .map(|err| err.valid_up_to())
+            } else {
+                None
+            }
+        })
+    }
+
     /// Get bool value (tag 2, bool)
     pub fn bool_value(&self) -> Option<bool> {
         self.parser.find_field(2).and_then(|(wire_type, pos)| {
@@ -784,6 +1740,18 @@ impl<'a> AnyValueParser<'a> {
         })
     }
 
+    /// Compatibility helper for non-conforming producers: like `int_value`,
+    /// but also accepts the int field encoded as fixed64 (wire type 1) and
+    /// reads it as sfixed64. OTLP always encodes `int_value` as a varint, so
+    /// prefer `int_value` unless such payloads must be recovered.
+    pub fn int_value_any(&self) -> Option<i64> {
+        self.parser.find_field(3).and_then(|(wire_type, pos)| match wire_type {
+            0 => self.parser.parse_varint(pos).map(|(value, _)| value as i64),
+            1 => self.parser.parse_fixed64(pos).map(|(value, _)| value as i64),
+            _ => None,
+        })
+    }
+
     /// Get double value (tag 4, double)
     pub fn double_value(&self) -> Option<f64> {
         self.parser.find_field(4).and_then(|(wire_type, pos)| {
@@ -795,30 +1763,40 @@ impl<'a> AnyValueParser<'a> {
         })
     }
 
-    /// Get array value (tag 5, repeated AnyValue)
-    pub fn array_value(&'a self) -> Option<ArrayValueIterator<'a>> {
-        // Check if field 5 exists
-        if self.parser.find_field(5).is_some() {
-            Some(ArrayValueIterator {
-                parser: &self.parser,
-                pos: 0,
-            })
-        } else {
-            None
-        }
+    /// Get array value (tag 5, ArrayValue message wrapping repeated AnyValue)
+    pub fn array_value(&self) -> Option<ArrayValueIterator<'a>> {
+        self.parser.find_field(5).and_then(|(wire_type, pos)| {
+            if wire_type == 2 {
+                self.parser.parse_length_delimited(pos).map(|(bytes, _)| ArrayValueIterator {
+                    fields: MessageFields::new(bytes, 1),
+                })
+            } else {
+                None
+            }
+        })
     }
 
-    /// Get kvlist value (tag 6, repeated KeyValue)
-    pub fn kvlist_value(&'a self) -> Option<KvListIterator<'a>> {
-        // Check if field 6 exists
-        if self.parser.find_field(6).is_some() {
-            Some(KvListIterator {
-                parser: &self.parser,
-                pos: 0,
-            })
-        } else {
-            None
-        }
+    /// Get kvlist value (tag 6, KeyValueList message wrapping repeated KeyValue)
+    pub fn kvlist_value(&self) -> Option<KvListIterator<'a>> {
+        self.parser.find_field(6).and_then(|(wire_type, pos)| {
+            if wire_type == 2 {
+                self.parser.parse_length_delimited(pos).map(|(bytes, _)| KvListIterator {
+                    fields: MessageFields::new(bytes, 1),
+                })
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Value of the first kvlist entry with `key`, scanning the entries
+    /// in wire order. `None` if this is not a kvlist, no entry has the key,
+    /// or that entry's value is unset. Lazy counterpart of
+    /// `AnyValueView::kvlist_get`, which can't see kvlists on this backend.
+    pub fn kvlist_get(&self, key: &str) -> Option<AnyValueParser<'a>> {
+        self.kvlist_value()?
+            .find(|entry| entry.key() == Some(key))?
+            .value()
     }
 
     /// Get bytes value (tag 7, bytes)
@@ -832,6 +1810,33 @@ impl<'a> AnyValueParser<'a> {
         })
     }
 
+    /// Write the bytes value as lowercase hex without allocating.
+    /// Writes nothing if the value is not bytes.
+    pub fn write_bytes_hex<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        if let Some(bytes) = self.bytes_value() {
+            for byte in bytes {
+                write!(w, "{:02x}", byte)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the bytes value rendered as lowercase hex
+    pub fn bytes_as_hex(&self) -> Option<String> {
+        let bytes = self.bytes_value()?;
+        let mut hex = String::with_capacity(bytes.len() * 2);
+        self.write_bytes_hex(&mut hex).ok()?;
+        Some(hex)
+    }
+
+    /// Get the bytes value rendered as standard (padded) base64
+    pub fn bytes_as_base64(&self) -> Option<String> {
+        let bytes = self.bytes_value()?;
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        crate::util::write_base64(&mut encoded, bytes).ok()?;
+        Some(encoded)
+    }
+
     /// Determine the value type by checking which field is present
     pub fn value_type(&self) -> AnyValueType {
         if self.parser.find_field(1).is_some() {
@@ -853,6 +1858,26 @@ impl<'a> AnyValueParser<'a> {
         }
     }
 
+    /// Determine the value type in a single pass over the fields, returning
+    /// on the first recognized oneof tag. `value_type` probes each tag in
+    /// turn and rescans the value up to seven times; since at most one
+    /// member of the oneof is present the two agree on well-formed input.
+    pub fn value_type_fast(&self) -> AnyValueType {
+        self.parser
+            .walk_fields()
+            .find_map(|(tag, _, _)| match tag {
+                1 => Some(AnyValueType::String),
+                2 => Some(AnyValueType::Bool),
+                3 => Some(AnyValueType::Int),
+                4 => Some(AnyValueType::Double),
+                5 => Some(AnyValueType::Array),
+                6 => Some(AnyValueType::KvList),
+                7 => Some(AnyValueType::Bytes),
+                _ => None,
+            })
+            .unwrap_or(AnyValueType::Unknown)
+    }
+
     /// Get a string representation of the value for easy printing
     pub fn to_display_string(&self) -> String {
         match self.value_type() {
@@ -888,6 +1913,69 @@ impl<'a> AnyValueParser<'a> {
             AnyValueType::Unknown => "unknown".to_string(),
         }
     }
+
+    /// Render the value with array and kvlist contents expanded, e.g.
+    /// `{a: {b: 1}}`, up to `max_depth` levels of nesting and `max_elems`
+    /// entries per container
+    pub fn to_display_string_deep(&self, max_depth: usize, max_elems: usize) -> String {
+        crate::util::display_value_deep(self, max_depth, max_elems)
+    }
+}
+
+
+/// Elements aren't materialized, so `as_array` and `as_kvlist` are always
+/// `None`; containers are reachable through `array_iter` and `kvlist_iter`
+impl<'a> AnyValueView for AnyValueParser<'a> {
+    type KeyValue = KeyValueParser<'a>;
+    type ArrayElement = AnyValueParser<'a>;
+
+    fn value_type(&self) -> ValueType {
+        match self.value_type() {
+            AnyValueType::String | AnyValueType::Unknown => ValueType::String,
+            AnyValueType::Bool => ValueType::Bool,
+            AnyValueType::Int => ValueType::Int64,
+            AnyValueType::Double => ValueType::Double,
+            AnyValueType::Bytes => ValueType::Bytes,
+            AnyValueType::Array => ValueType::Array,
+            AnyValueType::KvList => ValueType::KeyValueList,
+        }
+    }
+
+    fn as_string(&self) -> Option<&str> {
+        self.string_value()
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        self.bool_value()
+    }
+
+    fn as_int64(&self) -> Option<i64> {
+        self.int_value()
+    }
+
+    fn as_double(&self) -> Option<f64> {
+        self.double_value()
+    }
+
+    fn as_bytes(&self) -> Option<&[u8]> {
+        self.bytes_value()
+    }
+
+    fn as_array(&self) -> Option<&[Self]> {
+        None
+    }
+
+    fn as_kvlist(&self) -> Option<&[KeyValueParser<'a>]> {
+        None
+    }
+
+    fn array_iter(&self) -> Option<impl Iterator<Item = impl Borrow<AnyValueParser<'a>>>> {
+        self.array_value()
+    }
+
+    fn kvlist_iter(&self) -> Option<impl Iterator<Item = impl Borrow<KeyValueParser<'a>>>> {
+        self.kvlist_value()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -902,75 +1990,45 @@ pub enum AnyValueType {
     Unknown,
 }
 
-/// Iterator over array values
+/// Iterator over array values (tag 1 of the ArrayValue message)
 pub struct ArrayValueIterator<'a> {
-    parser: &'a ProtobufParser<'a>,
-    pos: usize,
+    fields: MessageFields<'a>,
 }
 
 impl<'a> Iterator for ArrayValueIterator<'a> {
     type Item = AnyValueParser<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.pos < self.parser.data.len() {
-            let (tag_and_wire, new_pos) = self.parser.parse_varint(self.pos)?;
-            self.pos = new_pos;
-
-            let tag = (tag_and_wire >> 3) as u32;
-            let wire_type = (tag_and_wire & 0x7) as u8;
-
-            if tag == 5 && wire_type == 2 {
-                let (bytes, end_pos) = self.parser.parse_length_delimited(self.pos)?;
-                self.pos = end_pos;
-                return Some(AnyValueParser::new(bytes));
-            } else {
-                // Skip field
-                self.pos = match wire_type {
-                    0 => self.parser.parse_varint(self.pos)?.1,
-                    1 => self.pos + 8,
-                    2 => self.parser.parse_length_delimited(self.pos)?.1,
-                    5 => self.pos + 4,
-                    _ => return None,
-                };
-            }
-        }
-        None
+        self.fields.next().map(AnyValueParser::new)
     }
 }
 
-/// Iterator over KeyValue list
+/// Iterator over KeyValue list (tag 1 of the KeyValueList message)
 pub struct KvListIterator<'a> {
-    parser: &'a ProtobufParser<'a>,
-    pos: usize,
+    fields: MessageFields<'a>,
 }
 
 impl<'a> Iterator for KvListIterator<'a> {
     type Item = KeyValueParser<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.pos < self.parser.data.len() {
-            let (tag_and_wire, new_pos) = self.parser.parse_varint(self.pos)?;
-            self.pos = new_pos;
+        self.fields.next().map(KeyValueParser::new)
+    }
 
-            let tag = (tag_and_wire >> 3) as u32;
-            let wire_type = (tag_and_wire & 0x7) as u8;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
 
-            if tag == 6 && wire_type == 2 {
-                let (bytes, end_pos) = self.parser.parse_length_delimited(self.pos)?;
-                self.pos = end_pos;
-                return Some(KeyValueParser::new(bytes));
-            } else {
-                // Skip field
-                self.pos = match wire_type {
-                    0 => self.parser.parse_varint(self.pos)?.1,
-                    1 => self.pos + 8,
-                    2 => self.parser.parse_length_delimited(self.pos)?.1,
-                    5 => self.pos + 4,
-                    _ => return None,
-                };
-            }
-        }
-        None
+    fn count(self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<'a> KvListIterator<'a> {
+    /// Entries left, counted without building KeyValueParsers
+    fn remaining(&self) -> usize {
+        self.fields.clone().count()
     }
 }
 
@@ -988,6 +2046,34 @@ struct FieldCache {
     trace_id: Option<(u8, usize)>,
     span_id: Option<(u8, usize)>,
     event_name: Option<(u8, usize)>,
+    errors: Vec<WireTypeMismatch>,
+}
+
+/// String fields already checked for UTF-8; `None` if absent or invalid
+struct ValidatedStrings<'a> {
+    severity_text: Option<&'a str>,
+    event_name: Option<&'a str>,
+}
+
+/// Byte ranges of a ResourceLogs' resource attributes, the ResourceLogs
+/// counterpart of `FieldCache`
+#[derive(Debug, Default)]
+struct ResourceCache {
+    /// `(start, end)` of each attribute's KeyValue payload, in wire order
+    attributes: Vec<(usize, usize)>,
+    /// Index into `attributes` of the first `service.name`
+    service_name: Option<usize>,
+}
+
+/// Wire type the LogRecord schema declares for a known tag
+fn expected_wire_type(tag: u32) -> Option<u8> {
+    match tag {
+        1 | 11 => Some(1),           // fixed64 timestamps
+        2 | 7 => Some(0),            // severity_number, dropped_attributes_count
+        3 | 5 | 6 | 9 | 10 | 12 => Some(2),
+        8 => Some(5),                // fixed32 flags
+        _ => None,
+    }
 }
 
 /// Cached iterator over attribute KeyValue messages
@@ -1013,4 +2099,24 @@ impl<'a> Iterator for CachedAttributeIterator<'a> {
         }
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+
+    fn count(self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<'a> CachedAttributeIterator<'a> {
+    /// Attributes left: cached positions up to the first one `next` would
+    /// stop at, checked without building KeyValueParsers
+    fn remaining(&self) -> usize {
+        self.positions[self.index..]
+            .iter()
+            .take_while(|&&(wire_type, pos)| wire_type == 2 && self.parser.parse_length_delimited(pos).is_some())
+            .count()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,122 @@
+use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView, ValueType};
+use std::fmt;
+
+const RESET: &str = "\x1b[0m";
+
+/// Formatting knobs for `write_logs_with`. The default reproduces
+/// `inspect_logs` output exactly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintOptions {
+    /// One nesting level of indentation, e.g. two spaces or `"\t"`
+    pub indent: String,
+    /// Color record lines by severity with ANSI escapes
+    pub color: bool,
+    /// Truncate string attribute values to this many characters, marking the
+    /// cut with `…`
+    pub max_attr_value_len: Option<usize>,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            indent: "  ".to_string(),
+            color: false,
+            max_attr_value_len: None,
+        }
+    }
+}
+
+impl PrintOptions {
+    fn indent(&self, depth: usize) -> String {
+        self.indent.repeat(depth)
+    }
+
+    /// ANSI color for a severity number, bucketed per the OTLP ranges
+    fn severity_color(&self, severity_number: i32) -> Option<&'static str> {
+        if !self.color {
+            return None;
+        }
+        match severity_number {
+            1..=4 => Some("\x1b[2m"),    // TRACE: dim
+            5..=8 => Some("\x1b[36m"),   // DEBUG: cyan
+            9..=12 => Some("\x1b[32m"),  // INFO: green
+            13..=16 => Some("\x1b[33m"), // WARN: yellow
+            17..=24 => Some("\x1b[31m"), // ERROR, FATAL: red
+            _ => None,
+        }
+    }
+}
+
+/// Write the resource → scope → record tree printed by `inspect_logs` to
+/// any `fmt::Write`, e.g. a `String`
+pub fn write_logs<'a, W: fmt::Write, L: LogsView<'a>>(w: &mut W, logs: &'a L) -> fmt::Result {
+    write_logs_with(w, logs, &PrintOptions::default())
+}
+
+/// `write_logs` with custom indentation, colors and value truncation
+pub fn write_logs_with<'a, W: fmt::Write, L: LogsView<'a>>(
+    w: &mut W,
+    logs: &'a L,
+    options: &PrintOptions,
+) -> fmt::Result {
+    writeln!(w, "🔍 Inspecting Logs Data")?;
+    writeln!(w, "========================")?;
+
+    let scope_indent = options.indent(1);
+    let record_indent = options.indent(2);
+    // Record details sit one column past the record's emoji
+    let detail_indent = format!("{} ", options.indent(3));
+
+    for (resource_idx, resource) in logs.resources().enumerate() {
+        writeln!(w, "📦 Resource {}: {}", resource_idx + 1, resource.resource())?;
+
+        for (scope_idx, scope) in resource.scopes().enumerate() {
+            writeln!(w, "{}🔧 Scope {}: {} (version: {:?})",
+                     scope_indent, scope_idx + 1, scope.scope(), scope.version())?;
+
+            for (record_idx, record) in scope.log_records().enumerate() {
+                match options.severity_color(record.severity_number()) {
+                    Some(color) => writeln!(w, "{}{}📝 Log Record {}: {}{}",
+                                            record_indent, color, record_idx + 1, record.name(), RESET)?,
+                    None => writeln!(w, "{}📝 Log Record {}: {}", record_indent, record_idx + 1, record.name())?,
+                }
+
+                if let Some(ts) = record.timestamp() {
+                    writeln!(w, "{}⏰ Timestamp: {}", detail_indent, ts)?;
+                }
+
+                for attr in record.attributes() {
+                    write!(w, "{}🏷️  {}: ", detail_indent, attr.key())?;
+                    match attr.value() {
+                        Some(value) => write_value(w, value, options)?,
+                        None => writeln!(w)?,
+                    }
+                }
+                writeln!(w)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_value<W: fmt::Write, V: AnyValueView>(w: &mut W, value: &V, options: &PrintOptions) -> fmt::Result {
+    match value.value_type() {
+        ValueType::String => {
+            let s = value.as_string().unwrap_or("N/A");
+            match options.max_attr_value_len {
+                Some(max) if s.chars().count() > max => {
+                    let cut = s.char_indices().nth(max).map_or(s.len(), |(i, _)| i);
+                    writeln!(w, "\"{}…\"", &s[..cut])
+                }
+                _ => writeln!(w, "\"{}\"", s),
+            }
+        }
+        ValueType::Bool => writeln!(w, "{}", value.as_bool().unwrap_or(false)),
+        ValueType::Int64 => writeln!(w, "{}", value.as_int64().unwrap_or(0)),
+        ValueType::Double => writeln!(w, "{}", value.as_double().unwrap_or(0.0)),
+        ValueType::Bytes => writeln!(w, "bytes[{}]", value.as_bytes().map(|b| b.len()).unwrap_or(0)),
+        ValueType::Array => writeln!(w, "array[{}]", value.array_iter().map(|a| a.count()).unwrap_or(0)),
+        ValueType::KeyValueList => writeln!(w, "kvlist[{}]", value.kvlist_iter().map(|kv| kv.count()).unwrap_or(0)),
+    }
+}
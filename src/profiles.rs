@@ -0,0 +1,239 @@
+use crate::otlp_bytes_lazy::{KeyValueParser, ProtobufParser};
+
+/// Payloads of the length-delimited fields with tag `tag`, in wire order
+fn messages(data: &[u8], tag: u32) -> impl Iterator<Item = &[u8]> {
+    ProtobufParser::new(data)
+        .walk_fields()
+        .filter(move |&(field_tag, wire_type, _)| field_tag == tag && wire_type == 2)
+        .map(|(_, _, payload)| payload)
+}
+
+/// Last occurrence of a singular varint field, which wins under protobuf
+/// merge rules
+fn last_varint(data: &[u8], tag: u32) -> Option<u64> {
+    ProtobufParser::new(data)
+        .walk_fields()
+        .filter(|&(field_tag, wire_type, _)| field_tag == tag && wire_type == 0)
+        .last()
+        .and_then(|(_, _, payload)| prost::encoding::decode_varint(&mut &payload[..]).ok())
+}
+
+/// Every value of a repeated varint field, accepting both packed (the
+/// proto3 default) and unpacked encodings. `None` if a packed run is
+/// malformed.
+fn repeated_varints(data: &[u8], tag: u32) -> Option<Vec<u64>> {
+    let mut values = Vec::new();
+    for (field_tag, wire_type, payload) in ProtobufParser::new(data).walk_fields() {
+        if field_tag != tag {
+            continue;
+        }
+        let mut buf = payload;
+        match wire_type {
+            0 => values.push(prost::encoding::decode_varint(&mut buf).ok()?),
+            2 => {
+                while !buf.is_empty() {
+                    values.push(prost::encoding::decode_varint(&mut buf).ok()?);
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(values)
+}
+
+fn last_string(data: &[u8], tag: u32) -> Option<&str> {
+    messages(data, tag).last().and_then(|bytes| std::str::from_utf8(bytes).ok())
+}
+
+/// Zero-copy parser for ProfilesData, the root of the OTLP Profiles signal
+/// (`ProfilesData` → `ResourceProfiles` → `ScopeProfiles` → `Profile`).
+/// Like `otlp_bytes_lazy`, each parser wraps its message's bytes and decodes
+/// a field only when asked for it.
+///
+/// Profiles are still in development upstream. This follows the v1.5.0
+/// `v1development` layout, where every `Profile` carries its own string
+/// table and its sample types refer to strings by index into it.
+pub struct ProfilesDataParser<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ProfilesDataParser<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Iterate ResourceProfiles (tag 1, repeated message)
+    pub fn resource_profiles(&self) -> impl Iterator<Item = ResourceProfilesParser<'a>> {
+        messages(self.data, 1).map(ResourceProfilesParser::new)
+    }
+
+    /// Every profile in the batch, across resources and scopes
+    pub fn profiles(&self) -> impl Iterator<Item = ProfileParser<'a>> {
+        self.resource_profiles()
+            .flat_map(|resource| resource.scope_profiles())
+            .flat_map(|scope| scope.profiles())
+    }
+}
+
+/// Zero-copy parser for ResourceProfiles
+pub struct ResourceProfilesParser<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ResourceProfilesParser<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Raw bytes of the resource (tag 1)
+    pub fn resource(&self) -> Option<&'a [u8]> {
+        messages(self.data, 1).next()
+    }
+
+    /// Iterate the resource's attributes (resource tag 1, attributes tag 1)
+    pub fn resource_attributes(&self) -> impl Iterator<Item = KeyValueParser<'a>> {
+        self.resource()
+            .into_iter()
+            .flat_map(|resource| messages(resource, 1))
+            .map(KeyValueParser::new)
+    }
+
+    /// Iterate ScopeProfiles (tag 2, repeated message)
+    pub fn scope_profiles(&self) -> impl Iterator<Item = ScopeProfilesParser<'a>> {
+        messages(self.data, 2).map(ScopeProfilesParser::new)
+    }
+
+    /// Schema URL (tag 3); `None` when absent or empty
+    pub fn schema_url(&self) -> Option<&'a str> {
+        last_string(self.data, 3).filter(|url| !url.is_empty())
+    }
+}
+
+/// Zero-copy parser for ScopeProfiles
+pub struct ScopeProfilesParser<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ScopeProfilesParser<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Instrumentation scope name (scope tag 1, then name tag 1)
+    pub fn scope_name(&self) -> Option<&'a str> {
+        messages(self.data, 1).next().and_then(|scope| last_string(scope, 1))
+    }
+
+    /// Iterate Profiles (tag 2, repeated message)
+    pub fn profiles(&self) -> impl Iterator<Item = ProfileParser<'a>> {
+        messages(self.data, 2).map(ProfileParser::new)
+    }
+
+    /// Schema URL (tag 3); `None` when absent or empty
+    pub fn schema_url(&self) -> Option<&'a str> {
+        last_string(self.data, 3).filter(|url| !url.is_empty())
+    }
+}
+
+/// Zero-copy parser for a Profile
+pub struct ProfileParser<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ProfileParser<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// The exact encoded bytes this parser was constructed from
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Iterate samples (tag 2, repeated message)
+    pub fn samples(&self) -> impl Iterator<Item = SampleParser<'a>> {
+        messages(self.data, 2).map(SampleParser::new)
+    }
+
+    /// Number of samples, without decoding them
+    pub fn sample_count(&self) -> usize {
+        messages(self.data, 2).count()
+    }
+
+    /// Iterate sample types (tag 1) as `(type_strindex, unit_strindex)`;
+    /// resolve them with `string`
+    pub fn sample_types(&self) -> impl Iterator<Item = (i32, i32)> + 'a {
+        messages(self.data, 1).map(|value_type| {
+            (
+                last_varint(value_type, 1).unwrap_or(0) as i32,
+                last_varint(value_type, 2).unwrap_or(0) as i32,
+            )
+        })
+    }
+
+    /// Iterate the string table (tag 10) in index order. Entries that are
+    /// not valid UTF-8 come back as `""` so indices stay aligned.
+    pub fn string_table(&self) -> impl Iterator<Item = &'a str> {
+        messages(self.data, 10).map(|bytes| std::str::from_utf8(bytes).unwrap_or(""))
+    }
+
+    /// Number of string table entries
+    pub fn string_table_len(&self) -> usize {
+        messages(self.data, 10).count()
+    }
+
+    /// The string table entry at `index`, as referenced by `*_strindex`
+    /// fields. Negative or out-of-range indices give `None`.
+    pub fn string(&self, index: i32) -> Option<&'a str> {
+        self.string_table().nth(usize::try_from(index).ok()?)
+    }
+
+    /// Collection time (tag 11), 0 when unset
+    pub fn time_nanos(&self) -> i64 {
+        last_varint(self.data, 11).unwrap_or(0) as i64
+    }
+
+    /// Profile duration (tag 12), 0 when unset
+    pub fn duration_nanos(&self) -> i64 {
+        last_varint(self.data, 12).unwrap_or(0) as i64
+    }
+
+    /// Profile id (tag 17), `None` when absent or empty
+    pub fn profile_id(&self) -> Option<&'a [u8]> {
+        messages(self.data, 17).last().filter(|id| !id.is_empty())
+    }
+}
+
+/// Zero-copy parser for a Sample
+pub struct SampleParser<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> SampleParser<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// The sample's values (tag 3), one per `ProfileParser::sample_types`
+    /// entry; empty if absent or malformed
+    pub fn values(&self) -> Vec<i64> {
+        repeated_varints(self.data, 3)
+            .map(|values| values.into_iter().map(|value| value as i64).collect())
+            .unwrap_or_default()
+    }
+
+    /// Start of the sample's stack in the profile's `location_indices` (tag 1)
+    pub fn locations_start_index(&self) -> i32 {
+        last_varint(self.data, 1).unwrap_or(0) as i32
+    }
+
+    /// Length of the sample's stack (tag 2)
+    pub fn locations_length(&self) -> i32 {
+        last_varint(self.data, 2).unwrap_or(0) as i32
+    }
+
+    /// Timestamps of the events aggregated into this sample (tag 6)
+    pub fn timestamps_unix_nano(&self) -> Vec<u64> {
+        repeated_varints(self.data, 6).unwrap_or_default()
+    }
+}
@@ -3,7 +3,8 @@ use crate::proto::{
     opentelemetry::proto::common::v1::*,
 };
 use crate::proto::opentelemetry::proto::common::v1::any_value;
-use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView, ValueType};
+use std::borrow::Borrow;
+use crate::{LogsView, ResourceLogsView, ScopeLogsView, LogRecordView, AttributeView, AnyValueView, EntityRefView, ValueType};
 
 // Implementations for the generated protobuf types
 impl<'a> LogsView<'a> for LogsData {
@@ -13,11 +14,51 @@ impl<'a> LogsView<'a> for LogsData {
     fn resources(&'a self) -> Self::ResourcesIter {
         self.resource_logs.iter()
     }
+
+    fn nth_resource(&'a self, n: usize) -> Option<&'a ResourceLogs> {
+        self.resource_logs.get(n)
+    }
+}
+
+impl LogsData {
+    /// Copy keeping only the records `keep` accepts. Records stay under
+    /// their original resource and scope; scopes and resources left without
+    /// records are dropped.
+    pub fn rebuild_filtered(&self, keep: impl Fn(&LogRecord) -> bool) -> LogsData {
+        let resource_logs = self
+            .resource_logs
+            .iter()
+            .filter_map(|resource_logs| {
+                let scope_logs: Vec<_> = resource_logs
+                    .scope_logs
+                    .iter()
+                    .filter_map(|scope_logs| {
+                        let log_records: Vec<_> = scope_logs.log_records.iter().filter(|&record| keep(record)).cloned().collect();
+                        (!log_records.is_empty()).then(|| ScopeLogs {
+                            scope: scope_logs.scope.clone(),
+                            log_records,
+                            schema_url: scope_logs.schema_url.clone(),
+                        })
+                    })
+                    .collect();
+                (!scope_logs.is_empty()).then(|| ResourceLogs {
+                    resource: resource_logs.resource.clone(),
+                    scope_logs,
+                    schema_url: resource_logs.schema_url.clone(),
+                })
+            })
+            .collect();
+        LogsData { resource_logs }
+    }
 }
 
 impl<'a> ResourceLogsView<'a> for ResourceLogs {
     type ScopeLogs = ScopeLogs;
     type ScopesIter = std::slice::Iter<'a, ScopeLogs>;
+    type ResourceAttribute = KeyValue;
+    type ResourceAttributesIter = std::slice::Iter<'a, KeyValue>;
+    type EntityRef = EntityRef;
+    type EntityRefsIter = std::slice::Iter<'a, EntityRef>;
     
     fn resource(&self) -> &str {
         // Extract resource name from the resource field
@@ -36,6 +77,34 @@ impl<'a> ResourceLogsView<'a> for ResourceLogs {
     fn scopes(&'a self) -> Self::ScopesIter {
         self.scope_logs.iter()
     }
+
+    fn nth_scope(&'a self, n: usize) -> Option<&'a ScopeLogs> {
+        self.scope_logs.get(n)
+    }
+    
+    fn resource_attributes(&'a self) -> Self::ResourceAttributesIter {
+        self.resource
+            .as_ref()
+            .map(|r| r.attributes.as_slice())
+            .unwrap_or(&[])
+            .iter()
+    }
+
+    fn entity_refs(&'a self) -> Self::EntityRefsIter {
+        self.resource
+            .as_ref()
+            .map(|r| r.entity_refs.as_slice())
+            .unwrap_or(&[])
+            .iter()
+    }
+
+    fn schema_url(&self) -> Option<&str> {
+        Some(self.schema_url.as_str()).filter(|url| !url.is_empty())
+    }
+
+    fn dropped_attributes_count(&self) -> u32 {
+        self.resource.as_ref().map_or(0, |r| r.dropped_attributes_count)
+    }
 }
 
 impl<'a> ScopeLogsView<'a> for ScopeLogs {
@@ -50,12 +119,24 @@ impl<'a> ScopeLogsView<'a> for ScopeLogs {
     
     fn version(&self) -> Option<&str> {
         self.scope.as_ref()
-            .and_then(|s| Some(s.version.as_ref()))
+            .map(|s| s.version.as_ref())
     }
     
     fn log_records(&'a self) -> Self::LogRecordsIter {
         self.log_records.iter()
     }
+
+    fn nth_record(&'a self, n: usize) -> Option<&'a LogRecord> {
+        self.log_records.get(n)
+    }
+
+    fn schema_url(&self) -> Option<&str> {
+        Some(self.schema_url.as_str()).filter(|url| !url.is_empty())
+    }
+
+    fn dropped_attributes_count(&self) -> u32 {
+        self.scope.as_ref().map_or(0, |s| s.dropped_attributes_count)
+    }
 }
 
 impl<'a> LogRecordView<'a> for LogRecord {
@@ -63,18 +144,80 @@ impl<'a> LogRecordView<'a> for LogRecord {
     type AttributesIter = std::slice::Iter<'a, KeyValue>;
     
     fn name(&self) -> &str {
-        // LogRecord doesn't have a "name" field in OTLP, 
-        // you might want to extract from body or attributes
-        "log_record" // or extract from body/attributes
+        if !self.event_name.is_empty() {
+            &self.event_name
+        } else if !self.severity_text.is_empty() {
+            &self.severity_text
+        } else {
+            "log_record"
+        }
+    }
+    
+    fn time_unix_nano(&self) -> Option<u64> {
+        Some(self.time_unix_nano).filter(|&t| t != 0)
+    }
+    
+    fn observed_time_unix_nano(&self) -> Option<u64> {
+        Some(self.observed_time_unix_nano).filter(|&t| t != 0)
     }
     
-    fn timestamp(&self) -> Option<u64> {
-        Some(self.time_unix_nano)
+    fn severity_number(&self) -> i32 {
+        self.severity_number
+    }
+    
+    fn severity_text(&self) -> Option<&str> {
+        Some(self.severity_text.as_str()).filter(|s| !s.is_empty())
+    }
+
+    fn trace_id(&self) -> Option<&[u8]> {
+        Some(self.trace_id.as_slice()).filter(|id| !id.is_empty())
+    }
+
+    fn span_id(&self) -> Option<&[u8]> {
+        Some(self.span_id.as_slice()).filter(|id| !id.is_empty())
+    }
+
+    fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    fn event_name(&self) -> Option<&str> {
+        Some(self.event_name.as_str()).filter(|s| !s.is_empty())
+    }
+    
+    fn body(&self) -> Option<&AnyValue> {
+        self.body.as_ref()
     }
     
     fn attributes(&'a self) -> Self::AttributesIter {
         self.attributes.iter()
     }
+
+    fn has_attributes(&self) -> bool {
+        !self.attributes.is_empty()
+    }
+
+    fn dropped_attributes_count(&self) -> u32 {
+        self.dropped_attributes_count
+    }
+}
+
+impl EntityRefView for EntityRef {
+    fn schema_url(&self) -> Option<&str> {
+        Some(self.schema_url.as_str()).filter(|url| !url.is_empty())
+    }
+
+    fn entity_type(&self) -> Option<&str> {
+        Some(self.r#type.as_str()).filter(|ty| !ty.is_empty())
+    }
+
+    fn id_keys(&self) -> impl Iterator<Item = &str> {
+        self.id_keys.iter().map(String::as_str)
+    }
+
+    fn description_keys(&self) -> impl Iterator<Item = &str> {
+        self.description_keys.iter().map(String::as_str)
+    }
 }
 
 impl AttributeView for KeyValue {
@@ -89,8 +232,18 @@ impl AttributeView for KeyValue {
     }
 }
 
+impl AnyValue {
+    /// Render the value with array and kvlist contents expanded, e.g.
+    /// `[1, "a", true, ...]`, up to `max_depth` levels of nesting and
+    /// `max_elems` entries per container
+    pub fn to_display_string_deep(&self, max_depth: usize, max_elems: usize) -> String {
+        crate::util::display_value_deep(self, max_depth, max_elems)
+    }
+}
+
 impl AnyValueView for AnyValue {
     type KeyValue = KeyValue;
+    type ArrayElement = AnyValue;
     
     fn value_type(&self) -> ValueType {
         match &self.value {
@@ -153,4 +306,12 @@ impl AnyValueView for AnyValue {
             _ => None,
         }
     }
+    
+    fn array_iter(&self) -> Option<impl Iterator<Item = impl Borrow<AnyValue>>> {
+        self.as_array().map(|values| values.iter())
+    }
+
+    fn kvlist_iter(&self) -> Option<impl Iterator<Item = impl Borrow<KeyValue>>> {
+        self.as_kvlist().map(|entries| entries.iter())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,155 @@
+use crate::otlp_bytes_lazy::ProtobufParser;
+
+/// Leaf messages inspected before settling on a guess; the signal is
+/// usually clear from the first record, span or metric
+const MAX_SAMPLED_LEAVES: usize = 16;
+
+/// OTLP signal type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    Logs,
+    Traces,
+    Metrics,
+    /// Not OTLP-shaped, or no leaf message to tell the signals apart
+    Unknown,
+}
+
+/// How strongly the payload supports the guess
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence {
+    /// Several signals fit the sampled fields; the guess prefers logs, then
+    /// traces, then metrics
+    Low,
+    /// Exactly one signal fits every sampled field
+    High,
+}
+
+/// Result of `detect_signal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignalGuess {
+    pub signal: Signal,
+    pub confidence: Confidence,
+}
+
+impl SignalGuess {
+    const UNKNOWN: SignalGuess = SignalGuess {
+        signal: Signal::Unknown,
+        confidence: Confidence::Low,
+    };
+}
+
+/// Guess which signal an unlabeled OTLP protobuf payload carries (a
+/// `*Data` message or the equivalent `Export*ServiceRequest`).
+///
+/// Logs, traces and metrics share the same envelope: repeated resource
+/// entries at tag 1, each holding scope entries at tag 2, each holding
+/// leaf messages at tag 2. Only the leaves differ, so the guess comes from
+/// checking the first few LogRecords/Spans/Metrics against each schema's
+/// tags and wire types. This is a heuristic for demultiplexing, not
+/// validation: unknown fields or a crafted payload can fool it.
+pub fn detect_signal(data: &[u8]) -> SignalGuess {
+    let Some(leaves) = sample_leaves(data) else {
+        return SignalGuess::UNKNOWN;
+    };
+    if leaves.is_empty() {
+        return SignalGuess::UNKNOWN;
+    }
+
+    let candidates: Vec<Signal> = [Signal::Logs, Signal::Traces, Signal::Metrics]
+        .into_iter()
+        .filter(|&signal| leaves.iter().all(|leaf| leaf_fits(signal, leaf)))
+        .collect();
+
+    match candidates.as_slice() {
+        [] => SignalGuess::UNKNOWN,
+        [signal] => SignalGuess {
+            signal: *signal,
+            confidence: Confidence::High,
+        },
+        [signal, ..] => SignalGuess {
+            signal: *signal,
+            confidence: Confidence::Low,
+        },
+    }
+}
+
+/// Collect up to `MAX_SAMPLED_LEAVES` leaf messages, or `None` if the
+/// envelope does not have the resource/scope/leaf shape
+fn sample_leaves(data: &[u8]) -> Option<Vec<&[u8]>> {
+    let resources = envelope_children(data, &[1])?;
+    if resources.is_empty() {
+        return None;
+    }
+
+    let mut leaves = Vec::new();
+    for (_, resource) in resources {
+        // resource (1), scope entries (2), schema_url (3)
+        for (_, scope) in envelope_children(resource, &[1, 2, 3])?.into_iter().filter(|(tag, _)| *tag == 2) {
+            // scope (1), leaves (2), schema_url (3)
+            for (tag, leaf) in envelope_children(scope, &[1, 2, 3])? {
+                if tag == 2 {
+                    leaves.push(leaf);
+                    if leaves.len() == MAX_SAMPLED_LEAVES {
+                        return Some(leaves);
+                    }
+                }
+            }
+        }
+    }
+    Some(leaves)
+}
+
+/// The length-delimited fields of an envelope message, all of whose tags
+/// must be in `tags`
+fn envelope_children<'a>(data: &'a [u8], tags: &[u32]) -> Option<Vec<(u32, &'a [u8])>> {
+    let mut fields = ProtobufParser::new(data).walk_fields();
+    let children = fields
+        .by_ref()
+        .map(|(tag, wire_type, payload)| (wire_type == 2 && tags.contains(&tag)).then_some((tag, payload)))
+        .collect::<Option<Vec<_>>>()?;
+    // A walk that stopped short of the end hit malformed bytes
+    (fields.offset() == data.len()).then_some(children)
+}
+
+/// Whether every field of a leaf message is allowed by `signal`'s schema
+fn leaf_fits(signal: Signal, leaf: &[u8]) -> bool {
+    let mut fields = ProtobufParser::new(leaf).walk_fields();
+    fields
+        .by_ref()
+        .all(|(tag, wire_type, payload)| field_fits(signal, tag, wire_type, payload))
+        && fields.offset() == leaf.len()
+}
+
+fn field_fits(signal: Signal, tag: u32, wire_type: u8, payload: &[u8]) -> bool {
+    match signal {
+        // LogRecord: times are fixed64, ids have fixed lengths
+        Signal::Logs => match tag {
+            1 | 11 => wire_type == 1,
+            2 | 7 => wire_type == 0,
+            8 => wire_type == 5,
+            9 => wire_type == 2 && matches!(payload.len(), 0 | 16),
+            10 => wire_type == 2 && matches!(payload.len(), 0 | 8),
+            3 | 12 => wire_type == 2 && std::str::from_utf8(payload).is_ok(),
+            5 | 6 => wire_type == 2,
+            _ => false,
+        },
+        // Span: ids lead, times at 7/8, events/links/status are messages
+        Signal::Traces => match tag {
+            1 => wire_type == 2 && matches!(payload.len(), 0 | 16),
+            2 | 4 => wire_type == 2 && matches!(payload.len(), 0 | 8),
+            3 | 5 => wire_type == 2 && std::str::from_utf8(payload).is_ok(),
+            9 | 11 | 13 | 15 => wire_type == 2,
+            6 | 10 | 12 | 14 => wire_type == 0,
+            7 | 8 => wire_type == 1,
+            16 => wire_type == 5,
+            _ => false,
+        },
+        // Metric: name/description/unit, then one data message
+        Signal::Metrics => match tag {
+            1..=3 => wire_type == 2 && std::str::from_utf8(payload).is_ok(),
+            5 | 7 | 9 | 10 | 11 | 12 => wire_type == 2,
+            _ => false,
+        },
+        Signal::Unknown => false,
+    }
+}
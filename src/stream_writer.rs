@@ -0,0 +1,157 @@
+use crate::proto::opentelemetry::proto as pb;
+use prost::encoding::{encode_key, encode_varint, WireType};
+use std::fmt;
+use std::io::{self, Write};
+
+/// Error writing through a `LogsStreamWriter`
+#[derive(Debug)]
+pub enum StreamWriteError {
+    /// The underlying writer failed
+    Io(io::Error),
+    /// A call was made out of order, e.g. `write_record` outside a scope or
+    /// `finish` with a resource still open
+    OutOfOrder(&'static str),
+}
+
+impl fmt::Display for StreamWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamWriteError::Io(err) => write!(f, "writing logs stream: {}", err),
+            StreamWriteError::OutOfOrder(call) => write!(f, "{} called out of order", call),
+        }
+    }
+}
+
+impl std::error::Error for StreamWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamWriteError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for StreamWriteError {
+    fn from(err: io::Error) -> Self {
+        StreamWriteError::Io(err)
+    }
+}
+
+/// Writes an encoded `LogsData` incrementally: resource by resource, scope by
+/// scope, record by record. A sub-message's length prefix precedes its
+/// content, so the open resource and scope are buffered until their `end_*`
+/// call backpatches the length; memory is bounded by the largest
+/// ResourceLogs rather than the whole batch. Each closed ResourceLogs goes
+/// straight to the writer, since `LogsData` itself is not length-prefixed.
+pub struct LogsStreamWriter<W> {
+    writer: W,
+    resource: Option<Vec<u8>>,
+    scope: Option<Vec<u8>>,
+}
+
+impl<W: Write> LogsStreamWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            resource: None,
+            scope: None,
+        }
+    }
+
+    /// Open a ResourceLogs. `resource` and `schema_url` are written up front;
+    /// an empty `schema_url` is omitted.
+    pub fn begin_resource(
+        &mut self,
+        resource: Option<&pb::resource::v1::Resource>,
+        schema_url: &str,
+    ) -> Result<(), StreamWriteError> {
+        if self.resource.is_some() {
+            return Err(StreamWriteError::OutOfOrder("begin_resource"));
+        }
+        let mut buf = Vec::new();
+        if let Some(resource) = resource {
+            prost::encoding::message::encode(1, resource, &mut buf);
+        }
+        encode_schema_url(schema_url, &mut buf);
+        self.resource = Some(buf);
+        Ok(())
+    }
+
+    /// Open a ScopeLogs inside the current resource
+    pub fn begin_scope(
+        &mut self,
+        scope: Option<&pb::common::v1::InstrumentationScope>,
+        schema_url: &str,
+    ) -> Result<(), StreamWriteError> {
+        if self.resource.is_none() || self.scope.is_some() {
+            return Err(StreamWriteError::OutOfOrder("begin_scope"));
+        }
+        let mut buf = Vec::new();
+        if let Some(scope) = scope {
+            prost::encoding::message::encode(1, scope, &mut buf);
+        }
+        encode_schema_url(schema_url, &mut buf);
+        self.scope = Some(buf);
+        Ok(())
+    }
+
+    /// Append a record to the current scope
+    pub fn write_record(&mut self, record: &pb::logs::v1::LogRecord) -> Result<(), StreamWriteError> {
+        let scope = self.scope.as_mut().ok_or(StreamWriteError::OutOfOrder("write_record"))?;
+        prost::encoding::message::encode(2, record, scope);
+        Ok(())
+    }
+
+    /// Append an already-encoded LogRecord (e.g. a span of a parsed buffer)
+    /// to the current scope without decoding it
+    pub fn write_record_bytes(&mut self, record: &[u8]) -> Result<(), StreamWriteError> {
+        let scope = self.scope.as_mut().ok_or(StreamWriteError::OutOfOrder("write_record_bytes"))?;
+        encode_length_delimited(2, record, scope);
+        Ok(())
+    }
+
+    /// Close the current scope, framing it into the resource buffer
+    pub fn end_scope(&mut self) -> Result<(), StreamWriteError> {
+        let scope = self.scope.take().ok_or(StreamWriteError::OutOfOrder("end_scope"))?;
+        // A scope is only ever open inside a resource
+        let resource = self.resource.as_mut().ok_or(StreamWriteError::OutOfOrder("end_scope"))?;
+        encode_length_delimited(2, &scope, resource);
+        Ok(())
+    }
+
+    /// Close the current resource and write it out
+    pub fn end_resource(&mut self) -> Result<(), StreamWriteError> {
+        if self.scope.is_some() {
+            return Err(StreamWriteError::OutOfOrder("end_resource"));
+        }
+        let resource = self.resource.take().ok_or(StreamWriteError::OutOfOrder("end_resource"))?;
+        let mut header = Vec::with_capacity(11);
+        encode_key(1, WireType::LengthDelimited, &mut header);
+        encode_varint(resource.len() as u64, &mut header);
+        self.writer.write_all(&header)?;
+        self.writer.write_all(&resource)?;
+        Ok(())
+    }
+
+    /// Flush and give back the writer. Fails if a resource or scope is
+    /// still open, since its bytes have not been written.
+    pub fn finish(mut self) -> Result<W, StreamWriteError> {
+        if self.resource.is_some() {
+            return Err(StreamWriteError::OutOfOrder("finish"));
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+fn encode_schema_url(schema_url: &str, buf: &mut Vec<u8>) {
+    if !schema_url.is_empty() {
+        encode_length_delimited(3, schema_url.as_bytes(), buf);
+    }
+}
+
+fn encode_length_delimited(tag: u32, payload: &[u8], buf: &mut Vec<u8>) {
+    encode_key(tag, WireType::LengthDelimited, buf);
+    encode_varint(payload.len() as u64, buf);
+    buf.extend_from_slice(payload);
+}
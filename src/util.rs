@@ -0,0 +1,246 @@
+use crate::{LogRecordView, AttributeView, AnyValueView, ValueType};
+use std::borrow::Borrow;
+use std::fmt::{self, Write};
+use std::hash::Hasher;
+
+/// Nesting depth past which `flatten_attributes` stops descending. Values
+/// nested deeper than this are dropped rather than flattened.
+pub const MAX_FLATTEN_DEPTH: usize = 16;
+
+/// Nesting depth past which `hash_any_value` stops descending. Deeper
+/// containers hash as an opaque marker, so hashing hostile input can't
+/// exhaust the stack.
+pub const MAX_HASH_DEPTH: usize = 64;
+
+/// An owned non-container attribute value
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScalarValue {
+    String(String),
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+    Bytes(Vec<u8>),
+}
+
+/// Flatten a record's attributes into dotted keys for stores that only take
+/// flat key-value pairs: kvlist entries become `parent.child` and array
+/// elements `parent.0`, `parent.1`, recursively. Empty containers and
+/// attributes without a value produce no entries.
+pub fn flatten_attributes<'a, R: LogRecordView<'a>>(record: &'a R) -> Vec<(String, ScalarValue)> {
+    let mut flattened = Vec::new();
+    for attr in record.attributes() {
+        if let Some(value) = attr.value() {
+            flatten_value(attr.key().to_string(), value, 0, &mut flattened);
+        }
+    }
+    flattened
+}
+
+pub(crate) fn flatten_value<V: AnyValueView>(key: String, value: &V, depth: usize, out: &mut Vec<(String, ScalarValue)>) {
+    let scalar = match value.value_type() {
+        ValueType::String => value.as_string().map(|s| ScalarValue::String(s.to_string())),
+        ValueType::Bool => value.as_bool().map(ScalarValue::Bool),
+        ValueType::Int64 => value.as_int64().map(ScalarValue::Int),
+        ValueType::Double => value.as_double().map(ScalarValue::Double),
+        ValueType::Bytes => value.as_bytes().map(|b| ScalarValue::Bytes(b.to_vec())),
+        ValueType::Array => {
+            if depth < MAX_FLATTEN_DEPTH {
+                for (idx, element) in value.array_iter().into_iter().flatten().enumerate() {
+                    flatten_value(format!("{}.{}", key, idx), element.borrow(), depth + 1, out);
+                }
+            }
+            None
+        }
+        ValueType::KeyValueList => {
+            if depth < MAX_FLATTEN_DEPTH {
+                for entry in value.kvlist_iter().into_iter().flatten() {
+                    let entry = entry.borrow();
+                    if let Some(nested) = entry.value() {
+                        flatten_value(format!("{}.{}", key, entry.key()), nested, depth + 1, out);
+                    }
+                }
+            }
+            None
+        }
+    };
+
+    if let Some(scalar) = scalar {
+        out.push((key, scalar));
+    }
+}
+
+/// Write `bytes` as standard padded base64, the encoding OTLP/JSON uses
+/// for bytes values
+pub(crate) fn write_base64<W: Write>(w: &mut W, bytes: &[u8]) -> fmt::Result {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        let sextet = |shift: u32| ALPHABET[(n >> shift) as usize & 0x3F] as char;
+        w.write_char(sextet(18))?;
+        w.write_char(sextet(12))?;
+        w.write_char(if chunk.len() > 1 { sextet(6) } else { '=' })?;
+        w.write_char(if chunk.len() > 2 { sextet(0) } else { '=' })?;
+    }
+    Ok(())
+}
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher`, its output is specified, so
+/// fingerprints stay stable across runs, platforms and Rust versions.
+pub(crate) struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+/// Feed a type-tagged, length-prefixed encoding of `value` into `hasher`.
+/// Kvlist entries are hashed in key order.
+pub(crate) fn hash_any_value<V: AnyValueView, H: Hasher>(value: &V, hasher: &mut H) {
+    hash_value_at(value, 0, hasher);
+}
+
+fn hash_value_at<V: AnyValueView, H: Hasher>(value: &V, depth: usize, hasher: &mut H) {
+    match value.value_type() {
+        ValueType::Array | ValueType::KeyValueList if depth >= MAX_HASH_DEPTH => hasher.write_u8(8),
+        ValueType::String => {
+            hasher.write_u8(1);
+            hash_bytes(value.as_string().unwrap_or_default().as_bytes(), hasher);
+        }
+        ValueType::Bool => {
+            hasher.write_u8(2);
+            hasher.write_u8(value.as_bool().unwrap_or_default() as u8);
+        }
+        ValueType::Int64 => {
+            hasher.write_u8(3);
+            hasher.write_i64(value.as_int64().unwrap_or_default());
+        }
+        ValueType::Double => {
+            hasher.write_u8(4);
+            hasher.write_u64(value.as_double().unwrap_or_default().to_bits());
+        }
+        ValueType::Bytes => {
+            hasher.write_u8(5);
+            hash_bytes(value.as_bytes().unwrap_or_default(), hasher);
+        }
+        ValueType::Array => {
+            // Terminated by a count rather than prefixed, so lazy backends
+            // need only one pass
+            hasher.write_u8(6);
+            let mut count = 0u64;
+            for element in value.array_iter().into_iter().flatten() {
+                hash_value_at(element.borrow(), depth + 1, hasher);
+                count += 1;
+            }
+            hasher.write_u64(count);
+        }
+        ValueType::KeyValueList => {
+            let owned: Vec<_> = value.kvlist_iter().into_iter().flatten().collect();
+            let mut entries: Vec<&V::KeyValue> = owned.iter().map(Borrow::borrow).collect();
+            entries.sort_by(|a, b| a.key().cmp(b.key()));
+            hasher.write_u8(7);
+            hash_attributes_at(&entries, depth + 1, hasher);
+        }
+    }
+}
+
+/// Hash key-value pairs in the given order
+pub(crate) fn hash_attributes<A: AttributeView, H: Hasher>(attributes: &[&A], hasher: &mut H) {
+    hash_attributes_at(attributes, 0, hasher);
+}
+
+fn hash_attributes_at<A: AttributeView, H: Hasher>(attributes: &[&A], depth: usize, hasher: &mut H) {
+    hasher.write_u64(attributes.len() as u64);
+    for attr in attributes {
+        hash_bytes(attr.key().as_bytes(), hasher);
+        match attr.value() {
+            Some(value) => hash_value_at(value, depth, hasher),
+            None => hasher.write_u8(0),
+        }
+    }
+}
+
+/// Hash a length-prefixed byte string
+pub(crate) fn hash_bytes<H: Hasher>(bytes: &[u8], hasher: &mut H) {
+    hasher.write_u64(bytes.len() as u64);
+    hasher.write(bytes);
+}
+
+/// Render a value like `to_display_string`, but expand arrays as
+/// `[1, "a", true]` and kvlists as `{key: value}` up to `max_depth` levels
+/// of nesting. Containers past the depth limit render as `[...]`/`{...}`,
+/// and containers with more than `max_elems` entries end with `...`.
+pub(crate) fn display_value_deep<V: AnyValueView>(value: &V, max_depth: usize, max_elems: usize) -> String {
+    let mut out = String::new();
+    // Writing into a String cannot fail
+    let _ = write_value_deep(&mut out, value, max_depth, max_elems);
+    out
+}
+
+fn write_value_deep<V: AnyValueView, W: Write>(w: &mut W, value: &V, depth: usize, max_elems: usize) -> fmt::Result {
+    match value.value_type() {
+        ValueType::String => write!(w, "\"{}\"", value.as_string().unwrap_or_default()),
+        ValueType::Bool => write!(w, "{}", value.as_bool().unwrap_or_default()),
+        ValueType::Int64 => write!(w, "{}", value.as_int64().unwrap_or_default()),
+        ValueType::Double => write!(w, "{}", value.as_double().unwrap_or_default()),
+        ValueType::Bytes => write!(w, "bytes[{}]", value.as_bytes().map(|b| b.len()).unwrap_or(0)),
+        ValueType::Array => {
+            if depth == 0 {
+                return w.write_str("[...]");
+            }
+            let mut values = value.array_iter().into_iter().flatten();
+            w.write_char('[')?;
+            for (idx, element) in values.by_ref().take(max_elems).enumerate() {
+                if idx > 0 {
+                    w.write_str(", ")?;
+                }
+                write_value_deep(w, element.borrow(), depth - 1, max_elems)?;
+            }
+            write_truncation(w, max_elems, values.next().is_some())?;
+            w.write_char(']')
+        }
+        ValueType::KeyValueList => {
+            if depth == 0 {
+                return w.write_str("{...}");
+            }
+            let mut entries = value.kvlist_iter().into_iter().flatten();
+            w.write_char('{')?;
+            for (idx, entry) in entries.by_ref().take(max_elems).enumerate() {
+                if idx > 0 {
+                    w.write_str(", ")?;
+                }
+                let entry = entry.borrow();
+                write!(w, "{}: ", entry.key())?;
+                match entry.value() {
+                    Some(nested) => write_value_deep(w, nested, depth - 1, max_elems)?,
+                    None => w.write_str("null")?,
+                }
+            }
+            write_truncation(w, max_elems, entries.next().is_some())?;
+            w.write_char('}')
+        }
+    }
+}
+
+fn write_truncation<W: Write>(w: &mut W, max_elems: usize, truncated: bool) -> fmt::Result {
+    match truncated {
+        false => Ok(()),
+        true if max_elems == 0 => w.write_str("..."),
+        true => w.write_str(", ..."),
+    }
+}
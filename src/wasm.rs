@@ -0,0 +1,70 @@
+use crate::otlp_bytes_lazy::LogsDataParser;
+use serde::Serialize;
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// How many severities `LogsSummary::top_severities` keeps
+pub const TOP_SEVERITIES: usize = 5;
+
+/// Counts for a logs payload, serialized to a plain JS object by
+/// `parse_logs_summary`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogsSummary {
+    pub resource_count: usize,
+    pub scope_count: usize,
+    pub record_count: usize,
+    /// Most frequent severities, most common first
+    pub top_severities: Vec<SeverityCount>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeverityCount {
+    pub severity_number: i32,
+    pub severity_text: String,
+    pub count: usize,
+}
+
+/// Build the summary with the lazy parser. Malformed input is summarized
+/// up to the first unreadable field rather than rejected.
+pub fn summarize_logs(data: &[u8]) -> LogsSummary {
+    let parser = LogsDataParser::new(data);
+    let mut summary = LogsSummary::default();
+    let mut severities: HashMap<(i32, &str), usize> = HashMap::new();
+
+    for resource in parser.resource_logs() {
+        summary.resource_count += 1;
+        summary.scope_count += resource.scope_logs().count();
+    }
+    // The flat pass hands out records borrowing the buffer itself, so the
+    // severity texts can key the map without copying
+    for flat in parser.collect_records() {
+        summary.record_count += 1;
+        let key = (flat.record.severity_number(), flat.record.severity_text().unwrap_or(""));
+        *severities.entry(key).or_default() += 1;
+    }
+
+    let mut severities: Vec<_> = severities.into_iter().collect();
+    // Ties break on severity number so the output is deterministic
+    severities.sort_by(|(a_key, a_count), (b_key, b_count)| b_count.cmp(a_count).then(a_key.cmp(b_key)));
+    summary.top_severities = severities
+        .into_iter()
+        .take(TOP_SEVERITIES)
+        .map(|((severity_number, severity_text), count)| SeverityCount {
+            severity_number,
+            severity_text: severity_text.to_string(),
+            count,
+        })
+        .collect();
+
+    summary
+}
+
+/// WASM export: summarize an OTLP `LogsData` protobuf buffer into a JS
+/// object with `resourceCount`, `scopeCount`, `recordCount` and
+/// `topSeverities`
+#[wasm_bindgen(js_name = parseLogsSummary)]
+pub fn parse_logs_summary(data: &[u8]) -> JsValue {
+    serde_wasm_bindgen::to_value(&summarize_logs(data)).unwrap_or(JsValue::NULL)
+}